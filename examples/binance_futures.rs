@@ -92,7 +92,7 @@ async fn market_data() {
     }
 
     match market.get_all_book_tickers().await {
-        Ok(BookTickers::AllBookTickers(answer)) => info!("First book ticker: {:?}", answer[0]),
+        Ok(answer) => info!("First book ticker: {:?}", answer[0]),
         Err(e) => error!("Error: {:?}", e),
     }
 
@@ -101,6 +101,11 @@ async fn market_data() {
         Err(e) => error!("Error: {:?}", e),
     }
 
+    match market.get_price_ticker("btcusdt").await {
+        Ok(answer) => info!("Price ticker: {:?}", answer),
+        Err(e) => error!("Error: {:?}", e),
+    }
+
     match market.get_mark_prices(Some("btcusdt".into())).await {
         Ok(answer) => info!("First mark Prices: {:?}", answer[0]),
         Err(e) => info!("Error: {:?}", e),
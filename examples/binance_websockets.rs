@@ -2,6 +2,7 @@
 extern crate tokio;
 
 use binance::api::*;
+use binance::rest_model::Interval;
 use binance::userstream::*;
 use binance::websockets::*;
 use binance::ws_model::{CombinedStreamEvent, WebsocketEvent, WebsocketEventUntag};
@@ -169,7 +170,7 @@ async fn all_trades_websocket(logger_tx: UnboundedSender<WebsocketEvent>) {
 #[allow(dead_code)]
 async fn kline_websocket(logger_tx: UnboundedSender<WebsocketEvent>) {
     let keep_running = AtomicBool::new(true);
-    let kline = kline_stream("ethbtc", "1m");
+    let kline = kline_stream("ethbtc", Interval::OneMinute);
     let mut web_socket: WebSockets<'_, WebsocketEvent> = WebSockets::new(|event: WebsocketEvent| {
         logger_tx.send(event.clone()).unwrap();
         if let WebsocketEvent::Kline(kline_event) = event {
@@ -194,7 +195,7 @@ async fn kline_websocket(logger_tx: UnboundedSender<WebsocketEvent>) {
 async fn last_price(logger_tx: UnboundedSender<WebsocketEvent>) {
     let keep_running = AtomicBool::new(true);
     let all_ticker = all_ticker_stream();
-    let btcusdt: RwLock<f32> = RwLock::new("0".parse().unwrap());
+    let btcusdt: RwLock<f64> = RwLock::new(0.0);
 
     let mut web_socket: WebSockets<'_, Vec<WebsocketEvent>> = WebSockets::new(|events: Vec<WebsocketEvent>| {
         for tick_events in events {
@@ -202,8 +203,8 @@ async fn last_price(logger_tx: UnboundedSender<WebsocketEvent>) {
             if let WebsocketEvent::DayTicker(tick_event) = tick_events {
                 if tick_event.symbol == "BTCUSDT" {
                     let mut btcusdt = btcusdt.write().unwrap();
-                    *btcusdt = tick_event.average_price.parse::<f32>().unwrap();
-                    let btcusdt_close: f32 = tick_event.current_close.parse().unwrap();
+                    *btcusdt = tick_event.average_price;
+                    let btcusdt_close = tick_event.current_close;
                     println!("{btcusdt} - {btcusdt_close}");
 
                     if btcusdt_close as i32 == 7000 {
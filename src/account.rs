@@ -136,6 +136,23 @@ pub struct OrdersQuery {
     pub recv_window: Option<u64>,
 }
 
+/// perform a query on the trades of the account for a symbol
+#[derive(Default, Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TradesQuery {
+    pub symbol: String,
+    pub order_id: Option<u64>,
+    /// The range between start_time and end_time cannot exceed 24 hours
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    /// Trades with an id greater or equal, cannot be combined with start_time and end_time
+    pub from_id: Option<u64>,
+    /// Default 500 max 1000
+    pub limit: Option<u32>,
+    /// Cannot be greater than 60000
+    pub recv_window: Option<u64>,
+}
+
 impl Account {
     /// General account information
     /// # Examples
@@ -427,4 +444,24 @@ impl Account {
         let request = build_signed_request(parameters, self.recv_window)?;
         self.client.get_signed(API_V3_MYTRADES, &request).await
     }
+
+    /// Trades of the account for a symbol, within a time range or from a trade id
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, account::*, config::*};
+    /// let account: Account = Binance::new_with_env(&Config::testnet());
+    /// let query = TradesQuery {
+    ///     symbol: "BTCUSDT".to_string(),
+    ///     from_id: Some(0),
+    ///     limit: Some(1000),
+    ///     ..TradesQuery::default()
+    /// };
+    /// let trades = tokio_test::block_on(account.get_trades(query));
+    /// assert!(trades.is_ok(), "{:?}", trades);
+    /// ```
+    pub async fn get_trades(&self, query: TradesQuery) -> Result<Vec<TradeHistory>> {
+        let recv_window = query.recv_window.unwrap_or(self.recv_window);
+        let request = build_signed_request_p(query, recv_window)?;
+        self.client.get_signed(API_V3_MYTRADES, &request).await
+    }
 }
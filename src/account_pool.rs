@@ -0,0 +1,343 @@
+//! Many accounts behind one process, with the rate limits of the IP and of each account tracked separately.
+//!
+//! Binance reports the weight used by the IP and the orders placed by the account in the headers of every response.
+//! The weight of the IP is counted separately for each family of endpoints, see [`WeightScope`].
+//! Gateways created by an [`AccountPool`] record those counters and wait for the next window once a limit is reached,
+//! instead of getting banned.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use reqwest::Response;
+
+use crate::api::Binance;
+use crate::config::Config;
+use crate::errors::*;
+
+/// Default request weight allowed by Binance per minute and per IP on the spot API
+pub const DEFAULT_IP_WEIGHT_PER_MINUTE: u32 = 6000;
+
+/// Default number of orders allowed by Binance per 10 seconds and per account
+pub const DEFAULT_ORDERS_PER_10S: u32 = 100;
+
+const IP_WEIGHT_HEADER: &str = "x-mbx-used-weight-1m";
+
+const SAPI_IP_WEIGHT_HEADER: &str = "x-sapi-used-ip-weight-1m";
+
+const ORDER_COUNT_HEADER: &str = "x-mbx-order-count-10s";
+
+/// Families of endpoints whose IP weight Binance counts and limits separately
+///
+/// # Examples
+/// ```rust
+/// use binance::account_pool::WeightScope;
+/// assert_eq!(WeightScope::of("/api/v3/order"), WeightScope::Spot);
+/// assert_eq!(WeightScope::of("/sapi/v1/capital/config/getall"), WeightScope::Sapi);
+/// assert_eq!(WeightScope::of("/fapi/v1/order"), WeightScope::UsdMFutures);
+/// assert_eq!(WeightScope::UsdMFutures.default_limit(), 2400);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WeightScope {
+    Spot,
+    /// The `/sapi` endpoints of the spot host, e.g. wallet, margin or sub-accounts
+    Sapi,
+    UsdMFutures,
+    CoinMFutures,
+    Options,
+    PortfolioMargin,
+}
+
+impl WeightScope {
+    pub const ALL: [WeightScope; 6] = [
+        WeightScope::Spot,
+        WeightScope::Sapi,
+        WeightScope::UsdMFutures,
+        WeightScope::CoinMFutures,
+        WeightScope::Options,
+        WeightScope::PortfolioMargin,
+    ];
+
+    /// Scope of the endpoint `path`
+    pub fn of(path: &str) -> Self {
+        match path.split('/').nth(1) {
+            Some("sapi") => WeightScope::Sapi,
+            Some("fapi") => WeightScope::UsdMFutures,
+            Some("dapi") => WeightScope::CoinMFutures,
+            Some("eapi") => WeightScope::Options,
+            Some("papi") => WeightScope::PortfolioMargin,
+            _ => WeightScope::Spot,
+        }
+    }
+
+    /// Header of the responses reporting the weight used by the IP over the current minute
+    pub fn header(&self) -> &'static str {
+        match self {
+            WeightScope::Sapi => SAPI_IP_WEIGHT_HEADER,
+            _ => IP_WEIGHT_HEADER,
+        }
+    }
+
+    /// Weight allowed by Binance per minute and per IP
+    pub fn default_limit(&self) -> u32 {
+        match self {
+            WeightScope::Spot | WeightScope::PortfolioMargin => DEFAULT_IP_WEIGHT_PER_MINUTE,
+            WeightScope::Sapi => 12000,
+            WeightScope::UsdMFutures | WeightScope::CoinMFutures => 2400,
+            WeightScope::Options => 400,
+        }
+    }
+}
+
+/// A counter reset at the start of every window, as reported by Binance
+pub struct UsageLimit {
+    header: &'static str,
+    limit: u32,
+    window: Duration,
+    usage: Mutex<Usage>,
+}
+
+#[derive(Default)]
+struct Usage {
+    used: u32,
+    window_end: Option<SystemTime>,
+}
+
+impl UsageLimit {
+    /// Limit of the counter reported in `header`, over windows of `window` aligned on the clock
+    pub fn new(header: &'static str, limit: u32, window: Duration) -> Self {
+        Self {
+            header,
+            limit,
+            window,
+            usage: Mutex::new(Usage::default()),
+        }
+    }
+
+    pub fn limit(&self) -> u32 { self.limit }
+
+    /// Usage of the current window
+    pub fn used(&self) -> u32 {
+        let usage = self.usage.lock().unwrap_or_else(|e| e.into_inner());
+        match usage.window_end {
+            Some(window_end) if window_end > SystemTime::now() => usage.used,
+            _ => 0,
+        }
+    }
+
+    /// Record the usage reported by a response
+    pub fn record(&self, response: &Response) {
+        let used = response
+            .headers()
+            .get(self.header)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse().ok());
+        if let Some(used) = used {
+            let mut usage = self.usage.lock().unwrap_or_else(|e| e.into_inner());
+            usage.used = used;
+            usage.window_end = Some(self.window_end(SystemTime::now()));
+        }
+    }
+
+    /// Wait until the end of the window if the limit is reached
+    pub async fn wait(&self) {
+        let remaining = {
+            let usage = self.usage.lock().unwrap_or_else(|e| e.into_inner());
+            match usage.window_end {
+                Some(window_end) if usage.used >= self.limit => window_end.duration_since(SystemTime::now()).ok(),
+                _ => None,
+            }
+        };
+        if let Some(remaining) = remaining {
+            tokio::time::sleep(remaining).await;
+        }
+    }
+
+    fn window_end(&self, now: SystemTime) -> SystemTime {
+        let window = self.window.as_millis().max(1);
+        let since_epoch = now.duration_since(UNIX_EPOCH).unwrap_or_default().as_millis();
+        let end = (since_epoch / window + 1) * window;
+        UNIX_EPOCH + Duration::from_millis(end as u64)
+    }
+}
+
+impl fmt::Debug for UsageLimit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UsageLimit")
+            .field("header", &self.header)
+            .field("limit", &self.limit)
+            .field("used", &self.used())
+            .finish()
+    }
+}
+
+/// Weight used by the IP, with a limit per [`WeightScope`]
+#[derive(Debug)]
+pub struct IpWeightLimits {
+    limits: HashMap<WeightScope, UsageLimit>,
+}
+
+impl IpWeightLimits {
+    /// The default limit of every scope, except the spot API which allows `spot_weight_per_minute`
+    pub fn new(spot_weight_per_minute: u32) -> Self {
+        let limits = WeightScope::ALL
+            .into_iter()
+            .map(|scope| {
+                let limit = match scope {
+                    WeightScope::Spot => spot_weight_per_minute,
+                    _ => scope.default_limit(),
+                };
+                (scope, UsageLimit::new(scope.header(), limit, Duration::from_secs(60)))
+            })
+            .collect();
+        Self { limits }
+    }
+
+    pub fn get(&self, scope: WeightScope) -> &UsageLimit { &self.limits[&scope] }
+}
+
+/// Limits applied by a client: the weight of the IP, shared by all the accounts, and the orders of its account
+#[derive(Clone, Debug)]
+pub struct RateLimits {
+    pub ip: Arc<IpWeightLimits>,
+    pub account: Arc<UsageLimit>,
+}
+
+impl RateLimits {
+    /// Wait for the IP limit of the scope of `endpoint`, and for the account limit if the request is signed
+    pub(crate) async fn wait(&self, endpoint: &str, signed: bool) {
+        self.ip.get(WeightScope::of(endpoint)).wait().await;
+        if signed {
+            self.account.wait().await;
+        }
+    }
+
+    pub(crate) fn record(&self, response: &Response) {
+        self.ip.get(WeightScope::of(response.url().path())).record(response);
+        self.account.record(response);
+    }
+}
+
+impl PartialEq for RateLimits {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.ip, &other.ip) && Arc::ptr_eq(&self.account, &other.account)
+    }
+}
+
+impl Eq for RateLimits {}
+
+struct PoolAccount {
+    api_key: String,
+    secret_key: String,
+    orders: Arc<UsageLimit>,
+}
+
+/// Keyed accounts sharing a configuration, an http client and the weight limit of the IP
+///
+/// # Examples
+/// ```rust,no_run
+/// use binance::{account::*, account_pool::*, api::*, config::*};
+/// let mut pool = AccountPool::new(&Config::default());
+/// pool.add_account("sub-1", "api key 1", "secret key 1");
+/// pool.add_account("sub-2", "api key 2", "secret key 2");
+/// let account: Account = pool.gateway("sub-1").unwrap();
+/// let balances = tokio_test::block_on(account.get_account());
+/// assert!(balances.is_ok(), "{:?}", balances);
+/// let spot = pool.ip_usage(WeightScope::Spot);
+/// println!("{} / {} weight used", spot.used(), spot.limit());
+/// ```
+pub struct AccountPool {
+    config: Config,
+    ip: Arc<IpWeightLimits>,
+    orders_per_10s: u32,
+    accounts: HashMap<String, PoolAccount>,
+}
+
+impl AccountPool {
+    /// Pool with the default limits, the gateways share the http client of `config` or a new one.
+    /// The credentials and signer of `config` are ignored, the gateways use the keys of their account.
+    pub fn new(config: &Config) -> Self {
+        Self::with_limits(config, DEFAULT_IP_WEIGHT_PER_MINUTE, DEFAULT_ORDERS_PER_10S)
+    }
+
+    /// Pool allowing `ip_weight_per_minute` for the IP on the spot API and `orders_per_10s` for each account,
+    /// the other scopes of the IP weight keep their default limit
+    pub fn with_limits(config: &Config, ip_weight_per_minute: u32, orders_per_10s: u32) -> Self {
+        let mut config = config.clone();
+        // Each account signs with its own key, the credentials and signer of the configuration would apply to all
+        config.shared_credentials = None;
+        config.credentials = None;
+        config.signer = None;
+        if config.http_client.is_none() {
+            config = config.with_shared_http_client();
+        }
+        Self {
+            config,
+            ip: Arc::new(IpWeightLimits::new(ip_weight_per_minute)),
+            orders_per_10s,
+            accounts: HashMap::new(),
+        }
+    }
+
+    /// Add or replace an account
+    pub fn add_account<S1, S2, S3>(&mut self, id: S1, api_key: S2, secret_key: S3)
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<String>,
+    {
+        let orders = Arc::new(UsageLimit::new(
+            ORDER_COUNT_HEADER,
+            self.orders_per_10s,
+            Duration::from_secs(10),
+        ));
+        self.accounts.insert(id.into(), PoolAccount {
+            api_key: api_key.into(),
+            secret_key: secret_key.into(),
+            orders,
+        });
+    }
+
+    /// Remove an account, returns whether it was in the pool
+    pub fn remove_account(&mut self, id: &str) -> bool { self.accounts.remove(id).is_some() }
+
+    pub fn ids(&self) -> impl Iterator<Item = &str> { self.accounts.keys().map(String::as_str) }
+
+    pub fn len(&self) -> usize { self.accounts.len() }
+
+    pub fn is_empty(&self) -> bool { self.accounts.is_empty() }
+
+    /// Any gateway, e.g. `Account` or `FuturesAccount`, for the account `id`
+    pub fn gateway<T: Binance>(&self, id: &str) -> Result<T> {
+        let account = self
+            .accounts
+            .get(id)
+            .ok_or_else(|| Error::Msg(format!("unknown account {id}")))?;
+        let config = self.config.clone().set_rate_limits(RateLimits {
+            ip: self.ip.clone(),
+            account: account.orders.clone(),
+        });
+        Ok(T::new_with_config(
+            Some(account.api_key.clone()),
+            Some(account.secret_key.clone()),
+            &config,
+        ))
+    }
+
+    /// The gateway for every account of the pool
+    pub fn gateways<T: Binance>(&self) -> Vec<(String, T)> {
+        self.accounts
+            .keys()
+            .filter_map(|id| self.gateway(id).ok().map(|gateway| (id.clone(), gateway)))
+            .collect()
+    }
+
+    /// Weight used by the IP on the endpoints of `scope`, shared by all the accounts
+    pub fn ip_usage(&self, scope: WeightScope) -> &UsageLimit { self.ip.get(scope) }
+
+    /// Orders placed by the account over the current 10 seconds window
+    pub fn account_usage(&self, id: &str) -> Option<&UsageLimit> {
+        self.accounts.get(id).map(|account| account.orders.as_ref())
+    }
+}
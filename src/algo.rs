@@ -0,0 +1,239 @@
+//! Spot algo orders, TWAP orders executed over time as a series of sub-orders.
+
+use std::collections::HashMap;
+
+use crate::client::*;
+use crate::errors::*;
+use crate::rest_model::{string_or_float, OrderSide};
+
+static SAPI_V1_ALGO_SPOT_NEW_ORDER_TWAP: &str = "/sapi/v1/algo/spot/newOrderTwap";
+static SAPI_V1_ALGO_SPOT_ORDER: &str = "/sapi/v1/algo/spot/order";
+static SAPI_V1_ALGO_SPOT_OPEN_ORDERS: &str = "/sapi/v1/algo/spot/openOrders";
+static SAPI_V1_ALGO_SPOT_HISTORICAL_ORDERS: &str = "/sapi/v1/algo/spot/historicalOrders";
+static SAPI_V1_ALGO_SPOT_SUB_ORDERS: &str = "/sapi/v1/algo/spot/subOrders";
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct TwapOrderRequest {
+    pub symbol: String,
+    pub side: OrderSide,
+    pub quantity: f64,
+    /// Duration of the order in seconds, Min: 300, Max: 86400
+    pub duration: u64,
+    /// Unique id of the order, generated by Binance if None
+    pub client_algo_id: Option<String>,
+    /// Sub-orders are not executed beyond this price
+    pub limit_price: Option<f64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TwapOrderResponse {
+    pub client_algo_id: String,
+    pub success: bool,
+    pub code: i32,
+    pub msg: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AlgoCancelResponse {
+    pub algo_id: u64,
+    pub success: bool,
+    pub code: i32,
+    pub msg: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AlgoStatus {
+    Working,
+    Finished,
+    Cancelled,
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AlgoOrders {
+    pub total: u64,
+    #[serde(default)]
+    pub orders: Vec<AlgoOrder>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AlgoOrder {
+    pub algo_id: u64,
+    pub symbol: String,
+    pub side: OrderSide,
+    #[serde(with = "string_or_float")]
+    pub executed_qty: f64,
+    #[serde(with = "string_or_float")]
+    pub executed_amt: f64,
+    #[serde(with = "string_or_float")]
+    pub avg_price: f64,
+    pub client_algo_id: String,
+    pub book_time: u64,
+    /// 0 while the order is working
+    pub end_time: u64,
+    pub algo_status: AlgoStatus,
+    /// Only TWAP for spot
+    pub algo_type: String,
+    /// LOW, MEDIUM or HIGH
+    pub urgency: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct AlgoHistoryQuery {
+    pub symbol: Option<String>,
+    pub side: Option<OrderSide>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    /// Page number, starting at 1
+    pub page: Option<u64>,
+    /// Default: 100, Max: 100
+    pub page_size: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AlgoSubOrders {
+    pub total: u64,
+    #[serde(with = "string_or_float")]
+    pub executed_qty: f64,
+    #[serde(with = "string_or_float")]
+    pub executed_amt: f64,
+    #[serde(default)]
+    pub sub_orders: Vec<AlgoSubOrder>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AlgoSubOrder {
+    pub algo_id: u64,
+    pub order_id: u64,
+    pub order_status: String,
+    #[serde(with = "string_or_float")]
+    pub executed_qty: f64,
+    #[serde(with = "string_or_float")]
+    pub executed_amt: f64,
+    /// Negative when paid
+    #[serde(with = "string_or_float")]
+    pub fee_amt: f64,
+    pub fee_asset: String,
+    pub book_time: u64,
+    #[serde(with = "string_or_float")]
+    pub avg_price: f64,
+    pub side: OrderSide,
+    pub symbol: String,
+    pub sub_id: u64,
+    /// e.g. IMMEDIATE_OR_CANCEL
+    pub time_in_force: String,
+    #[serde(with = "string_or_float")]
+    pub orig_qty: f64,
+}
+
+/// This struct acts as a gateway for all spot algo endpoints.
+/// Preferably use the trait [`crate::api::Binance`] to get an instance.
+#[derive(Clone)]
+pub struct Algo {
+    pub client: Client,
+    pub recv_window: u64,
+}
+
+impl Algo {
+    /// Place a TWAP order
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, algo::*, config::*, rest_model::OrderSide};
+    /// let algo: Algo = Binance::new_with_env(&Config::testnet());
+    /// let order = TwapOrderRequest {
+    ///     symbol: "BTCUSDT".to_string(),
+    ///     side: OrderSide::Buy,
+    ///     quantity: 1.0,
+    ///     duration: 3600,
+    ///     ..TwapOrderRequest::default()
+    /// };
+    /// let placed = tokio_test::block_on(algo.twap_order(order));
+    /// assert!(placed.is_ok(), "{:?}", placed);
+    /// ```
+    pub async fn twap_order(&self, order: TwapOrderRequest) -> Result<TwapOrderResponse> {
+        self.client
+            .post_signed_p(SAPI_V1_ALGO_SPOT_NEW_ORDER_TWAP, Some(order), self.recv_window)
+            .await
+    }
+
+    /// Cancel an algo order, the sub-orders already executed are not reverted
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, algo::*, config::*};
+    /// let algo: Algo = Binance::new_with_env(&Config::testnet());
+    /// let canceled = tokio_test::block_on(algo.cancel_order(14511));
+    /// assert!(canceled.is_ok(), "{:?}", canceled);
+    /// ```
+    pub async fn cancel_order(&self, algo_id: u64) -> Result<AlgoCancelResponse> {
+        let mut params = HashMap::new();
+        params.insert("algoId", algo_id);
+        self.client
+            .delete_signed_p(SAPI_V1_ALGO_SPOT_ORDER, params, self.recv_window)
+            .await
+    }
+
+    /// Algo orders still working
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, algo::*, config::*};
+    /// let algo: Algo = Binance::new_with_env(&Config::testnet());
+    /// let orders = tokio_test::block_on(algo.open_orders());
+    /// assert!(orders.is_ok(), "{:?}", orders);
+    /// ```
+    pub async fn open_orders(&self) -> Result<AlgoOrders> {
+        self.client
+            .get_signed_p(SAPI_V1_ALGO_SPOT_OPEN_ORDERS, Option::<String>::None, self.recv_window)
+            .await
+    }
+
+    /// Finished or cancelled algo orders
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, algo::*, config::*};
+    /// let algo: Algo = Binance::new_with_env(&Config::testnet());
+    /// let orders = tokio_test::block_on(algo.historical_orders(AlgoHistoryQuery::default()));
+    /// assert!(orders.is_ok(), "{:?}", orders);
+    /// ```
+    pub async fn historical_orders(&self, query: AlgoHistoryQuery) -> Result<AlgoOrders> {
+        self.client
+            .get_signed_p(SAPI_V1_ALGO_SPOT_HISTORICAL_ORDERS, Some(query), self.recv_window)
+            .await
+    }
+
+    /// Sub-orders executed for an algo order
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, algo::*, config::*};
+    /// let algo: Algo = Binance::new_with_env(&Config::testnet());
+    /// let sub_orders = tokio_test::block_on(algo.sub_orders(14511, None, None));
+    /// assert!(sub_orders.is_ok(), "{:?}", sub_orders);
+    /// ```
+    pub async fn sub_orders(&self, algo_id: u64, page: Option<u64>, page_size: Option<u64>) -> Result<AlgoSubOrders> {
+        let mut params = HashMap::new();
+        params.insert("algoId", algo_id);
+        if let Some(page) = page {
+            params.insert("page", page);
+        }
+        if let Some(page_size) = page_size {
+            params.insert("pageSize", page_size);
+        }
+        self.client
+            .get_signed_p(SAPI_V1_ALGO_SPOT_SUB_ORDERS, Some(params), self.recv_window)
+            .await
+    }
+}
@@ -10,10 +10,17 @@ pub trait Binance: Sized {
         Self::new_with_config(api_key, secret_key, &Config::default())
     }
 
-    /// Create a binance API using environment variables for credentials
+    /// Create a binance API using the credentials of the config, or environment variables for credentials
     /// BINANCE_API_KEY=$YOUR_API_KEY
     /// BINANCE_API_SECRET_KEY=$YOUR_SECRET_KEY
     fn new_with_env(config: &Config) -> Self {
+        if let Some(credentials) = &config.credentials {
+            return Self::new_with_config(
+                Some(credentials.api_key.clone()),
+                Some(credentials.secret_key.clone()),
+                config,
+            );
+        }
         let api_key = std::env::var("BINANCE_API_KEY").ok();
         let secret = std::env::var("BINANCE_API_SECRET_KEY").ok();
         Self::new_with_config(api_key, secret, config)
@@ -25,7 +32,7 @@ pub trait Binance: Sized {
 impl Binance for General {
     fn new_with_config(api_key: Option<String>, secret_key: Option<String>, config: &Config) -> General {
         General {
-            client: Client::new(api_key, secret_key, config.rest_api_endpoint.clone(), config.timeout),
+            client: Client::from_config(api_key, secret_key, config.rest_api_endpoint.clone(), config),
         }
     }
 }
@@ -33,7 +40,7 @@ impl Binance for General {
 impl Binance for Account {
     fn new_with_config(api_key: Option<String>, secret_key: Option<String>, config: &Config) -> Account {
         Account {
-            client: Client::new(api_key, secret_key, config.rest_api_endpoint.clone(), config.timeout),
+            client: Client::from_config(api_key, secret_key, config.rest_api_endpoint.clone(), config),
             recv_window: config.recv_window,
         }
     }
@@ -43,7 +50,7 @@ impl Binance for Account {
 impl Binance for crate::savings::Savings {
     fn new_with_config(api_key: Option<String>, secret_key: Option<String>, config: &Config) -> Self {
         Self {
-            client: Client::new(api_key, secret_key, config.rest_api_endpoint.clone(), config.timeout),
+            client: Client::from_config(api_key, secret_key, config.sapi_endpoint().to_string(), config),
             recv_window: config.recv_window,
         }
     }
@@ -52,7 +59,7 @@ impl Binance for crate::savings::Savings {
 impl Binance for Market {
     fn new_with_config(api_key: Option<String>, secret_key: Option<String>, config: &Config) -> Market {
         Market {
-            client: Client::new(api_key, secret_key, config.rest_api_endpoint.clone(), config.timeout),
+            client: Client::from_config(api_key, secret_key, config.rest_api_endpoint.clone(), config),
             recv_window: config.recv_window,
         }
     }
@@ -61,7 +68,7 @@ impl Binance for Market {
 impl Binance for UserStream {
     fn new_with_config(api_key: Option<String>, secret_key: Option<String>, config: &Config) -> UserStream {
         UserStream {
-            client: Client::new(api_key, secret_key, config.rest_api_endpoint.clone(), config.timeout),
+            client: Client::from_config(api_key, secret_key, config.rest_api_endpoint.clone(), config),
             recv_window: config.recv_window,
         }
     }
@@ -71,12 +78,7 @@ impl Binance for UserStream {
 impl Binance for crate::futures::general::FuturesGeneral {
     fn new_with_config(api_key: Option<String>, secret_key: Option<String>, config: &Config) -> Self {
         Self {
-            client: Client::new(
-                api_key,
-                secret_key,
-                config.futures_rest_api_endpoint.clone(),
-                config.timeout,
-            ),
+            client: Client::from_config(api_key, secret_key, config.futures_rest_api_endpoint.clone(), config),
         }
     }
 }
@@ -85,12 +87,7 @@ impl Binance for crate::futures::general::FuturesGeneral {
 impl Binance for crate::futures::market::FuturesMarket {
     fn new_with_config(api_key: Option<String>, secret_key: Option<String>, config: &Config) -> Self {
         Self {
-            client: Client::new(
-                api_key,
-                secret_key,
-                config.futures_rest_api_endpoint.clone(),
-                config.timeout,
-            ),
+            client: Client::from_config(api_key, secret_key, config.futures_rest_api_endpoint.clone(), config),
             recv_window: config.recv_window,
         }
     }
@@ -100,12 +97,36 @@ impl Binance for crate::futures::market::FuturesMarket {
 impl Binance for crate::futures::account::FuturesAccount {
     fn new_with_config(api_key: Option<String>, secret_key: Option<String>, config: &Config) -> Self {
         Self {
-            client: Client::new(
-                api_key,
-                secret_key,
-                config.futures_rest_api_endpoint.clone(),
-                config.timeout,
-            ),
+            client: Client::from_config(api_key, secret_key, config.futures_rest_api_endpoint.clone(), config),
+            recv_window: config.recv_window,
+        }
+    }
+}
+
+#[cfg(feature = "options_api")]
+impl Binance for crate::options::general::OptionsGeneral {
+    fn new_with_config(api_key: Option<String>, secret_key: Option<String>, config: &Config) -> Self {
+        Self {
+            client: Client::from_config(api_key, secret_key, config.options_rest_api_endpoint.clone(), config),
+        }
+    }
+}
+
+#[cfg(feature = "options_api")]
+impl Binance for crate::options::market::OptionsMarket {
+    fn new_with_config(api_key: Option<String>, secret_key: Option<String>, config: &Config) -> Self {
+        Self {
+            client: Client::from_config(api_key, secret_key, config.options_rest_api_endpoint.clone(), config),
+            recv_window: config.recv_window,
+        }
+    }
+}
+
+#[cfg(feature = "options_api")]
+impl Binance for crate::options::account::OptionsAccount {
+    fn new_with_config(api_key: Option<String>, secret_key: Option<String>, config: &Config) -> Self {
+        Self {
+            client: Client::from_config(api_key, secret_key, config.options_rest_api_endpoint.clone(), config),
             recv_window: config.recv_window,
         }
     }
@@ -115,7 +136,7 @@ impl Binance for crate::futures::account::FuturesAccount {
 impl Binance for crate::margin::Margin {
     fn new_with_config(api_key: Option<String>, secret_key: Option<String>, config: &Config) -> Self {
         Self {
-            client: Client::new(api_key, secret_key, config.rest_api_endpoint.clone(), config.timeout),
+            client: Client::from_config(api_key, secret_key, config.sapi_endpoint().to_string(), config),
             recv_window: config.recv_window,
         }
     }
@@ -125,9 +146,200 @@ impl Binance for crate::margin::Margin {
 impl Binance for crate::wallet::Wallet {
     fn new_with_config(api_key: Option<String>, secret_key: Option<String>, config: &Config) -> Self {
         Self {
-            client: Client::new(api_key, secret_key, config.rest_api_endpoint.clone(), config.timeout),
+            client: Client::from_config(api_key, secret_key, config.sapi_endpoint().to_string(), config),
             recv_window: config.recv_window,
             binance_us_api: config.binance_us_api,
         }
     }
 }
+
+#[cfg(feature = "broker_api")]
+impl Binance for crate::broker::Broker {
+    fn new_with_config(api_key: Option<String>, secret_key: Option<String>, config: &Config) -> Self {
+        Self {
+            client: Client::from_config(api_key, secret_key, config.sapi_endpoint().to_string(), config),
+            recv_window: config.recv_window,
+        }
+    }
+}
+
+#[cfg(feature = "simple_earn_api")]
+impl Binance for crate::simple_earn::SimpleEarn {
+    fn new_with_config(api_key: Option<String>, secret_key: Option<String>, config: &Config) -> Self {
+        Self {
+            client: Client::from_config(api_key, secret_key, config.sapi_endpoint().to_string(), config),
+            recv_window: config.recv_window,
+        }
+    }
+}
+
+#[cfg(feature = "staking_api")]
+impl Binance for crate::staking::Staking {
+    fn new_with_config(api_key: Option<String>, secret_key: Option<String>, config: &Config) -> Self {
+        Self {
+            client: Client::from_config(api_key, secret_key, config.sapi_endpoint().to_string(), config),
+            recv_window: config.recv_window,
+        }
+    }
+}
+
+#[cfg(feature = "convert_api")]
+impl Binance for crate::convert::Convert {
+    fn new_with_config(api_key: Option<String>, secret_key: Option<String>, config: &Config) -> Self {
+        Self {
+            client: Client::from_config(api_key, secret_key, config.sapi_endpoint().to_string(), config),
+            recv_window: config.recv_window,
+        }
+    }
+}
+
+#[cfg(feature = "loans_api")]
+impl Binance for crate::loans::Loans {
+    fn new_with_config(api_key: Option<String>, secret_key: Option<String>, config: &Config) -> Self {
+        Self {
+            client: Client::from_config(api_key, secret_key, config.sapi_endpoint().to_string(), config),
+            recv_window: config.recv_window,
+        }
+    }
+}
+
+#[cfg(feature = "vip_loans_api")]
+impl Binance for crate::vip_loans::VipLoans {
+    fn new_with_config(api_key: Option<String>, secret_key: Option<String>, config: &Config) -> Self {
+        Self {
+            client: Client::from_config(api_key, secret_key, config.sapi_endpoint().to_string(), config),
+            recv_window: config.recv_window,
+        }
+    }
+}
+
+#[cfg(feature = "mining_api")]
+impl Binance for crate::mining::Mining {
+    fn new_with_config(api_key: Option<String>, secret_key: Option<String>, config: &Config) -> Self {
+        Self {
+            client: Client::from_config(api_key, secret_key, config.sapi_endpoint().to_string(), config),
+            recv_window: config.recv_window,
+        }
+    }
+}
+
+#[cfg(feature = "pay_api")]
+impl Binance for crate::pay::Pay {
+    fn new_with_config(api_key: Option<String>, secret_key: Option<String>, config: &Config) -> Self {
+        Self {
+            client: Client::from_config(api_key, secret_key, config.sapi_endpoint().to_string(), config),
+            recv_window: config.recv_window,
+        }
+    }
+}
+
+#[cfg(feature = "sub_account_api")]
+impl Binance for crate::sub_account::SubAccount {
+    fn new_with_config(api_key: Option<String>, secret_key: Option<String>, config: &Config) -> Self {
+        Self {
+            client: Client::from_config(api_key, secret_key, config.sapi_endpoint().to_string(), config),
+            recv_window: config.recv_window,
+        }
+    }
+}
+
+#[cfg(feature = "dual_investment_api")]
+impl Binance for crate::dual_investment::DualInvestment {
+    fn new_with_config(api_key: Option<String>, secret_key: Option<String>, config: &Config) -> Self {
+        Self {
+            client: Client::from_config(api_key, secret_key, config.sapi_endpoint().to_string(), config),
+            recv_window: config.recv_window,
+        }
+    }
+}
+
+#[cfg(feature = "c2c_api")]
+impl Binance for crate::c2c::C2c {
+    fn new_with_config(api_key: Option<String>, secret_key: Option<String>, config: &Config) -> Self {
+        Self {
+            client: Client::from_config(api_key, secret_key, config.sapi_endpoint().to_string(), config),
+            recv_window: config.recv_window,
+        }
+    }
+}
+
+#[cfg(feature = "rebate_api")]
+impl Binance for crate::rebate::Rebates {
+    fn new_with_config(api_key: Option<String>, secret_key: Option<String>, config: &Config) -> Self {
+        Self {
+            client: Client::from_config(api_key, secret_key, config.sapi_endpoint().to_string(), config),
+            recv_window: config.recv_window,
+        }
+    }
+}
+
+#[cfg(feature = "blvt_api")]
+impl Binance for crate::blvt::Blvt {
+    fn new_with_config(api_key: Option<String>, secret_key: Option<String>, config: &Config) -> Self {
+        Self {
+            client: Client::from_config(api_key, secret_key, config.sapi_endpoint().to_string(), config),
+            recv_window: config.recv_window,
+        }
+    }
+}
+
+#[cfg(feature = "algo_api")]
+impl Binance for crate::algo::Algo {
+    fn new_with_config(api_key: Option<String>, secret_key: Option<String>, config: &Config) -> Self {
+        Self {
+            client: Client::from_config(api_key, secret_key, config.sapi_endpoint().to_string(), config),
+            recv_window: config.recv_window,
+        }
+    }
+}
+
+#[cfg(feature = "portfolio_margin_api")]
+impl Binance for crate::portfolio_margin::PortfolioMargin {
+    fn new_with_config(api_key: Option<String>, secret_key: Option<String>, config: &Config) -> Self {
+        Self {
+            client: Client::from_config(api_key, secret_key, config.sapi_endpoint().to_string(), config),
+            recv_window: config.recv_window,
+        }
+    }
+}
+
+#[cfg(all(
+    feature = "futures_api",
+    feature = "margin_api",
+    feature = "wallet_api",
+    feature = "convert_api"
+))]
+impl Binance for crate::ledger::Ledger {
+    fn new_with_config(api_key: Option<String>, secret_key: Option<String>, config: &Config) -> Self {
+        Self {
+            account: Account::new_with_config(api_key.clone(), secret_key.clone(), config),
+            general: General::new_with_config(api_key.clone(), secret_key.clone(), config),
+            wallet: Binance::new_with_config(api_key.clone(), secret_key.clone(), config),
+            margin: Binance::new_with_config(api_key.clone(), secret_key.clone(), config),
+            futures: Binance::new_with_config(api_key.clone(), secret_key.clone(), config),
+            convert: Binance::new_with_config(api_key, secret_key, config),
+            kinds: crate::ledger::LedgerEntryKind::ALL.to_vec(),
+            transfer_types: crate::ledger::DEFAULT_TRANSFER_TYPES.to_vec(),
+        }
+    }
+}
+
+#[cfg(all(
+    feature = "futures_api",
+    feature = "margin_api",
+    feature = "wallet_api",
+    feature = "simple_earn_api"
+))]
+impl Binance for crate::portfolio::Portfolio {
+    fn new_with_config(api_key: Option<String>, secret_key: Option<String>, config: &Config) -> Self {
+        Self {
+            account: Account::new_with_config(api_key.clone(), secret_key.clone(), config),
+            wallet: Binance::new_with_config(api_key.clone(), secret_key.clone(), config),
+            margin: Binance::new_with_config(api_key.clone(), secret_key.clone(), config),
+            futures: Binance::new_with_config(api_key.clone(), secret_key.clone(), config),
+            simple_earn: Binance::new_with_config(api_key.clone(), secret_key.clone(), config),
+            market: Market::new_with_config(api_key, secret_key, config),
+            sources: crate::portfolio::BalanceSource::ALL.to_vec(),
+        }
+    }
+}
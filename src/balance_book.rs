@@ -0,0 +1,208 @@
+//! Local copy of the account balances, kept up to date with the user stream events.
+
+use std::collections::BTreeMap;
+
+use crate::account::Account;
+use crate::errors::*;
+use crate::futures::account::FuturesAccount;
+use crate::futures::rest_model::AccountBalance;
+use crate::futures::ws_model::FuturesWebsocketEvent;
+use crate::portfolio::BalanceSource;
+use crate::rest_model::AccountInformation;
+use crate::ws_model::WebsocketEvent;
+
+/// Default absolute difference above which a tracked balance is considered to have drifted
+pub const DEFAULT_DRIFT_TOLERANCE: f64 = 1e-8;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct BookBalance {
+    pub free: f64,
+    pub locked: f64,
+}
+
+impl BookBalance {
+    pub fn total(&self) -> f64 { self.free + self.locked }
+}
+
+/// Difference between a tracked balance and the balance returned by the REST API
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BalanceDrift {
+    pub source: BalanceSource,
+    pub asset: String,
+    pub tracked: f64,
+    pub actual: f64,
+}
+
+/// Spot and USD-M futures balances seeded from the REST API and updated with the user stream events.
+///
+/// Spot balances are updated by `outboundAccountPosition` and `balanceUpdate` events,
+/// futures wallet balances by `ACCOUNT_UPDATE` events.
+/// Events older than the last snapshot are ignored, [`BalanceBook::reconcile`] compares the tracked balances
+/// to a fresh snapshot, reports the drifts and resets the book to the snapshot.
+///
+/// # Examples
+/// ```rust
+/// use binance::{balance_book::*, ws_model::WebsocketEvent};
+/// let mut book = BalanceBook::default();
+/// let event: WebsocketEvent = serde_json::from_str(
+///     r#"{"e":"outboundAccountPosition","E":1564034571105,"u":1564034571073,"B":[{"a":"ETH","f":"10000.000000","l":"0.000000"}]}"#,
+/// ).unwrap();
+/// book.apply(&event);
+/// assert_eq!(book.spot_balance("ETH").map(|b| b.free), Some(10000.0));
+/// ```
+#[derive(Debug, Clone)]
+pub struct BalanceBook {
+    spot: BTreeMap<String, BookBalance>,
+    futures: BTreeMap<String, f64>,
+    spot_update_time: u64,
+    futures_update_time: u64,
+    /// Absolute difference above which a balance is reported as drifted
+    pub tolerance: f64,
+}
+
+impl Default for BalanceBook {
+    fn default() -> Self {
+        Self {
+            spot: BTreeMap::new(),
+            futures: BTreeMap::new(),
+            spot_update_time: 0,
+            futures_update_time: 0,
+            tolerance: DEFAULT_DRIFT_TOLERANCE,
+        }
+    }
+}
+
+impl BalanceBook {
+    /// Seed the book with the spot balances and, if `futures` is set, the USD-M futures balances
+    pub async fn seed(account: &Account, futures: Option<&FuturesAccount>) -> Result<Self> {
+        let mut book = Self::default();
+        book.reconcile(account, futures).await?;
+        Ok(book)
+    }
+
+    /// Replace the spot balances with a REST snapshot
+    pub fn seed_spot(&mut self, information: &AccountInformation) {
+        self.spot = information
+            .balances
+            .iter()
+            .map(|b| {
+                (b.asset.clone(), BookBalance {
+                    free: b.free,
+                    locked: b.locked,
+                })
+            })
+            .collect();
+        self.spot_update_time = information.update_time.max(0) as u64;
+    }
+
+    /// Replace the futures wallet balances with a REST snapshot
+    pub fn seed_futures(&mut self, balances: &[AccountBalance]) {
+        self.futures = balances.iter().map(|b| (b.asset.clone(), b.balance)).collect();
+        self.futures_update_time = balances.iter().map(|b| b.update_time).max().unwrap_or_default();
+    }
+
+    /// Apply a spot user stream event, other events are ignored
+    pub fn apply(&mut self, event: &WebsocketEvent) {
+        match event {
+            WebsocketEvent::AccountPositionUpdate(update) if update.last_update_time >= self.spot_update_time => {
+                for balance in &update.balances {
+                    self.spot.insert(balance.asset.clone(), BookBalance {
+                        free: balance.free,
+                        locked: balance.locked,
+                    });
+                }
+                self.spot_update_time = update.last_update_time;
+            }
+            WebsocketEvent::BalanceUpdate(update) if update.clear_time > self.spot_update_time => {
+                self.spot.entry(update.asset.clone()).or_default().free += update.delta;
+            }
+            _ => {}
+        }
+    }
+
+    /// Apply a futures user stream event
+    pub fn apply_futures(&mut self, event: &FuturesWebsocketEvent) {
+        match event {
+            FuturesWebsocketEvent::AccountUpdate(update) if update.transaction_time >= self.futures_update_time => {
+                for balance in &update.data.balances {
+                    self.futures.insert(balance.asset.clone(), balance.wallet_balance);
+                }
+                self.futures_update_time = update.transaction_time;
+            }
+            FuturesWebsocketEvent::AccountUpdate(_) => {}
+        }
+    }
+
+    pub fn spot_balance(&self, asset: &str) -> Option<&BookBalance> { self.spot.get(asset) }
+
+    pub fn spot_balances(&self) -> &BTreeMap<String, BookBalance> { &self.spot }
+
+    /// Futures wallet balance, excluding unrealized pnl
+    pub fn futures_balance(&self, asset: &str) -> Option<f64> { self.futures.get(asset).copied() }
+
+    pub fn futures_balances(&self) -> &BTreeMap<String, f64> { &self.futures }
+
+    /// Compare the tracked spot balances to a REST snapshot and reset them to the snapshot
+    pub fn reconcile_spot(&mut self, information: &AccountInformation) -> Vec<BalanceDrift> {
+        let tracked: BTreeMap<String, f64> = self.spot.iter().map(|(a, b)| (a.clone(), b.total())).collect();
+        let actual = information
+            .balances
+            .iter()
+            .map(|b| (b.asset.clone(), b.free + b.locked));
+        let drifts = drifts(BalanceSource::Spot, tracked, actual, self.tolerance);
+        self.seed_spot(information);
+        drifts
+    }
+
+    /// Compare the tracked futures balances to a REST snapshot and reset them to the snapshot
+    pub fn reconcile_futures(&mut self, balances: &[AccountBalance]) -> Vec<BalanceDrift> {
+        let actual = balances.iter().map(|b| (b.asset.clone(), b.balance));
+        let drifts = drifts(BalanceSource::UsdFutures, self.futures.clone(), actual, self.tolerance);
+        self.seed_futures(balances);
+        drifts
+    }
+
+    /// Fetch fresh snapshots, report the drifts and reset the book to the snapshots, meant to be called periodically
+    pub async fn reconcile(
+        &mut self,
+        account: &Account,
+        futures: Option<&FuturesAccount>,
+    ) -> Result<Vec<BalanceDrift>> {
+        let mut drifts = self.reconcile_spot(&account.get_account().await?);
+        if let Some(futures) = futures {
+            drifts.extend(self.reconcile_futures(&futures.account_balance().await?));
+        }
+        Ok(drifts)
+    }
+}
+
+fn drifts<I>(source: BalanceSource, mut tracked: BTreeMap<String, f64>, actual: I, tolerance: f64) -> Vec<BalanceDrift>
+where
+    I: Iterator<Item = (String, f64)>,
+{
+    let mut drifts = vec![];
+    for (asset, actual) in actual {
+        let tracked = tracked.remove(&asset).unwrap_or_default();
+        if (tracked - actual).abs() > tolerance {
+            drifts.push(BalanceDrift {
+                source,
+                asset,
+                tracked,
+                actual,
+            });
+        }
+    }
+    // Assets missing from the snapshot are held in zero quantity
+    drifts.extend(
+        tracked
+            .into_iter()
+            .filter(|(_, tracked)| tracked.abs() > tolerance)
+            .map(|(asset, tracked)| BalanceDrift {
+                source,
+                asset,
+                tracked,
+                actual: 0.0,
+            }),
+    );
+    drifts
+}
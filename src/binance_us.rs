@@ -0,0 +1,88 @@
+//! Routing of the endpoints which differ or do not exist on Binance.US.
+//!
+//! Binance.US only offers the spot and wallet APIs. Paths which differ are rewritten by the [`crate::client::Client`]
+//! when it targets Binance.US, and calls to products missing from Binance.US fail with
+//! [`crate::errors::Error::UnsupportedOnBinanceUs`] instead of an HTTP 404.
+//! OTC, staking, credit line and custodial endpoints are specific to Binance.US and are not covered by this crate.
+
+/// How a Binance path is served by Binance.US
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Route {
+    /// The path is identical
+    Same,
+    /// The endpoint is served under another path
+    Rewrite(&'static str),
+    /// The endpoint does not exist on Binance.US
+    Unsupported,
+}
+
+/// Paths which differ on Binance.US
+static REWRITES: &[(&str, &str)] = &[("/sapi/v1/asset/tradeFee", "/sapi/v1/asset/query/trading-fee")];
+
+/// Products which do not exist on Binance.US, the modules of such products register their paths here
+static UNSUPPORTED_PREFIXES: &[&str] = &[
+    "/fapi/",
+    "/dapi/",
+    "/eapi/",
+    "/papi/",
+    "/sapi/v1/margin/",
+    "/sapi/v1/userDataStream",
+    "/sapi/v1/bnbBurn",
+    "/sapi/v1/simple-earn/",
+    "/sapi/v1/lending/",
+    "/sapi/v1/eth-staking/",
+    "/sapi/v2/eth-staking/",
+    "/sapi/v1/sol-staking/",
+    "/sapi/v1/staking/",
+    "/sapi/v1/loan/",
+    "/sapi/v2/loan/",
+    "/sapi/v1/mining/",
+    "/sapi/v1/pay/",
+    "/sapi/v1/c2c/",
+    "/sapi/v1/rebate/",
+    "/sapi/v1/blvt/",
+    "/sapi/v1/algo/",
+    "/sapi/v1/portfolio/",
+    "/sapi/v1/broker/",
+    "/sapi/v2/broker/",
+    "/sapi/v1/managed-subaccount/",
+    "/sapi/v1/sub-account/margin/",
+    "/sapi/v2/sub-account/futures/",
+    "/sapi/v1/sub-account/subAccountApi/",
+    "/sapi/v2/sub-account/subAccountApi/",
+    "/sapi/v1/accountSnapshot",
+    "/sapi/v1/convert/",
+    "/sapi/v1/dci/",
+    "/sapi/v1/futures/",
+    "/sapi/v1/asset/transfer",
+    "/sapi/v1/asset/assetDividend",
+    "/sapi/v1/capital/deposit/credit-apply",
+];
+
+/// Route of `endpoint` on Binance.US
+///
+/// # Examples
+/// ```rust
+/// use binance::binance_us::*;
+/// assert_eq!(route("/api/v3/order"), Route::Same);
+/// assert_eq!(route("/sapi/v1/asset/tradeFee"), Route::Rewrite("/sapi/v1/asset/query/trading-fee"));
+/// assert_eq!(route("/sapi/v1/margin/loan"), Route::Unsupported);
+/// assert_eq!(route("/sapi/v1/userDataStream/isolated"), Route::Unsupported);
+/// assert_eq!(route("/sapi/v1/sol-staking/sol/stake"), Route::Unsupported);
+/// assert_eq!(route("/sapi/v1/staking/productList"), Route::Unsupported);
+/// assert_eq!(route("/sapi/v2/broker/subAccountApi/ipRestriction"), Route::Unsupported);
+/// assert_eq!(route("/sapi/v1/managed-subaccount/asset"), Route::Unsupported);
+/// assert_eq!(route("/sapi/v1/accountSnapshot"), Route::Unsupported);
+/// assert_eq!(route("/sapi/v1/dci/product/positions"), Route::Unsupported);
+/// assert_eq!(route("/sapi/v2/sub-account/futures/accountSummary"), Route::Unsupported);
+/// assert_eq!(route("/sapi/v2/sub-account/subAccountApi/ipRestriction"), Route::Unsupported);
+/// ```
+pub fn route(endpoint: &str) -> Route {
+    if let Some((_, rewritten)) = REWRITES.iter().find(|(path, _)| *path == endpoint) {
+        return Route::Rewrite(rewritten);
+    }
+    if UNSUPPORTED_PREFIXES.iter().any(|prefix| endpoint.starts_with(prefix)) {
+        return Route::Unsupported;
+    }
+    Route::Same
+}
@@ -0,0 +1,159 @@
+//! Synchronous wrappers around the async gateways, enabled with the `blocking` feature.
+//!
+//! [`Blocking`] owns a single threaded tokio runtime and drives the requests of the wrapped gateway on it.
+//! The most common spot methods are available directly, any other method goes through [`Blocking::call`].
+//! Blocking methods panic when called from within an async runtime, use the gateways directly there.
+
+use std::future::Future;
+use std::sync::Arc;
+
+use tokio::runtime::{Builder, Runtime};
+
+use crate::account::{Account, OrderCancellation, OrderRequest, OrderStatusRequest};
+use crate::api::Binance;
+use crate::config::Config;
+use crate::errors::*;
+use crate::general::General;
+use crate::market::Market;
+use crate::rest_model::*;
+
+/// Blocking version of a gateway, clones share the same runtime
+///
+/// # Examples
+/// ```rust,no_run
+/// use binance::{api::*, blocking::*, config::*, market::*};
+/// let market: Blocking<Market> = Binance::new_with_env(&Config::default());
+/// let price = market.get_price("BTCUSDT");
+/// assert!(price.is_ok(), "{:?}", price);
+/// let prices = market.call(|market| market.get_prices(&["BTCUSDT", "ETHUSDT"]));
+/// assert!(prices.is_ok(), "{:?}", prices);
+/// ```
+#[derive(Clone)]
+pub struct Blocking<T> {
+    inner: T,
+    runtime: Arc<Runtime>,
+}
+
+impl<T> Blocking<T> {
+    /// Wrap a gateway with a new runtime
+    pub fn new(inner: T) -> Result<Self> {
+        let runtime = Builder::new_current_thread().enable_all().build()?;
+        Ok(Self::with_runtime(inner, Arc::new(runtime)))
+    }
+
+    /// Wrap a gateway with an existing runtime, e.g. to share it between several gateways
+    pub fn with_runtime(inner: T, runtime: Arc<Runtime>) -> Self { Self { inner, runtime } }
+
+    /// Run any method of the wrapped gateway to completion
+    pub fn call<'a, F, R>(&'a self, f: impl FnOnce(&'a T) -> F) -> R
+    where
+        F: Future<Output = R>,
+    {
+        self.runtime.block_on(f(&self.inner))
+    }
+
+    pub fn inner(&self) -> &T { &self.inner }
+
+    pub fn runtime(&self) -> &Arc<Runtime> { &self.runtime }
+
+    pub fn into_inner(self) -> T { self.inner }
+}
+
+impl<T: Binance> Binance for Blocking<T> {
+    /// # Panics
+    ///
+    /// If the runtime cannot be created
+    fn new_with_config(api_key: Option<String>, secret_key: Option<String>, config: &Config) -> Self {
+        Self::new(T::new_with_config(api_key, secret_key, config)).expect("failed to build the tokio runtime")
+    }
+}
+
+impl Blocking<General> {
+    /// See [`General::ping`]
+    pub fn ping(&self) -> Result<&'static str> { self.call(|general| general.ping()) }
+
+    /// See [`General::get_server_time`]
+    pub fn get_server_time(&self) -> Result<ServerTime> { self.call(|general| general.get_server_time()) }
+
+    /// See [`General::exchange_info`]
+    pub fn exchange_info(&self) -> Result<ExchangeInformation> { self.call(|general| general.exchange_info()) }
+}
+
+impl Blocking<Market> {
+    /// See [`Market::get_depth`]
+    pub fn get_depth<S: AsRef<str>>(&self, symbol: S) -> Result<OrderBook> {
+        self.call(|market| market.get_depth(symbol))
+    }
+
+    /// See [`Market::get_all_prices`]
+    pub fn get_all_prices(&self) -> Result<Prices> { self.call(|market| market.get_all_prices()) }
+
+    /// See [`Market::get_price`]
+    pub fn get_price<S: AsRef<str>>(&self, symbol: S) -> Result<SymbolPrice> {
+        self.call(|market| market.get_price(symbol))
+    }
+
+    /// See [`Market::get_average_price`]
+    pub fn get_average_price<S: AsRef<str>>(&self, symbol: S) -> Result<AveragePrice> {
+        self.call(|market| market.get_average_price(symbol))
+    }
+
+    /// See [`Market::get_24h_price_stats`]
+    pub fn get_24h_price_stats<S: AsRef<str>>(&self, symbol: S) -> Result<PriceStats> {
+        self.call(|market| market.get_24h_price_stats(symbol))
+    }
+
+    /// See [`Market::get_klines`]
+    pub fn get_klines<S1, S2, S3, S4, S5>(
+        &self,
+        symbol: S1,
+        interval: S2,
+        limit: S3,
+        start_time: S4,
+        end_time: S5,
+    ) -> Result<KlineSummaries>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<Option<u16>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        self.call(|market| market.get_klines(symbol, interval, limit, start_time, end_time))
+    }
+}
+
+impl Blocking<Account> {
+    /// See [`Account::get_account`]
+    pub fn get_account(&self) -> Result<AccountInformation> { self.call(|account| account.get_account()) }
+
+    /// See [`Account::get_balance`]
+    pub fn get_balance<S: Into<String>>(&self, asset: S) -> Result<Balance> {
+        self.call(|account| account.get_balance(asset))
+    }
+
+    /// See [`Account::get_open_orders`]
+    pub fn get_open_orders<S: AsRef<str>>(&self, symbol: S) -> Result<Vec<Order>> {
+        self.call(|account| account.get_open_orders(symbol))
+    }
+
+    /// See [`Account::order_status`]
+    pub fn order_status(&self, osr: OrderStatusRequest) -> Result<Order> {
+        self.call(|account| account.order_status(osr))
+    }
+
+    /// See [`Account::place_order`]
+    pub fn place_order(&self, order: OrderRequest) -> Result<Transaction> {
+        self.call(|account| account.place_order(order))
+    }
+
+    /// See [`Account::cancel_order`]
+    pub fn cancel_order(&self, o: OrderCancellation) -> Result<OrderCanceled> {
+        self.call(|account| account.cancel_order(o))
+    }
+
+    /// See [`Account::trade_history`]
+    pub fn trade_history<S: AsRef<str>>(&self, symbol: S) -> Result<Vec<TradeHistory>> {
+        self.call(|account| account.trade_history(symbol))
+    }
+}
@@ -0,0 +1,266 @@
+//! Binance leveraged tokens (BLVT), token info, subscription, redemption and user limits.
+
+use std::collections::HashMap;
+
+use crate::client::*;
+use crate::errors::*;
+use crate::rest_model::string_or_float;
+
+static SAPI_V1_BLVT_TOKEN_INFO: &str = "/sapi/v1/blvt/tokenInfo";
+static SAPI_V1_BLVT_SUBSCRIBE: &str = "/sapi/v1/blvt/subscribe";
+static SAPI_V1_BLVT_SUBSCRIBE_RECORD: &str = "/sapi/v1/blvt/subscribe/record";
+static SAPI_V1_BLVT_REDEEM: &str = "/sapi/v1/blvt/redeem";
+static SAPI_V1_BLVT_REDEEM_RECORD: &str = "/sapi/v1/blvt/redeem/record";
+static SAPI_V1_BLVT_USER_LIMIT: &str = "/sapi/v1/blvt/userLimit";
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BlvtTokenInfo {
+    pub token_name: String,
+    pub description: String,
+    pub underlying: String,
+    #[serde(with = "string_or_float")]
+    pub token_issued: f64,
+    pub basket: String,
+    pub current_baskets: Vec<BlvtBasket>,
+    #[serde(with = "string_or_float")]
+    pub nav: f64,
+    #[serde(with = "string_or_float")]
+    pub real_leverage: f64,
+    #[serde(with = "string_or_float")]
+    pub funding_rate: f64,
+    #[serde(with = "string_or_float")]
+    pub daily_management_fee: f64,
+    #[serde(with = "string_or_float")]
+    pub purchase_fee_pct: f64,
+    #[serde(with = "string_or_float")]
+    pub daily_purchase_limit: f64,
+    #[serde(with = "string_or_float")]
+    pub redeem_fee_pct: f64,
+    #[serde(with = "string_or_float")]
+    pub daily_redeem_limit: f64,
+    pub timestamp: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BlvtBasket {
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    #[serde(with = "string_or_float")]
+    pub notional_value: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub enum BlvtOrderStatus {
+    #[serde(rename = "S")]
+    Success,
+    #[serde(rename = "P")]
+    Pending,
+    #[serde(rename = "F")]
+    Failure,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BlvtSubscription {
+    pub id: u64,
+    pub status: BlvtOrderStatus,
+    pub token_name: String,
+    /// Subscribed token amount
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    /// Subscription cost in USDT
+    #[serde(with = "string_or_float")]
+    pub cost: f64,
+    pub timestamp: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BlvtRedemption {
+    pub id: u64,
+    pub status: BlvtOrderStatus,
+    pub token_name: String,
+    /// Redeemed token amount
+    #[serde(with = "string_or_float")]
+    pub redeem_amount: f64,
+    /// Redemption value in USDT
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    pub timestamp: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct BlvtRecordQuery {
+    pub token_name: Option<String>,
+    pub id: Option<u64>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    /// Default: 1000, Max: 1000
+    pub limit: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BlvtSubscriptionRecord {
+    pub id: u64,
+    pub token_name: String,
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    #[serde(with = "string_or_float")]
+    pub nav: f64,
+    #[serde(with = "string_or_float")]
+    pub fee: f64,
+    #[serde(with = "string_or_float")]
+    pub total_charge: f64,
+    pub timestamp: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BlvtRedemptionRecord {
+    pub id: u64,
+    pub token_name: String,
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    #[serde(with = "string_or_float")]
+    pub nav: f64,
+    #[serde(with = "string_or_float")]
+    pub fee: f64,
+    #[serde(with = "string_or_float")]
+    pub net_proceed: f64,
+    pub timestamp: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BlvtUserLimit {
+    pub token_name: String,
+    #[serde(with = "string_or_float")]
+    pub user_daily_total_purchase_limit: f64,
+    #[serde(with = "string_or_float")]
+    pub user_daily_total_redeem_limit: f64,
+}
+
+/// This struct acts as a gateway for all BLVT endpoints.
+/// Preferably use the trait [`crate::api::Binance`] to get an instance.
+#[derive(Clone)]
+pub struct Blvt {
+    pub client: Client,
+    pub recv_window: u64,
+}
+
+impl Blvt {
+    /// Leveraged tokens, or a single token if `token_name` is given
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, blvt::*, config::*};
+    /// let blvt: Blvt = Binance::new_with_env(&Config::testnet());
+    /// let tokens = tokio_test::block_on(blvt.token_info(Some("BTCDOWN".to_string())));
+    /// assert!(tokens.is_ok(), "{:?}", tokens);
+    /// ```
+    pub async fn token_info(&self, token_name: Option<String>) -> Result<Vec<BlvtTokenInfo>> {
+        let mut params = HashMap::new();
+        if let Some(token_name) = token_name {
+            params.insert("tokenName", token_name);
+        }
+        self.client
+            .get_signed_p(SAPI_V1_BLVT_TOKEN_INFO, Some(params), self.recv_window)
+            .await
+    }
+
+    /// Subscribe to a leveraged token, spending `cost` USDT
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, blvt::*, config::*};
+    /// let blvt: Blvt = Binance::new_with_env(&Config::testnet());
+    /// let subscription = tokio_test::block_on(blvt.subscribe("BTCDOWN", 10.0));
+    /// assert!(subscription.is_ok(), "{:?}", subscription);
+    /// ```
+    pub async fn subscribe<S>(&self, token_name: S, cost: f64) -> Result<BlvtSubscription>
+    where
+        S: Into<String>,
+    {
+        let mut params = HashMap::new();
+        params.insert("tokenName", token_name.into());
+        params.insert("cost", cost.to_string());
+        self.client
+            .post_signed_p(SAPI_V1_BLVT_SUBSCRIBE, Some(params), self.recv_window)
+            .await
+    }
+
+    /// Subscription history
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, blvt::*, config::*};
+    /// let blvt: Blvt = Binance::new_with_env(&Config::testnet());
+    /// let records = tokio_test::block_on(blvt.subscription_history(BlvtRecordQuery::default()));
+    /// assert!(records.is_ok(), "{:?}", records);
+    /// ```
+    pub async fn subscription_history(&self, query: BlvtRecordQuery) -> Result<Vec<BlvtSubscriptionRecord>> {
+        self.client
+            .get_signed_p(SAPI_V1_BLVT_SUBSCRIBE_RECORD, Some(query), self.recv_window)
+            .await
+    }
+
+    /// Redeem `amount` of a leveraged token
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, blvt::*, config::*};
+    /// let blvt: Blvt = Binance::new_with_env(&Config::testnet());
+    /// let redemption = tokio_test::block_on(blvt.redeem("BTCDOWN", 1.0));
+    /// assert!(redemption.is_ok(), "{:?}", redemption);
+    /// ```
+    pub async fn redeem<S>(&self, token_name: S, amount: f64) -> Result<BlvtRedemption>
+    where
+        S: Into<String>,
+    {
+        let mut params = HashMap::new();
+        params.insert("tokenName", token_name.into());
+        params.insert("amount", amount.to_string());
+        self.client
+            .post_signed_p(SAPI_V1_BLVT_REDEEM, Some(params), self.recv_window)
+            .await
+    }
+
+    /// Redemption history
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, blvt::*, config::*};
+    /// let blvt: Blvt = Binance::new_with_env(&Config::testnet());
+    /// let records = tokio_test::block_on(blvt.redemption_history(BlvtRecordQuery::default()));
+    /// assert!(records.is_ok(), "{:?}", records);
+    /// ```
+    pub async fn redemption_history(&self, query: BlvtRecordQuery) -> Result<Vec<BlvtRedemptionRecord>> {
+        self.client
+            .get_signed_p(SAPI_V1_BLVT_REDEEM_RECORD, Some(query), self.recv_window)
+            .await
+    }
+
+    /// Daily subscription and redemption limits of the user
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, blvt::*, config::*};
+    /// let blvt: Blvt = Binance::new_with_env(&Config::testnet());
+    /// let limits = tokio_test::block_on(blvt.user_limit(None));
+    /// assert!(limits.is_ok(), "{:?}", limits);
+    /// ```
+    pub async fn user_limit(&self, token_name: Option<String>) -> Result<Vec<BlvtUserLimit>> {
+        let mut params = HashMap::new();
+        if let Some(token_name) = token_name {
+            params.insert("tokenName", token_name);
+        }
+        self.client
+            .get_signed_p(SAPI_V1_BLVT_USER_LIMIT, Some(params), self.recv_window)
+            .await
+    }
+}
@@ -0,0 +1,553 @@
+//! Binance Link (broker) endpoints, to manage the sub accounts of a broker account and their API keys.
+
+use std::collections::HashMap;
+
+use serde_json::Value;
+
+use crate::client::*;
+use crate::errors::*;
+use crate::rest_model::{string_or_bool, string_or_float, DepositAddress};
+
+static SAPI_V1_BROKER_INFO: &str = "/sapi/v1/broker/info";
+static SAPI_V1_BROKER_SUBACCOUNT: &str = "/sapi/v1/broker/subAccount";
+static SAPI_V1_BROKER_SUBACCOUNT_API: &str = "/sapi/v1/broker/subAccountApi";
+static SAPI_V1_BROKER_SUBACCOUNT_API_COMMISSION: &str = "/sapi/v1/broker/subAccountApi/commission";
+static SAPI_V1_BROKER_SUBACCOUNT_API_IP_RESTRICTION: &str = "/sapi/v1/broker/subAccountApi/ipRestriction";
+static SAPI_V2_BROKER_SUBACCOUNT_API_IP_RESTRICTION: &str = "/sapi/v2/broker/subAccountApi/ipRestriction";
+static SAPI_V1_BROKER_SUBACCOUNT_API_IP_RESTRICTION_IP_LIST: &str =
+    "/sapi/v1/broker/subAccountApi/ipRestriction/ipList";
+static SAPI_V1_BROKER_REBATE_RECENT_RECORD: &str = "/sapi/v1/broker/rebate/recentRecord";
+static SAPI_V1_BROKER_SUBACCOUNT_DEPOSITHIST: &str = "/sapi/v1/broker/subAccount/depositHist";
+static SAPI_V1_BROKER_SUBACCOUNT_DEPOSITADDRESS: &str = "/sapi/v1/broker/subAccount/depositAddress";
+static SAPI_V1_BROKER_TRANSFER: &str = "/sapi/v1/broker/transfer";
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BrokerInfo {
+    #[serde(with = "string_or_float")]
+    pub max_maker_commission: f64,
+    #[serde(with = "string_or_float")]
+    pub min_maker_commission: f64,
+    #[serde(with = "string_or_float")]
+    pub max_taker_commission: f64,
+    #[serde(with = "string_or_float")]
+    pub min_taker_commission: f64,
+    pub sub_account_qty: u64,
+    pub max_sub_account_qty: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CreatedBrokerSubAccount {
+    #[serde(rename = "subaccountId")]
+    pub sub_account_id: String,
+    pub email: String,
+    pub tag: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct BrokerSubAccountQuery {
+    /// All the sub accounts if None
+    pub sub_account_id: Option<String>,
+    /// Default: 1
+    pub page: Option<u64>,
+    /// Default: 500, Max: 500
+    pub size: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BrokerSubAccount {
+    #[serde(rename = "subaccountId")]
+    pub sub_account_id: String,
+    pub email: String,
+    pub tag: Option<String>,
+    #[serde(with = "string_or_float")]
+    pub maker_commission: f64,
+    #[serde(with = "string_or_float")]
+    pub taker_commission: f64,
+    /// -1 if margin is not enabled for the sub account
+    #[serde(with = "string_or_float")]
+    pub margin_maker_commission: f64,
+    /// -1 if margin is not enabled for the sub account
+    #[serde(with = "string_or_float")]
+    pub margin_taker_commission: f64,
+    pub create_time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateBrokerApiKeyQuery {
+    pub sub_account_id: String,
+    pub can_trade: bool,
+    pub margin_trade: Option<bool>,
+    pub futures_trade: Option<bool>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BrokerApiKey {
+    #[serde(rename = "subaccountId")]
+    pub sub_account_id: String,
+    pub api_key: String,
+    /// Only returned on creation
+    pub secret_key: Option<String>,
+    pub can_trade: bool,
+    pub margin_trade: bool,
+    pub futures_trade: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct BrokerApiKeyQuery {
+    pub sub_account_id: String,
+    /// All the API keys of the sub account if None
+    pub sub_account_api_key: Option<String>,
+    /// Default: 1
+    pub page: Option<u64>,
+    /// Default: 500, Max: 500
+    pub size: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct BrokerCommissionQuery {
+    pub sub_account_id: String,
+    pub maker_commission: f64,
+    pub taker_commission: f64,
+    pub margin_maker_commission: Option<f64>,
+    pub margin_taker_commission: Option<f64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BrokerCommission {
+    #[serde(rename = "subaccountId")]
+    pub sub_account_id: u64,
+    pub maker_commission: f64,
+    pub taker_commission: f64,
+    pub margin_maker_commission: f64,
+    pub margin_taker_commission: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BrokerIpRestriction {
+    #[serde(rename = "subaccountId")]
+    pub sub_account_id: String,
+    #[serde(rename = "apikey")]
+    pub api_key: String,
+    /// Only returned when querying the restriction
+    #[serde(default, with = "string_or_bool")]
+    pub ip_restrict: bool,
+    #[serde(default)]
+    pub ip_list: Vec<String>,
+    pub update_time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct BrokerRebateQuery {
+    /// Rebates of all the sub accounts if None
+    pub sub_account_id: Option<String>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    /// Default: 1
+    pub page: Option<u64>,
+    /// Default: 500, Max: 500
+    pub size: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BrokerRebate {
+    #[serde(rename = "subaccountId")]
+    pub sub_account_id: String,
+    #[serde(with = "string_or_float")]
+    pub income: f64,
+    pub asset: String,
+    pub symbol: String,
+    pub trade_id: u64,
+    pub time: u64,
+    pub status: u8,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountDepositHistoryQuery {
+    pub sub_account_id: Option<String>,
+    pub coin: Option<String>,
+    /// 0(0:pending,6: credited but cannot withdraw, 1:success)
+    pub status: Option<u16>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    /// Default 500
+    pub limit: Option<u64>,
+    /// Default 0
+    pub offset: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountDepositRecord {
+    pub deposit_id: u64,
+    pub sub_account_id: String,
+    pub address: String,
+    pub address_tag: Option<String>,
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    pub coin: String,
+    pub insert_time: u64,
+    pub transfer_type: u8,
+    pub network: String,
+    pub status: u8,
+    pub tx_id: String,
+    pub source_address: Option<String>,
+    pub confirm_times: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct BrokerDepositAddressQuery {
+    pub sub_account_id: String,
+    pub coin: String,
+    /// Default network of the coin if None
+    pub network: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct BrokerTransferQuery {
+    /// Sub account id to transfer from, the broker account if None
+    pub from_id: Option<String>,
+    /// Sub account id to transfer to, the broker account if None
+    pub to_id: Option<String>,
+    /// User defined id, must be unique
+    pub client_tran_id: Option<String>,
+    pub asset: String,
+    pub amount: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BrokerTransferResponse {
+    pub txn_id: String,
+    pub client_tran_id: Option<String>,
+}
+
+/// This struct acts as a gateway for all Binance Link (broker) endpoints.
+/// Preferably use the trait [`crate::api::Binance`] to get an instance.
+#[derive(Clone)]
+pub struct Broker {
+    pub client: Client,
+    pub recv_window: u64,
+}
+
+impl Broker {
+    /// Commission limits and sub account quota of the broker account
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, broker::*, config::*};
+    /// let broker: Broker = Binance::new_with_env(&Config::testnet());
+    /// let info = tokio_test::block_on(broker.info());
+    /// assert!(info.is_ok(), "{:?}", info);
+    /// ```
+    pub async fn info(&self) -> Result<BrokerInfo> {
+        self.client
+            .get_signed_p(SAPI_V1_BROKER_INFO, Option::<String>::None, self.recv_window)
+            .await
+    }
+
+    /// Create a sub account, optionally tagged to identify it
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, broker::*, config::*};
+    /// let broker: Broker = Binance::new_with_env(&Config::testnet());
+    /// let sub_account = tokio_test::block_on(broker.create_sub_account(Some("client1".to_string())));
+    /// assert!(sub_account.is_ok(), "{:?}", sub_account);
+    /// ```
+    pub async fn create_sub_account(&self, tag: Option<String>) -> Result<CreatedBrokerSubAccount> {
+        let mut params = HashMap::new();
+        if let Some(tag) = tag {
+            params.insert("tag", tag);
+        }
+        self.client
+            .post_signed_p(SAPI_V1_BROKER_SUBACCOUNT, Some(params), self.recv_window)
+            .await
+    }
+
+    /// Sub accounts of the broker account and their commissions
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, broker::*, config::*};
+    /// let broker: Broker = Binance::new_with_env(&Config::testnet());
+    /// let sub_accounts = tokio_test::block_on(broker.sub_accounts(BrokerSubAccountQuery::default()));
+    /// assert!(sub_accounts.is_ok(), "{:?}", sub_accounts);
+    /// ```
+    pub async fn sub_accounts(&self, query: BrokerSubAccountQuery) -> Result<Vec<BrokerSubAccount>> {
+        self.client
+            .get_signed_p(SAPI_V1_BROKER_SUBACCOUNT, Some(query), self.recv_window)
+            .await
+    }
+
+    /// Create an API key for a sub account, the secret key is only returned by this call
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, broker::*, config::*};
+    /// let broker: Broker = Binance::new_with_env(&Config::testnet());
+    /// let query = CreateBrokerApiKeyQuery {
+    ///     sub_account_id: "1".to_string(),
+    ///     can_trade: true,
+    ///     ..Default::default()
+    /// };
+    /// let api_key = tokio_test::block_on(broker.create_api_key(query));
+    /// assert!(api_key.is_ok(), "{:?}", api_key);
+    /// ```
+    pub async fn create_api_key(&self, query: CreateBrokerApiKeyQuery) -> Result<BrokerApiKey> {
+        self.client
+            .post_signed_p(SAPI_V1_BROKER_SUBACCOUNT_API, Some(query), self.recv_window)
+            .await
+    }
+
+    /// Delete an API key of a sub account
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, broker::*, config::*};
+    /// let broker: Broker = Binance::new_with_env(&Config::testnet());
+    /// let deleted = tokio_test::block_on(broker.delete_api_key("1", "api_key"));
+    /// assert!(deleted.is_ok(), "{:?}", deleted);
+    /// ```
+    pub async fn delete_api_key<S, K>(&self, sub_account_id: S, api_key: K) -> Result<()>
+    where
+        S: Into<String>,
+        K: Into<String>,
+    {
+        let mut params = HashMap::new();
+        params.insert("subAccountId", sub_account_id.into());
+        params.insert("subAccountApiKey", api_key.into());
+        let _: Value = self
+            .client
+            .delete_signed_p(SAPI_V1_BROKER_SUBACCOUNT_API, params, self.recv_window)
+            .await?;
+        Ok(())
+    }
+
+    /// API keys of a sub account
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, broker::*, config::*};
+    /// let broker: Broker = Binance::new_with_env(&Config::testnet());
+    /// let query = BrokerApiKeyQuery {
+    ///     sub_account_id: "1".to_string(),
+    ///     ..Default::default()
+    /// };
+    /// let api_keys = tokio_test::block_on(broker.api_keys(query));
+    /// assert!(api_keys.is_ok(), "{:?}", api_keys);
+    /// ```
+    pub async fn api_keys(&self, query: BrokerApiKeyQuery) -> Result<Vec<BrokerApiKey>> {
+        self.client
+            .get_signed_p(SAPI_V1_BROKER_SUBACCOUNT_API, Some(query), self.recv_window)
+            .await
+    }
+
+    /// Change the spot and margin commissions of a sub account,
+    /// within the limits returned by [`Broker::info`]
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, broker::*, config::*};
+    /// let broker: Broker = Binance::new_with_env(&Config::testnet());
+    /// let query = BrokerCommissionQuery {
+    ///     sub_account_id: "1".to_string(),
+    ///     maker_commission: 0.001,
+    ///     taker_commission: 0.001,
+    ///     ..Default::default()
+    /// };
+    /// let commission = tokio_test::block_on(broker.change_commission(query));
+    /// assert!(commission.is_ok(), "{:?}", commission);
+    /// ```
+    pub async fn change_commission(&self, query: BrokerCommissionQuery) -> Result<BrokerCommission> {
+        self.client
+            .post_signed_p(SAPI_V1_BROKER_SUBACCOUNT_API_COMMISSION, Some(query), self.recv_window)
+            .await
+    }
+
+    /// Recent spot commission rebates of the broker account, at most 7 days for a single query
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, broker::*, config::*};
+    /// let broker: Broker = Binance::new_with_env(&Config::testnet());
+    /// let rebates = tokio_test::block_on(broker.rebates(BrokerRebateQuery::default()));
+    /// assert!(rebates.is_ok(), "{:?}", rebates);
+    /// ```
+    pub async fn rebates(&self, query: BrokerRebateQuery) -> Result<Vec<BrokerRebate>> {
+        self.client
+            .get_signed_p(SAPI_V1_BROKER_REBATE_RECENT_RECORD, Some(query), self.recv_window)
+            .await
+    }
+
+    /// IP restriction of an API key of a sub account
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, broker::*, config::*};
+    /// let broker: Broker = Binance::new_with_env(&Config::testnet());
+    /// let restriction = tokio_test::block_on(broker.ip_restriction("1", "api_key"));
+    /// assert!(restriction.is_ok(), "{:?}", restriction);
+    /// ```
+    pub async fn ip_restriction<S, K>(&self, sub_account_id: S, api_key: K) -> Result<BrokerIpRestriction>
+    where
+        S: Into<String>,
+        K: Into<String>,
+    {
+        let mut params = HashMap::new();
+        params.insert("subAccountId", sub_account_id.into());
+        params.insert("subAccountApiKey", api_key.into());
+        self.client
+            .get_signed_p(
+                SAPI_V1_BROKER_SUBACCOUNT_API_IP_RESTRICTION,
+                Some(params),
+                self.recv_window,
+            )
+            .await
+    }
+
+    /// Restrict an API key of a sub account to `ip_address`, or lift the restriction if `restrict` is false
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, broker::*, config::*};
+    /// let broker: Broker = Binance::new_with_env(&Config::testnet());
+    /// let restriction = tokio_test::block_on(broker.set_ip_restriction("1", "api_key", true, Some("1.2.3.4".to_string())));
+    /// assert!(restriction.is_ok(), "{:?}", restriction);
+    /// ```
+    pub async fn set_ip_restriction<S, K>(
+        &self,
+        sub_account_id: S,
+        api_key: K,
+        restrict: bool,
+        ip_address: Option<String>,
+    ) -> Result<BrokerIpRestriction>
+    where
+        S: Into<String>,
+        K: Into<String>,
+    {
+        let mut params = HashMap::new();
+        params.insert("subAccountId", sub_account_id.into());
+        params.insert("subAccountApiKey", api_key.into());
+        // 1 lifts the IP restriction, 2 restricts access to the whitelisted IPs
+        params.insert("status", if restrict { "2" } else { "1" }.to_string());
+        if let Some(ip_address) = ip_address {
+            params.insert("ipAddress", ip_address);
+        }
+        self.client
+            .post_signed_p(
+                SAPI_V2_BROKER_SUBACCOUNT_API_IP_RESTRICTION,
+                Some(params),
+                self.recv_window,
+            )
+            .await
+    }
+
+    /// Remove `ip_address` from the IP whitelist of an API key of a sub account
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, broker::*, config::*};
+    /// let broker: Broker = Binance::new_with_env(&Config::testnet());
+    /// let restriction = tokio_test::block_on(broker.delete_ip_restriction("1", "api_key", "1.2.3.4"));
+    /// assert!(restriction.is_ok(), "{:?}", restriction);
+    /// ```
+    pub async fn delete_ip_restriction<S, K, I>(
+        &self,
+        sub_account_id: S,
+        api_key: K,
+        ip_address: I,
+    ) -> Result<BrokerIpRestriction>
+    where
+        S: Into<String>,
+        K: Into<String>,
+        I: Into<String>,
+    {
+        let mut params = HashMap::new();
+        params.insert("subAccountId", sub_account_id.into());
+        params.insert("subAccountApiKey", api_key.into());
+        params.insert("ipAddress", ip_address.into());
+        self.client
+            .delete_signed_p(
+                SAPI_V1_BROKER_SUBACCOUNT_API_IP_RESTRICTION_IP_LIST,
+                params,
+                self.recv_window,
+            )
+            .await
+    }
+
+    /// Deposit history of the broker sub accounts
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, broker::*, config::*};
+    /// let broker: Broker = Binance::new_with_env(&Config::testnet());
+    /// let query = SubAccountDepositHistoryQuery::default();
+    /// let records = tokio_test::block_on(broker.sub_account_deposit_history(query));
+    /// assert!(records.is_ok(), "{:?}", records);
+    /// ```
+    pub async fn sub_account_deposit_history(
+        &self,
+        query: SubAccountDepositHistoryQuery,
+    ) -> Result<Vec<SubAccountDepositRecord>> {
+        self.client
+            .get_signed_p(SAPI_V1_BROKER_SUBACCOUNT_DEPOSITHIST, Some(query), self.recv_window)
+            .await
+    }
+
+    /// Deposit address of a sub account
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, broker::*, config::*};
+    /// let broker: Broker = Binance::new_with_env(&Config::testnet());
+    /// let query = BrokerDepositAddressQuery {
+    ///     sub_account_id: "1".to_string(),
+    ///     coin: "BTC".to_string(),
+    ///     ..Default::default()
+    /// };
+    /// let address = tokio_test::block_on(broker.sub_account_deposit_address(query));
+    /// assert!(address.is_ok(), "{:?}", address);
+    /// ```
+    pub async fn sub_account_deposit_address(&self, query: BrokerDepositAddressQuery) -> Result<DepositAddress> {
+        self.client
+            .get_signed_p(SAPI_V1_BROKER_SUBACCOUNT_DEPOSITADDRESS, Some(query), self.recv_window)
+            .await
+    }
+
+    /// Transfer spot assets between the broker account and its sub accounts,
+    /// e.g. to withdraw the funds of a sub account to the broker account
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, broker::*, config::*};
+    /// let broker: Broker = Binance::new_with_env(&Config::testnet());
+    /// let query = BrokerTransferQuery {
+    ///     from_id: Some("1".to_string()),
+    ///     asset: "USDT".to_string(),
+    ///     amount: 100.0,
+    ///     ..Default::default()
+    /// };
+    /// let records = tokio_test::block_on(broker.transfer(query));
+    /// assert!(records.is_ok(), "{:?}", records);
+    /// ```
+    pub async fn transfer(&self, query: BrokerTransferQuery) -> Result<BrokerTransferResponse> {
+        self.client
+            .post_signed_p(SAPI_V1_BROKER_TRANSFER, Some(query), self.recv_window)
+            .await
+    }
+}
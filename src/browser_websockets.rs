@@ -0,0 +1,123 @@
+//! Websocket streams in the browser, enabled with the `wasm` feature on `wasm32` targets.
+//!
+//! Mirrors [`crate::websockets::WebSockets`] on top of the browser websocket API,
+//! the stream names of [`crate::websockets`] and the events of [`crate::ws_model`] are shared.
+//! Browsers do not allow custom handshake headers, the user agent and headers of the configuration are not sent.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use futures::StreamExt;
+use gloo_net::websocket::futures::WebSocket;
+use gloo_net::websocket::Message;
+use serde_json::from_str;
+use url::Url;
+
+use crate::config::{Config, WebSocketProduct};
+use crate::errors::*;
+use crate::websockets::{combined_stream, STREAM_ENDPOINT, WS_ENDPOINT};
+
+/// # Examples
+/// ```rust,ignore
+/// use std::sync::atomic::AtomicBool;
+/// use binance::{browser_websockets::*, websockets::*, ws_model::WebsocketEvent};
+/// let running = AtomicBool::new(true);
+/// let mut web_socket: BrowserWebSockets<'_, WebsocketEvent> = BrowserWebSockets::new(|event| {
+///     log::info!("{event:?}");
+///     Ok(())
+/// });
+/// web_socket.connect(&agg_trade_stream("btcusdt"))?;
+/// web_socket.event_loop(&running).await?;
+/// ```
+pub struct BrowserWebSockets<'a, WE> {
+    socket: Option<WebSocket>,
+    handler: Box<dyn FnMut(WE) -> Result<()> + 'a>,
+    conf: Config,
+    product: WebSocketProduct,
+}
+
+impl<'a, WE: serde::de::DeserializeOwned> BrowserWebSockets<'a, WE> {
+    /// New websocket holder with default configuration
+    pub fn new<Callback>(handler: Callback) -> BrowserWebSockets<'a, WE>
+    where
+        Callback: FnMut(WE) -> Result<()> + 'a,
+    {
+        Self::new_with_options(handler, Config::default())
+    }
+
+    /// New websocket holder with provided configuration
+    pub fn new_with_options<Callback>(handler: Callback, conf: Config) -> BrowserWebSockets<'a, WE>
+    where
+        Callback: FnMut(WE) -> Result<()> + 'a,
+    {
+        BrowserWebSockets {
+            socket: None,
+            handler: Box::new(handler),
+            conf,
+            product: WebSocketProduct::Spot,
+        }
+    }
+
+    /// Stream from the endpoint of `product` instead of spot
+    pub fn with_product(mut self, product: WebSocketProduct) -> Self {
+        self.product = product;
+        self
+    }
+
+    /// Connect to multiple websocket endpoints
+    /// N.B: WE has to be CombinedStreamEvent
+    pub fn connect_multiple(&mut self, endpoints: Vec<String>) -> Result<()> {
+        let mut url = Url::parse(self.conf.ws_endpoint_for(self.product))?;
+        url.path_segments_mut()
+            .map_err(|_| Error::UrlParserError(url::ParseError::RelativeUrlWithoutBase))?
+            .push(STREAM_ENDPOINT);
+        url.set_query(Some(&format!("streams={}", combined_stream(endpoints))));
+
+        self.open(url)
+    }
+
+    /// Connect to a websocket endpoint
+    pub fn connect(&mut self, endpoint: &str) -> Result<()> {
+        let ws_endpoint = self.conf.ws_endpoint_for(self.product);
+        let wss: String = format!("{ws_endpoint}/{WS_ENDPOINT}/{endpoint}");
+        let url = Url::parse(&wss)?;
+
+        self.open(url)
+    }
+
+    fn open(&mut self, url: Url) -> Result<()> {
+        let socket = WebSocket::open(url.as_str()).map_err(|e| Error::Msg(format!("Error during handshake {e}")))?;
+        self.socket = Some(socket);
+        Ok(())
+    }
+
+    /// Disconnect from the endpoint
+    pub fn disconnect(&mut self) -> Result<()> {
+        match self.socket.take() {
+            Some(socket) => socket
+                .close(None, None)
+                .map_err(|e| Error::Msg(format!("Error while closing {e}"))),
+            None => Err(Error::Msg("Not able to close the connection".to_string())),
+        }
+    }
+
+    pub async fn event_loop(&mut self, running: &AtomicBool) -> Result<()> {
+        while running.load(Ordering::Relaxed) {
+            let Some(socket) = self.socket.as_mut() else {
+                return Ok(());
+            };
+            match socket.next().await {
+                Some(Ok(Message::Text(msg))) => {
+                    if msg.is_empty() {
+                        return Ok(());
+                    }
+                    let event: WE = from_str(msg.as_str())?;
+                    (self.handler)(event)?;
+                }
+                Some(Ok(Message::Bytes(_))) => {}
+                Some(Err(e)) => return Err(Error::Msg(format!("Disconnected {e}"))),
+                None => return Err(Error::Msg("Disconnected".to_string())),
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,120 @@
+//! C2C (P2P) trade history.
+
+use crate::client::*;
+use crate::errors::*;
+use crate::rest_model::{string_or_float, OrderSide};
+
+static SAPI_V1_C2C_ORDER_HISTORY: &str = "/sapi/v1/c2c/orderMatch/listUserOrderHistory";
+
+/// C2C endpoints wrap their data in an envelope with a success flag
+#[derive(Deserialize)]
+struct C2cResponse<T> {
+    code: String,
+    message: String,
+    data: Option<T>,
+    #[serde(default)]
+    total: u64,
+    success: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct C2cTradeHistoryQuery {
+    pub trade_type: OrderSide,
+    pub start_timestamp: Option<u64>,
+    pub end_timestamp: Option<u64>,
+    /// Page number, starting at 1
+    pub page: Option<u64>,
+    /// Default: 100, Max: 100
+    pub rows: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct C2cTradeHistory {
+    pub trades: Vec<C2cTrade>,
+    /// Number of trades over all pages
+    pub total: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum C2cOrderStatus {
+    Pending,
+    Trading,
+    BuyerPayed,
+    Distributing,
+    Completed,
+    InAppeal,
+    Cancelled,
+    CancelledBySystem,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AdvertisementRole {
+    Maker,
+    Taker,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct C2cTrade {
+    pub order_number: String,
+    pub adv_no: String,
+    pub trade_type: OrderSide,
+    pub asset: String,
+    pub fiat: String,
+    pub fiat_symbol: String,
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    #[serde(with = "string_or_float")]
+    pub total_price: f64,
+    #[serde(with = "string_or_float")]
+    pub unit_price: f64,
+    pub order_status: C2cOrderStatus,
+    pub create_time: u64,
+    #[serde(with = "string_or_float")]
+    pub commission: f64,
+    pub counter_part_nick_name: String,
+    pub advertisement_role: AdvertisementRole,
+}
+
+/// This struct acts as a gateway for all C2C endpoints.
+/// Preferably use the trait [`crate::api::Binance`] to get an instance.
+#[derive(Clone)]
+pub struct C2c {
+    pub client: Client,
+    pub recv_window: u64,
+}
+
+impl C2c {
+    /// Buy or sell trade history, most recent first
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, c2c::*, config::*, rest_model::OrderSide};
+    /// let c2c: C2c = Binance::new_with_env(&Config::testnet());
+    /// let query = C2cTradeHistoryQuery {
+    ///     trade_type: OrderSide::Sell,
+    ///     ..C2cTradeHistoryQuery::default()
+    /// };
+    /// let history = tokio_test::block_on(c2c.trade_history(query));
+    /// assert!(history.is_ok(), "{:?}", history);
+    /// ```
+    pub async fn trade_history(&self, query: C2cTradeHistoryQuery) -> Result<C2cTradeHistory> {
+        let response: C2cResponse<Vec<C2cTrade>> = self
+            .client
+            .get_signed_p(SAPI_V1_C2C_ORDER_HISTORY, Some(query), self.recv_window)
+            .await?;
+        match response.data {
+            Some(trades) if response.success => Ok(C2cTradeHistory {
+                trades,
+                total: response.total,
+            }),
+            _ => Err(Error::Msg(format!(
+                "code: {}, msg: {}",
+                response.code, response.message
+            ))),
+        }
+    }
+}
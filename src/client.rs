@@ -1,24 +1,47 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::OnceLock;
 use std::time::Duration;
 
 use boolinator::Boolinator;
-use hex::encode as hex_encode;
 use reqwest::header::{ HeaderMap, HeaderName, HeaderValue, CONTENT_TYPE, USER_AGENT };
-use reqwest::Response;
+use reqwest::{ Method, RequestBuilder, Response };
 use reqwest::StatusCode;
-use ring::hmac;
 use serde::de;
 use serde::de::DeserializeOwned;
 
+use crate::account_pool::RateLimits;
+use crate::binance_us::{ route, Route };
+use crate::config::{Config, Credentials, SharedCredentials, DEFAULT_USER_AGENT};
 use crate::errors::error_messages;
 use crate::errors::*;
-use crate::util::{ build_request_p, build_signed_request_p };
+use crate::permissions::{ required_permissions, PermissionPreflight, SAPI_V1_ACCOUNT_APIRESTRICTIONS };
+use crate::rest_model::ApiKeyPermissions;
+#[cfg(feature = "schema_drift")]
+use crate::schema_drift::SchemaDriftReporter;
+use crate::signer::{HmacSigner, SharedSigner};
+use crate::util::{ build_request_p, build_signed_request, build_signed_request_p, get_timestamp };
 
 #[derive(Clone)]
 pub struct Client {
-    pub api_key: String,
-    pub secret_key: String,
+    /// API key pair, shared with the clones of this client, see [`Client::rotate_credentials`]
+    pub credentials: SharedCredentials,
     pub inner: reqwest::Client,
     pub host: String,
+    /// Rewrite the paths which differ on Binance.US and reject the ones it does not serve
+    pub binance_us: bool,
+    /// User-Agent and custom headers sent with every request, see [`Config::default_headers`]
+    pub headers: HeaderMap,
+    /// Header the id generated for each request is sent in, see [`Config::set_request_id_header`]
+    pub request_id_header: Option<HeaderName>,
+    /// Signs the requests instead of the secret key, see [`crate::signer`]
+    pub signer: Option<SharedSigner>,
+    /// Limits waited for before sending a request, see [`crate::account_pool`]
+    pub rate_limits: Option<RateLimits>,
+    /// Checks the permissions of the key before calling restricted endpoints, see [`crate::permissions`]
+    pub permission_preflight: Option<PermissionPreflight>,
+    /// Receives the fields of the responses unknown to the models, see [`crate::schema_drift`]
+    #[cfg(feature = "schema_drift")]
+    pub schema_drift_reporter: Option<SchemaDriftReporter>,
 }
 
 impl Client {
@@ -31,28 +54,78 @@ impl Client {
         host: String,
         timeout: Option<u64>
     ) -> Self {
-        let mut builder: reqwest::ClientBuilder = reqwest::ClientBuilder::new();
-        if let Some(timeout_secs) = timeout {
-            builder = builder.timeout(Duration::from_secs(timeout_secs));
-        }
         Client {
             // Does it ever make sense for api_key and secret_key to be ""?
-            api_key: api_key.unwrap_or_else(|| "".into()),
-            secret_key: secret_key.unwrap_or_else(|| "".into()),
-            inner: builder.build().unwrap(),
+            credentials: SharedCredentials::new(api_key.unwrap_or_default(), secret_key.unwrap_or_default()),
+            inner: http_client(timeout, None),
+            host,
+            binance_us: false,
+            headers: HeaderMap::new(),
+            request_id_header: None,
+            signer: None,
+            rate_limits: None,
+            permission_preflight: None,
+            #[cfg(feature = "schema_drift")]
+            schema_drift_reporter: None,
+        }
+    }
+
+    /// Returns a client for the host, with the shared http client or the timeout and proxy, the shared credentials
+    /// and the Binance.US routing of the configuration
+    pub fn from_config(api_key: Option<String>, secret_key: Option<String>, host: String, config: &Config) -> Self {
+        Client {
+            credentials: config
+                .shared_credentials
+                .clone()
+                .unwrap_or_else(|| SharedCredentials::new(api_key.unwrap_or_default(), secret_key.unwrap_or_default())),
+            inner: match &config.http_client {
+                Some(http_client) => http_client.inner().clone(),
+                None => http_client(config.timeout, config.proxy.as_deref()),
+            },
             host,
+            binance_us: config.binance_us_api,
+            headers: config.default_headers().unwrap_or_default(),
+            request_id_header: config
+                .request_id_header
+                .as_deref()
+                .and_then(|name| HeaderName::from_bytes(name.as_bytes()).ok()),
+            signer: config.signer.clone(),
+            rate_limits: config.rate_limits.clone(),
+            permission_preflight: config.permission_preflight.clone(),
+            #[cfg(feature = "schema_drift")]
+            schema_drift_reporter: config.schema_drift_reporter.clone(),
         }
     }
 
+    /// Atomically replace the API key pair, for the next requests of this client and of the clients sharing its credentials
+    pub fn rotate_credentials<S1: Into<String>, S2: Into<String>>(&self, api_key: S1, secret_key: S2) {
+        self.credentials.rotate(api_key, secret_key);
+    }
+
+    /// Sign the requests with `signer` instead of the secret key
+    pub fn with_signer(mut self, signer: SharedSigner) -> Self {
+        self.signer = Some(signer);
+        self
+    }
+
+    /// Send the id generated for each request in the header `name`
+    pub fn with_request_id_header(mut self, name: HeaderName) -> Self {
+        self.request_id_header = Some(name);
+        self
+    }
+
+    /// Route the requests for Binance.US, see [`crate::binance_us`]
+    pub fn with_binance_us(mut self, binance_us: bool) -> Self {
+        self.binance_us = binance_us;
+        self
+    }
+
     pub async fn get_signed<T: DeserializeOwned>(
         &self,
         endpoint: &str,
         request: &str
     ) -> Result<T> {
-        let url = self.sign_request(endpoint, request);
-        let response = self.inner.get(&url).headers(self.build_headers(true)?).send().await?;
-
-        self.handler(response).await
+        self.send_signed(Method::GET, endpoint, request).await
     }
 
     pub async fn get_signed_d<T: de::DeserializeOwned>(
@@ -78,10 +151,7 @@ impl Client {
         endpoint: &str,
         request: &str
     ) -> Result<T> {
-        let url = self.sign_request(endpoint, request);
-        let response = self.inner.post(&url).headers(self.build_headers(true)?).send().await?;
-
-        self.handler(response).await
+        self.send_signed(Method::POST, endpoint, request).await
     }
 
     pub async fn post_signed_d<T: de::DeserializeOwned>(
@@ -117,10 +187,90 @@ impl Client {
         endpoint: &str,
         request: &str
     ) -> Result<T> {
-        let url = self.sign_request(endpoint, request);
-        let response = self.inner.delete(&url).headers(self.build_headers(true)?).send().await?;
+        self.send_signed(Method::DELETE, endpoint, request).await
+    }
+
+    /// Call a signed GET endpoint which is not modelled by the crate yet, with the url encoded `params`, and
+    /// return the raw json response
+    ///
+    /// The endpoint is relative to the host of the client, e.g. use the client of a `Wallet` for
+    /// `/sapi` endpoints. The timestamp, the receive window if it is not 0, and the signature are added.
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{account::*, api::*, config::*};
+    /// let account: Account = Binance::new_with_env(&Config::testnet());
+    /// let params = [("omitZeroBalances", "true")];
+    /// let info = tokio_test::block_on(account.client.get_signed_raw("/api/v3/account", params, account.recv_window));
+    /// assert!(info.is_ok(), "{:?}", info);
+    /// ```
+    pub async fn get_signed_raw<K: AsRef<str>, V: AsRef<str>>(
+        &self,
+        endpoint: &str,
+        params: impl IntoIterator<Item = (K, V)>,
+        recv_window: u64
+    ) -> Result<serde_json::Value> {
+        self.signed_raw(Method::GET, endpoint, params, recv_window).await
+    }
+
+    /// Call a signed POST endpoint which is not modelled by the crate yet, see [`Client::get_signed_raw`]
+    pub async fn post_signed_raw<K: AsRef<str>, V: AsRef<str>>(
+        &self,
+        endpoint: &str,
+        params: impl IntoIterator<Item = (K, V)>,
+        recv_window: u64
+    ) -> Result<serde_json::Value> {
+        self.signed_raw(Method::POST, endpoint, params, recv_window).await
+    }
+
+    /// Call a signed PUT endpoint which is not modelled by the crate yet, see [`Client::get_signed_raw`]
+    pub async fn put_signed_raw<K: AsRef<str>, V: AsRef<str>>(
+        &self,
+        endpoint: &str,
+        params: impl IntoIterator<Item = (K, V)>,
+        recv_window: u64
+    ) -> Result<serde_json::Value> {
+        self.signed_raw(Method::PUT, endpoint, params, recv_window).await
+    }
+
+    /// Call a signed DELETE endpoint which is not modelled by the crate yet, see [`Client::get_signed_raw`]
+    pub async fn delete_signed_raw<K: AsRef<str>, V: AsRef<str>>(
+        &self,
+        endpoint: &str,
+        params: impl IntoIterator<Item = (K, V)>,
+        recv_window: u64
+    ) -> Result<serde_json::Value> {
+        self.signed_raw(Method::DELETE, endpoint, params, recv_window).await
+    }
+
+    async fn signed_raw<K: AsRef<str>, V: AsRef<str>>(
+        &self,
+        method: Method,
+        endpoint: &str,
+        params: impl IntoIterator<Item = (K, V)>,
+        recv_window: u64
+    ) -> Result<serde_json::Value> {
+        let params = params.into_iter().map(|(k, v)| {
+            let value: String = url::form_urlencoded::byte_serialize(v.as_ref().as_bytes()).collect();
+            (k, value)
+        });
+        let request = build_signed_request(params, recv_window)?;
+        self.send_signed(method, endpoint, &request).await
+    }
 
-        self.handler(response).await
+    /// Sign and send the request, with a single snapshot of the key pair so that a rotation cannot pair the
+    /// signature of the new secret key with the old API key
+    async fn send_signed<T: DeserializeOwned>(
+        &self,
+        method: Method,
+        endpoint: &str,
+        request: &str
+    ) -> Result<T> {
+        let credentials = self.credentials.get();
+        self.check_permissions(&credentials, method.clone(), endpoint).await?;
+        self.wait_rate_limits(endpoint, true).await;
+        let url = self.sign_request(&credentials, endpoint, request).await?;
+        self.send(self.inner.request(method, &url).headers(self.build_headers(&credentials.api_key, true)?)).await
     }
 
     pub async fn get<T: DeserializeOwned>(
@@ -128,13 +278,13 @@ impl Client {
         endpoint: &str,
         request: Option<&str>
     ) -> Result<T> {
+        let endpoint = self.route(endpoint)?;
         let url = request
             .map(|r| format!("{}{}?{}", self.host, endpoint, r))
             .unwrap_or_else(|| format!("{}{}", self.host, endpoint));
 
-        let response = self.inner.get(&url).send().await?;
-
-        self.handler(response).await
+        self.wait_rate_limits(endpoint, false).await;
+        self.send(self.inner.get(&url).headers(self.build_headers(&self.credentials.get().api_key, false)?)).await
     }
 
     pub async fn get_p<T: DeserializeOwned>(
@@ -159,13 +309,13 @@ impl Client {
         endpoint: &str,
         symbol: Option<&str>
     ) -> Result<T> {
+        let endpoint = self.route(endpoint)?;
         let url = symbol
             .map(|s| format!("{}{}?symbol={}", self.host, endpoint, s))
             .unwrap_or_else(|| format!("{}{}", self.host, endpoint));
 
-        let response = self.inner.post(url).headers(self.build_headers(false)?).send().await?;
-
-        self.handler(response).await
+        self.wait_rate_limits(endpoint, false).await;
+        self.send(self.inner.post(url).headers(self.build_headers(&self.credentials.get().api_key, false)?)).await
     }
 
     pub async fn put<T: DeserializeOwned>(
@@ -174,14 +324,14 @@ impl Client {
         listen_key: &str,
         symbol: Option<&str>
     ) -> Result<T> {
+        let endpoint = self.route(endpoint)?;
         let data = symbol
             .map(|s| format!("listenKey={listen_key}&symbol={s}"))
             .unwrap_or_else(|| format!("listenKey={listen_key}"));
-        let headers = self.build_headers(false)?;
+        let headers = self.build_headers(&self.credentials.get().api_key, false)?;
         let url = format!("{}{}?{}", self.host, endpoint, data);
-        let response = self.inner.put(&url).headers(headers).send().await?;
-
-        self.handler(response).await
+        self.wait_rate_limits(endpoint, false).await;
+        self.send(self.inner.put(&url).headers(headers)).await
     }
 
     pub async fn delete<T: DeserializeOwned>(
@@ -190,30 +340,90 @@ impl Client {
         listen_key: &str,
         symbol: Option<&str>
     ) -> Result<T> {
+        let endpoint = self.route(endpoint)?;
         let data = symbol
             .map(|s| format!("listenKey={listen_key}&symbol={s}"))
             .unwrap_or_else(|| format!("listenKey={listen_key}"));
         let url = format!("{}{}?{}", self.host, endpoint, data);
-        let response = self.inner.delete(url).headers(self.build_headers(false)?).send().await?;
+        self.wait_rate_limits(endpoint, false).await;
+        self.send(self.inner.delete(url).headers(self.build_headers(&self.credentials.get().api_key, false)?)).await
+    }
+
+    fn route<'a>(&self, endpoint: &'a str) -> Result<&'a str> {
+        if !self.binance_us {
+            return Ok(endpoint);
+        }
+        match route(endpoint) {
+            Route::Same => Ok(endpoint),
+            Route::Rewrite(rewritten) => Ok(rewritten),
+            Route::Unsupported => Err(Error::UnsupportedOnBinanceUs(endpoint.to_string())),
+        }
+    }
+
+    async fn wait_rate_limits(&self, endpoint: &str, signed: bool) {
+        if let Some(rate_limits) = &self.rate_limits {
+            rate_limits.wait(endpoint, signed).await;
+        }
+    }
+
+    /// Fails with [`Error::MissingPermission`] if the preflight is enabled and the key cannot call the endpoint
+    async fn check_permissions(&self, credentials: &Credentials, method: Method, endpoint: &str) -> Result<()> {
+        let Some(preflight) = &self.permission_preflight else {
+            return Ok(());
+        };
+        let required = required_permissions(&method, endpoint);
+        if required.is_empty() {
+            return Ok(());
+        }
+        let permissions = match preflight.cached(&credentials.api_key) {
+            Some(permissions) => permissions,
+            None => {
+                let permissions = self.fetch_permissions(preflight, credentials).await?;
+                preflight.store(credentials.api_key.clone(), permissions.clone());
+                permissions
+            }
+        };
+        required.iter().try_for_each(|permission| permissions.check(*permission))
+    }
 
-        self.handler(response).await
+    async fn fetch_permissions(
+        &self,
+        preflight: &PermissionPreflight,
+        credentials: &Credentials
+    ) -> Result<ApiKeyPermissions> {
+        let client = Client {
+            host: preflight.host.clone(),
+            ..self.clone()
+        };
+        let request = build_signed_request_p(Option::<String>::None, preflight.recv_window)?;
+        let url = client.sign_request(credentials, SAPI_V1_ACCOUNT_APIRESTRICTIONS, &request).await?;
+        client.send(client.inner.get(&url).headers(client.build_headers(&credentials.api_key, true)?)).await
     }
 
     // Request must be signed
-    fn sign_request(&self, endpoint: &str, request: &str) -> String {
-        let signed_key = hmac::Key::new(hmac::HMAC_SHA256, self.secret_key.as_bytes());
-        let signature = hex_encode(hmac::sign(&signed_key, request.as_bytes()).as_ref());
+    async fn sign_request(&self, credentials: &Credentials, endpoint: &str, request: &str) -> Result<String> {
+        let endpoint = self.route(endpoint)?;
+        let signature = match &self.signer {
+            Some(signer) => signer.0.sign(request).await?,
+            None => HmacSigner::new(credentials.secret_key.clone()).sign_sync(request),
+        };
         let url = format!("{}{}?{}&signature={}", self.host, endpoint, request, signature);
 
-        url
+        Ok(url)
     }
 
-    fn build_headers(&self, content_type: bool) -> Result<HeaderMap> {
+    fn build_headers(&self, api_key: &str, content_type: bool) -> Result<HeaderMap> {
+        let mut headers = self.headers.clone();
+        // Always include user agent
+        if !headers.contains_key(USER_AGENT) {
+            headers.insert(USER_AGENT, HeaderValue::from_static(DEFAULT_USER_AGENT));
+        }
         let header = IntoIterator::into_iter([
-            // Always include user agent
-            Some((USER_AGENT, HeaderValue::from_static("binance-rs"))),
-            // Always include API key
-            Some((HeaderName::from_static("x-mbx-apikey"), HeaderValue::from_str(&self.api_key)?)),
+            // Include API key if any, public requests can be sent without
+            (!api_key.is_empty())
+                .then(|| HeaderValue::from_str(api_key))
+                .transpose()?
+                .map(|api_key| (HeaderName::from_static("x-mbx-apikey"), api_key)),
             // Include content type if needed
             content_type
                 .as_option()
@@ -221,24 +431,93 @@ impl Client {
                     (CONTENT_TYPE, HeaderValue::from_static("application/x-www-form-urlencoded"))
                 }),
         ])
-            .flatten()
-            .collect();
+            .flatten();
+        headers.extend(header);
 
-        Ok(header)
+        Ok(headers)
+    }
+
+    /// Send the request with a new request id, in the configured header if any, and read its response
+    async fn send<T: de::DeserializeOwned>(&self, request: RequestBuilder) -> Result<T> {
+        let request_id = next_request_id();
+        let mut request = request.build()?;
+        if let Some(header) = &self.request_id_header {
+            request.headers_mut().insert(header.clone(), HeaderValue::from_str(&request_id)?);
+        }
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            request_id = %request_id,
+            method = %request.method(),
+            endpoint = request.url().path(),
+            "sending request"
+        );
+        let response = match self.inner.execute(request).await {
+            Ok(response) => response,
+            Err(source) => {
+                let error = transport_error(&request_id, source);
+                #[cfg(feature = "tracing")]
+                tracing::warn!(%error, "request failed");
+                return Err(error);
+            }
+        };
+        self.handler(response, request_id).await
     }
 
-    async fn handler<T: de::DeserializeOwned>(&self, response: Response) -> Result<T> {
-        match response.status() {
-            StatusCode::OK => Ok(response.json().await?),
-            StatusCode::INTERNAL_SERVER_ERROR => Err(Error::InternalServerError),
-            StatusCode::SERVICE_UNAVAILABLE => Err(Error::ServiceUnavailable),
-            StatusCode::UNAUTHORIZED => Err(Error::Unauthorized),
-            StatusCode::BAD_REQUEST => {
-                let error: BinanceContentError = response.json().await?;
-                Err(handle_content_error(error))
+    async fn handler<T: de::DeserializeOwned>(&self, response: Response, request_id: String) -> Result<T> {
+        if let Some(rate_limits) = &self.rate_limits {
+            rate_limits.record(&response);
+        }
+        #[cfg(feature = "tracing")]
+        tracing::debug!(request_id = %request_id, status = response.status().as_u16(), "received response");
+        if response.status() == StatusCode::OK {
+            #[cfg(feature = "schema_drift")]
+            if let Some(reporter) = &self.schema_drift_reporter {
+                let endpoint = response.url().path().to_string();
+                let body = response.text().await.map_err(|e| transport_error(&request_id, e))?;
+                return reporter.deserialize(&endpoint, &body);
             }
-            s => Err(Error::Msg(format!("Received response: {s:?}"))),
+            return response.json().await.map_err(|e| transport_error(&request_id, e));
         }
+        let context = Box::new(response_context(&response, request_id.clone()));
+        let body = response.text().await.map_err(|e| transport_error(&request_id, e))?;
+        let error = match serde_json::from_str::<BinanceContentError>(&body) {
+            Ok(mut error) => {
+                error.context = Some(context);
+                handle_content_error(error)
+            }
+            Err(_) => Error::Http { context, body },
+        };
+        #[cfg(feature = "tracing")]
+        tracing::warn!(%error, "request rejected");
+        Err(error)
+    }
+}
+
+fn transport_error(client_request_id: &str, source: reqwest::Error) -> Error {
+    Error::Transport {
+        client_request_id: client_request_id.to_string(),
+        source,
+    }
+}
+
+/// Id of a request, unique within the process: the start time of the process followed by a counter
+fn next_request_id() -> String {
+    static PROCESS_ID: OnceLock<u64> = OnceLock::new();
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let process_id = PROCESS_ID.get_or_init(|| get_timestamp().unwrap_or_default());
+    format!("{process_id:x}-{}", COUNTER.fetch_add(1, Ordering::Relaxed))
+}
+
+fn response_context(response: &Response, client_request_id: String) -> ResponseContext {
+    let header = |name: &str| response.headers().get(name).and_then(|value| value.to_str().ok());
+    ResponseContext {
+        endpoint: response.url().path().to_string(),
+        status: response.status().as_u16(),
+        request_id: header("x-mbx-uuid").map(str::to_string),
+        client_request_id: Some(client_request_id),
+        retry_after: header("retry-after")
+            .and_then(|value| value.parse().ok())
+            .map(Duration::from_secs),
     }
 }
 
@@ -249,3 +528,100 @@ fn handle_content_error(error: BinanceContentError) -> crate::errors::Error {
         _ => Error::BinanceError { response: error },
     }
 }
+
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn http_client(timeout: Option<u64>, proxy: Option<&str>) -> reqwest::Client {
+    let mut builder: reqwest::ClientBuilder = reqwest::ClientBuilder::new();
+    if let Some(timeout_secs) = timeout {
+        builder = builder.timeout(Duration::from_secs(timeout_secs));
+    }
+    if let Some(proxy) = proxy.and_then(|proxy| reqwest::Proxy::all(proxy).ok()) {
+        builder = builder.proxy(proxy);
+    }
+    builder.build().unwrap()
+}
+
+/// The browser fetch API has no client level timeout nor proxy
+#[cfg(target_arch = "wasm32")]
+pub(crate) fn http_client(_timeout: Option<u64>, _proxy: Option<&str>) -> reqwest::Client { reqwest::Client::new() }
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod test {
+    use futures::future::BoxFuture;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    use super::Client;
+    use crate::config::{Config, SharedCredentials};
+    use crate::errors::{Error, Result};
+    use crate::signer::RequestSigner;
+
+    /// Serves a single request with an empty json object and returns the raw request
+    async fn capture_request(listener: TcpListener) -> String {
+        let (mut stream, _) = listener.accept().await.unwrap();
+        let mut request = Vec::new();
+        let mut buf = [0; 1024];
+        while !request.ends_with(b"\r\n\r\n") {
+            let read = stream.read(&mut buf).await.unwrap();
+            request.extend_from_slice(&buf[..read]);
+        }
+        stream
+            .write_all(b"HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: 2\r\n\r\n{}")
+            .await
+            .unwrap();
+        String::from_utf8(request).unwrap().to_lowercase()
+    }
+
+    #[tokio::test]
+    async fn public_requests_send_configured_headers() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let host = format!("http://{}", listener.local_addr().unwrap());
+        let server = tokio::spawn(capture_request(listener));
+        let config = Config::default()
+            .set_user_agent("my-broker/1.0")
+            .add_header("x-proxy-token", "secret");
+        let client = Client::from_config(None, None, host, &config);
+        let response: serde_json::Value = client.get("/api/v3/ping", None).await.unwrap();
+        assert_eq!(response, serde_json::json!({}));
+        let request = server.await.unwrap();
+        assert!(request.contains("user-agent: my-broker/1.0"), "{request}");
+        assert!(request.contains("x-proxy-token: secret"), "{request}");
+    }
+
+    /// Rotates the credentials while signing, as a concurrent rotation would
+    struct RotatingSigner(SharedCredentials);
+
+    impl RequestSigner for RotatingSigner {
+        fn sign<'a>(&'a self, _payload: &'a str) -> BoxFuture<'a, Result<String>> {
+            self.0.rotate("new api key", "new secret key");
+            Box::pin(futures::future::ready(Ok("signature".to_string())))
+        }
+    }
+
+    #[tokio::test]
+    async fn signed_requests_keep_the_key_pair_they_were_signed_with() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let host = format!("http://{}", listener.local_addr().unwrap());
+        let server = tokio::spawn(capture_request(listener));
+        let credentials = SharedCredentials::new("old api key", "old secret key");
+        let config = Config::default()
+            .set_shared_credentials(credentials.clone())
+            .set_signer(RotatingSigner(credentials.clone()));
+        let client = Client::from_config(None, None, host, &config);
+        let _: serde_json::Value = client.get_signed("/api/v3/account", "timestamp=1").await.unwrap();
+        let request = server.await.unwrap();
+        assert!(request.contains("x-mbx-apikey: old api key"), "{request}");
+        assert_eq!(credentials.get().api_key, "new api key");
+    }
+
+    #[tokio::test]
+    async fn transport_errors_carry_the_request_id() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let host = format!("http://{}", listener.local_addr().unwrap());
+        drop(listener);
+        let client = Client::from_config(None, None, host, &Config::default());
+        let error = client.get::<serde_json::Value>("/api/v3/ping", None).await.unwrap_err();
+        assert!(matches!(error, Error::Transport { .. }), "{error:?}");
+        assert!(error.client_request_id().is_some());
+    }
+}
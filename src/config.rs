@@ -1,18 +1,170 @@
+use std::fmt;
+use std::sync::{Arc, RwLock};
+
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue, USER_AGENT};
+use url::Url;
+
+use crate::account_pool::RateLimits;
+use crate::errors::{ConfigError, Result};
+use crate::permissions::PermissionPreflight;
+#[cfg(feature = "schema_drift")]
+use crate::schema_drift::{SchemaDriftReporter, UnknownField};
+use crate::signer::{RequestSigner, SharedSigner};
+
 pub static DATA_REST_ENDPOINT: &str = "https://data.binance.com";
 
+/// Public spot market data only, see [`Config::market_data`]
+pub static VISION_DATA_REST_ENDPOINT: &str = "https://data-api.binance.vision";
+pub static VISION_DATA_WS_ENDPOINT: &str = "wss://data-stream.binance.vision";
+
+pub static DEFAULT_USER_AGENT: &str = "binance-rs";
+
+/// Largest receive window accepted by Binance, in milliseconds
+pub const MAX_RECV_WINDOW: u64 = 60_000;
+
+/// Product a websocket streams from, which selects its endpoint in the [`Config`]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum WebSocketProduct {
+    /// `ws_endpoint`
+    #[default]
+    Spot,
+    /// `futures_ws_endpoint`
+    UsdMFutures,
+    /// `coin_futures_ws_endpoint`
+    CoinMFutures,
+    /// `options_ws_endpoint`
+    Options,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Config {
     pub rest_api_endpoint: String,
     pub ws_endpoint: String,
 
+    /// Host of the `/sapi` endpoints (wallet, margin, earn...), defaults to the rest api endpoint
+    pub sapi_rest_api_endpoint: Option<String>,
+
     pub futures_rest_api_endpoint: String,
     pub futures_ws_endpoint: String,
 
+    /// COIN-M futures, the `/dapi` endpoints
+    pub coin_futures_rest_api_endpoint: String,
+    pub coin_futures_ws_endpoint: String,
+
+    pub options_rest_api_endpoint: String,
+    pub options_ws_endpoint: String,
+
     pub recv_window: u64,
 
     pub binance_us_api: bool,
 
     pub timeout: Option<u64>,
+
+    /// Proxy for all the REST requests, e.g. `http://proxy:3128` or `socks5://proxy:1080`
+    pub proxy: Option<String>,
+
+    /// Credentials used by [`crate::api::Binance::new_with_env`] instead of the environment variables
+    pub credentials: Option<Credentials>,
+
+    /// Connection pool shared by all the gateways created with this configuration, see [`Config::set_http_client`]
+    pub http_client: Option<HttpClient>,
+
+    /// User-Agent of the REST requests and websocket handshakes, defaults to `binance-rs`
+    pub user_agent: Option<String>,
+
+    /// Headers added to every REST request and websocket handshake
+    pub headers: Vec<(String, String)>,
+
+    /// Header the id generated for each REST request is sent in, see [`Config::set_request_id_header`]
+    pub request_id_header: Option<String>,
+
+    /// Signs the requests instead of the secret key, see [`crate::signer`]
+    pub signer: Option<SharedSigner>,
+
+    /// Limits the gateways wait for, see [`crate::account_pool`]
+    pub rate_limits: Option<RateLimits>,
+
+    /// Credentials shared by all the gateways, which can be rotated while they run, see [`Config::set_shared_credentials`]
+    pub shared_credentials: Option<SharedCredentials>,
+
+    /// Checks the permissions of the key before calling restricted endpoints, see [`crate::permissions`]
+    pub permission_preflight: Option<PermissionPreflight>,
+
+    /// Receives the fields of the responses unknown to the models, see [`crate::schema_drift`]
+    #[cfg(feature = "schema_drift")]
+    pub schema_drift_reporter: Option<SchemaDriftReporter>,
+}
+
+/// reqwest client shared between gateways, two configurations are equal if they share the same client
+#[derive(Clone, Debug)]
+pub struct HttpClient(Arc<reqwest::Client>);
+
+impl HttpClient {
+    pub fn new(client: reqwest::Client) -> Self { Self(Arc::new(client)) }
+
+    pub fn inner(&self) -> &reqwest::Client { &self.0 }
+}
+
+impl PartialEq for HttpClient {
+    fn eq(&self, other: &Self) -> bool { Arc::ptr_eq(&self.0, &other.0) }
+}
+
+impl Eq for HttpClient {}
+
+/// API key pair, the secret is redacted from the debug output
+#[derive(Clone, PartialEq, Eq, Deserialize)]
+pub struct Credentials {
+    pub api_key: String,
+    pub secret_key: String,
+}
+
+/// API key pair shared between clients, rotating it affects the next requests of all of them
+///
+/// # Examples
+/// ```
+/// use binance::config::SharedCredentials;
+/// let credentials = SharedCredentials::new("old api key", "old secret key");
+/// let shared = credentials.clone();
+/// credentials.rotate("new api key", "new secret key");
+/// assert_eq!(shared.get().api_key, "new api key");
+/// ```
+#[derive(Clone, Debug)]
+pub struct SharedCredentials(Arc<RwLock<Credentials>>);
+
+impl SharedCredentials {
+    pub fn new<S1: Into<String>, S2: Into<String>>(api_key: S1, secret_key: S2) -> Self {
+        Self(Arc::new(RwLock::new(Credentials {
+            api_key: api_key.into(),
+            secret_key: secret_key.into(),
+        })))
+    }
+
+    /// The current key pair
+    pub fn get(&self) -> Credentials { self.0.read().unwrap_or_else(|e| e.into_inner()).clone() }
+
+    /// Atomically replace the key pair
+    pub fn rotate<S1: Into<String>, S2: Into<String>>(&self, api_key: S1, secret_key: S2) {
+        let credentials = Credentials {
+            api_key: api_key.into(),
+            secret_key: secret_key.into(),
+        };
+        *self.0.write().unwrap_or_else(|e| e.into_inner()) = credentials;
+    }
+}
+
+impl PartialEq for SharedCredentials {
+    fn eq(&self, other: &Self) -> bool { Arc::ptr_eq(&self.0, &other.0) }
+}
+
+impl Eq for SharedCredentials {}
+
+impl fmt::Debug for Credentials {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Credentials")
+            .field("api_key", &self.api_key)
+            .field("secret_key", &"<redacted>")
+            .finish()
+    }
 }
 
 impl Config {
@@ -22,12 +174,208 @@ impl Config {
     /// use binance::config::Config;
     /// let config = Config::testnet();
     /// ```
-    pub fn testnet() -> Config {
+    pub fn testnet() -> Config { Config::default().with_spot_testnet().with_futures_testnet() }
+
+    /// Configure binance with the public market data endpoints of binance.vision, which do not serve account endpoints
+    /// # Examples
+    /// ```
+    /// use binance::config::Config;
+    /// let config = Config::market_data();
+    /// ```
+    pub fn market_data() -> Config {
         Config::default()
-            .set_rest_api_endpoint("https://testnet.binance.vision")
+            .set_rest_api_endpoint(VISION_DATA_REST_ENDPOINT)
+            .set_ws_endpoint(VISION_DATA_WS_ENDPOINT)
+    }
+
+    /// Use the spot testnet endpoints, leaving the other products untouched
+    /// # Examples
+    /// ```
+    /// use binance::config::Config;
+    /// let config = Config::default().with_spot_testnet();
+    /// ```
+    pub fn with_spot_testnet(self) -> Config {
+        self.set_rest_api_endpoint("https://testnet.binance.vision")
             .set_ws_endpoint("wss://testnet.binance.vision")
-            .set_futures_rest_api_endpoint("https://testnet.binancefuture.com")
+    }
+
+    /// Use the USD-M and COIN-M futures testnet endpoints, leaving the other products untouched,
+    /// e.g. to trade futures on the testnet with live spot market data
+    /// # Examples
+    /// ```
+    /// use binance::config::Config;
+    /// let config = Config::default().with_futures_testnet();
+    /// assert_eq!(config.rest_api_endpoint, "https://api.binance.com");
+    /// ```
+    pub fn with_futures_testnet(self) -> Config {
+        self.set_futures_rest_api_endpoint("https://testnet.binancefuture.com")
             .set_futures_ws_endpoint("wss://testnet.binancefuture.com")
+            .set_coin_futures_rest_api_endpoint("https://testnet.binancefuture.com")
+            .set_coin_futures_ws_endpoint("wss://dstream.binancefuture.com")
+    }
+
+    /// Host of the `/sapi` endpoints
+    pub fn sapi_endpoint(&self) -> &str {
+        self.sapi_rest_api_endpoint
+            .as_deref()
+            .unwrap_or(&self.rest_api_endpoint)
+    }
+
+    /// Websocket endpoint of `product`
+    /// # Examples
+    /// ```
+    /// use binance::config::{Config, WebSocketProduct};
+    /// let config = Config::default();
+    /// assert_eq!(config.ws_endpoint_for(WebSocketProduct::CoinMFutures), "wss://dstream.binance.com");
+    /// ```
+    pub fn ws_endpoint_for(&self, product: WebSocketProduct) -> &str {
+        match product {
+            WebSocketProduct::Spot => &self.ws_endpoint,
+            WebSocketProduct::UsdMFutures => &self.futures_ws_endpoint,
+            WebSocketProduct::CoinMFutures => &self.coin_futures_ws_endpoint,
+            WebSocketProduct::Options => &self.options_ws_endpoint,
+        }
+    }
+
+    /// Configure binance with the Binance.US endpoints, paths are routed for Binance.US
+    /// # Examples
+    /// ```
+    /// use binance::config::Config;
+    /// let config = Config::binance_us();
+    /// assert!(config.binance_us_api);
+    /// ```
+    pub fn binance_us() -> Config {
+        let mut config = Config::default()
+            .set_rest_api_endpoint("https://api.binance.us")
+            .set_ws_endpoint("wss://stream.binance.us:9443");
+        config.binance_us_api = true;
+        config
+    }
+
+    /// Configure binance from the environment variables, on top of the default production endpoints.
+    ///
+    /// | Variable | Field |
+    /// |---|---|
+    /// | `BINANCE_API_KEY`, `BINANCE_API_SECRET_KEY` | `credentials`, both must be set |
+    /// | `BINANCE_REST_API_ENDPOINT` | `rest_api_endpoint` |
+    /// | `BINANCE_WS_ENDPOINT` | `ws_endpoint` |
+    /// | `BINANCE_SAPI_REST_API_ENDPOINT` | `sapi_rest_api_endpoint` |
+    /// | `BINANCE_FUTURES_REST_API_ENDPOINT` | `futures_rest_api_endpoint` |
+    /// | `BINANCE_FUTURES_WS_ENDPOINT` | `futures_ws_endpoint` |
+    /// | `BINANCE_COIN_FUTURES_REST_API_ENDPOINT` | `coin_futures_rest_api_endpoint` |
+    /// | `BINANCE_COIN_FUTURES_WS_ENDPOINT` | `coin_futures_ws_endpoint` |
+    /// | `BINANCE_OPTIONS_REST_API_ENDPOINT` | `options_rest_api_endpoint` |
+    /// | `BINANCE_OPTIONS_WS_ENDPOINT` | `options_ws_endpoint` |
+    /// | `BINANCE_RECV_WINDOW` | `recv_window`, in milliseconds |
+    /// | `BINANCE_TIMEOUT` | `timeout`, in seconds |
+    /// | `BINANCE_PROXY` | `proxy` |
+    /// | `BINANCE_USER_AGENT` | `user_agent` |
+    /// | `BINANCE_REQUEST_ID_HEADER` | `request_id_header` |
+    /// | `BINANCE_US_API` | `binance_us_api`, `true` or `false` |
+    ///
+    /// The resulting configuration is validated with [`Config::validate`].
+    /// # Examples
+    /// ```
+    /// use binance::config::Config;
+    /// std::env::set_var("BINANCE_RECV_WINDOW", "10000");
+    /// let config = Config::from_env().unwrap();
+    /// assert_eq!(config.recv_window, 10000);
+    /// ```
+    pub fn from_env() -> Result<Config> { Config::default().with_env() }
+
+    /// Override this configuration with the environment variables listed in [`Config::from_env`],
+    /// e.g. to start from [`Config::testnet`]
+    pub fn with_env(self) -> Result<Config> { ConfigOverrides::from_env()?.apply(self) }
+
+    /// Configure binance from a TOML file, on top of the default production endpoints.
+    ///
+    /// Keys are the field names of [`Config`], all of them optional, credentials go in a `[credentials]` table:
+    /// ```toml
+    /// rest_api_endpoint = "https://testnet.binance.vision"
+    /// recv_window = 10000
+    /// timeout = 5
+    /// proxy = "http://proxy:3128"
+    /// user_agent = "my-broker/1.0"
+    /// request_id_header = "x-request-id"
+    ///
+    /// [headers]
+    /// x-proxy-token = "..."
+    ///
+    /// [credentials]
+    /// api_key = "..."
+    /// secret_key = "..."
+    /// ```
+    #[cfg(feature = "config_file")]
+    pub fn from_toml_file<P: AsRef<std::path::Path>>(path: P) -> Result<Config> {
+        let content = std::fs::read_to_string(path.as_ref())
+            .map_err(|e| ConfigError::File(format!("{}: {e}", path.as_ref().display())))?;
+        Config::from_toml_str(&content)
+    }
+
+    /// Configure binance from the content of a TOML file, see [`Config::from_toml_file`]
+    /// # Examples
+    /// ```
+    /// use binance::config::Config;
+    /// let config = Config::from_toml_str("recv_window = 10000\ntimeout = 5").unwrap();
+    /// assert_eq!(config.timeout, Some(5));
+    /// ```
+    #[cfg(feature = "config_file")]
+    pub fn from_toml_str(content: &str) -> Result<Config> {
+        let overrides: ConfigOverrides = toml::from_str(content).map_err(|e| ConfigError::File(e.to_string()))?;
+        overrides.apply(Config::default())
+    }
+
+    /// Start building a validated configuration from the default production endpoints
+    /// # Examples
+    /// ```
+    /// use binance::config::Config;
+    /// let config = Config::builder().recv_window(15000).timeout(10).build();
+    /// assert!(config.is_ok(), "{:?}", config);
+    /// ```
+    pub fn builder() -> ConfigBuilder { ConfigBuilder::default() }
+
+    /// Check that the endpoints are valid urls, and that the receive window and timeout are within bounds
+    pub fn validate(&self) -> Result<()> {
+        for (field, url, schemes) in [
+            ("rest api", self.rest_api_endpoint.as_str(), REST_SCHEMES),
+            ("websocket", &self.ws_endpoint, WS_SCHEMES),
+            ("sapi rest api", self.sapi_endpoint(), REST_SCHEMES),
+            ("futures rest api", &self.futures_rest_api_endpoint, REST_SCHEMES),
+            ("futures websocket", &self.futures_ws_endpoint, WS_SCHEMES),
+            (
+                "coin futures rest api",
+                &self.coin_futures_rest_api_endpoint,
+                REST_SCHEMES,
+            ),
+            ("coin futures websocket", &self.coin_futures_ws_endpoint, WS_SCHEMES),
+            ("options rest api", &self.options_rest_api_endpoint, REST_SCHEMES),
+            ("options websocket", &self.options_ws_endpoint, WS_SCHEMES),
+        ] {
+            validate_url(field, url, schemes)?;
+        }
+        if self.recv_window == 0 || self.recv_window > MAX_RECV_WINDOW {
+            return Err(ConfigError::RecvWindowOutOfRange(self.recv_window).into());
+        }
+        if self.timeout == Some(0) {
+            return Err(ConfigError::ZeroTimeout.into());
+        }
+        if let Some(proxy) = &self.proxy {
+            validate_url("proxy", proxy, PROXY_SCHEMES)?;
+        }
+        self.default_headers()?;
+        if let Some(name) = &self.request_id_header {
+            HeaderName::from_bytes(name.as_bytes()).map_err(|_| ConfigError::InvalidHeader(name.clone()))?;
+        }
+        if self.binance_us_api {
+            let host = Url::parse(&self.rest_api_endpoint)
+                .ok()
+                .and_then(|url| url.host_str().map(str::to_string))
+                .unwrap_or_default();
+            if host.ends_with("binance.com") || host.ends_with("binance.vision") {
+                return Err(ConfigError::BinanceUsEndpoint(self.rest_api_endpoint.clone()).into());
+            }
+        }
+        Ok(())
     }
 
     /// Sets the rest api endpoint. Defaults to <https://api.binance.com>.
@@ -70,6 +418,26 @@ impl Config {
         self
     }
 
+    /// Sets the host of the `/sapi` endpoints. Defaults to the rest api endpoint.
+    ///
+    /// # Arguments
+    ///
+    /// * `sapi_rest_api_endpoint`:
+    ///
+    /// returns: Config
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use binance::config::Config;
+    /// let config = Config::default();
+    /// config.set_sapi_rest_api_endpoint("http://myendpoint:8080");
+    /// ```
+    pub fn set_sapi_rest_api_endpoint<T: Into<String>>(mut self, sapi_rest_api_endpoint: T) -> Self {
+        self.sapi_rest_api_endpoint = Some(sapi_rest_api_endpoint.into());
+        self
+    }
+
     /// Sets the futures rest api endpoint. Defaults to <https://fapi.binance.com>.
     ///
     /// # Arguments
@@ -110,6 +478,86 @@ impl Config {
         self
     }
 
+    /// Sets the coin futures rest api endpoint. Defaults to <https://dapi.binance.com>.
+    ///
+    /// # Arguments
+    ///
+    /// * `coin_futures_rest_api_endpoint`:
+    ///
+    /// returns: Config
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use binance::config::Config;
+    /// let config = Config::default();
+    /// config.set_coin_futures_rest_api_endpoint("http://myendpoint:8080");
+    /// ```
+    pub fn set_coin_futures_rest_api_endpoint<T: Into<String>>(mut self, coin_futures_rest_api_endpoint: T) -> Self {
+        self.coin_futures_rest_api_endpoint = coin_futures_rest_api_endpoint.into();
+        self
+    }
+
+    /// Sets the coin futures websocket endpoint. Defaults to "wss://dstream.binance.com".
+    ///
+    /// # Arguments
+    ///
+    /// * `coin_futures_ws_endpoint`:
+    ///
+    /// returns: Config
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use binance::config::Config;
+    /// let config = Config::default();
+    /// config.set_coin_futures_ws_endpoint("ws://myendpoint:8080");
+    /// ```
+    pub fn set_coin_futures_ws_endpoint<T: Into<String>>(mut self, coin_futures_ws_endpoint: T) -> Self {
+        self.coin_futures_ws_endpoint = coin_futures_ws_endpoint.into();
+        self
+    }
+
+    /// Sets the options rest api endpoint. Defaults to <https://eapi.binance.com>.
+    ///
+    /// # Arguments
+    ///
+    /// * `options_rest_api_endpoint`:
+    ///
+    /// returns: Config
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use binance::config::Config;
+    /// let config = Config::default();
+    /// config.set_options_rest_api_endpoint("http://myendpoint:8080");
+    /// ```
+    pub fn set_options_rest_api_endpoint<T: Into<String>>(mut self, options_rest_api_endpoint: T) -> Self {
+        self.options_rest_api_endpoint = options_rest_api_endpoint.into();
+        self
+    }
+
+    /// Sets the options websocket endpoint. Defaults to "wss://nbstream.binance.com/eoptions".
+    ///
+    /// # Arguments
+    ///
+    /// * `options_ws_endpoint`:
+    ///
+    /// returns: Config
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use binance::config::Config;
+    /// let config = Config::default();
+    /// config.set_options_ws_endpoint("ws://myendpoint:8080");
+    /// ```
+    pub fn set_options_ws_endpoint<T: Into<String>>(mut self, options_ws_endpoint: T) -> Self {
+        self.options_ws_endpoint = options_ws_endpoint.into();
+        self
+    }
+
     /// Sets the 'receive window'. The receive window is the number of milliseconds after timestamp
     /// the request is valid for.
     ///
@@ -150,6 +598,214 @@ impl Config {
         self.timeout = Some(timeout);
         self
     }
+
+    /// Sets the User-Agent of the REST requests and websocket handshakes. Defaults to "binance-rs".
+    ///
+    /// # Arguments
+    ///
+    /// * `user_agent`:
+    ///
+    /// returns: Config
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use binance::config::Config;
+    /// let config = Config::default();
+    /// config.set_user_agent("my-broker/1.0");
+    /// ```
+    pub fn set_user_agent<T: Into<String>>(mut self, user_agent: T) -> Self {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+
+    /// Adds a header to every REST request and websocket handshake
+    ///
+    /// # Arguments
+    ///
+    /// * `name`: The header name
+    /// * `value`: The header value
+    ///
+    /// returns: Config
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use binance::config::Config;
+    /// let config = Config::default();
+    /// config.add_header("x-proxy-token", "secret");
+    /// ```
+    pub fn add_header<N: Into<String>, V: Into<String>>(mut self, name: N, value: V) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Sends the id generated for each REST request in the header `name`, e.g. to correlate the requests with the
+    /// logs of a proxy. The id is always logged with the `tracing` feature and set in the errors, see
+    /// [`crate::errors::ResponseContext::client_request_id`].
+    ///
+    /// # Arguments
+    ///
+    /// * `name`: The header name
+    ///
+    /// returns: Config
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use binance::config::Config;
+    /// let config = Config::default();
+    /// config.set_request_id_header("x-request-id");
+    /// ```
+    pub fn set_request_id_header<N: Into<String>>(mut self, name: N) -> Self {
+        self.request_id_header = Some(name.into());
+        self
+    }
+
+    /// Sets the signer of the `SIGNED` requests, the secret key given to the gateways is then not used
+    ///
+    /// # Arguments
+    ///
+    /// * `signer`: See [`crate::signer::RequestSigner`]
+    ///
+    /// returns: Config
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use binance::{config::Config, signer::HmacSigner};
+    /// let config = Config::default();
+    /// config.set_signer(HmacSigner::new("secret"));
+    /// ```
+    pub fn set_signer<S: RequestSigner + 'static>(mut self, signer: S) -> Self {
+        self.signer = Some(SharedSigner::new(signer));
+        self
+    }
+
+    /// Sets the limits the gateways wait for before sending a request, see [`crate::account_pool::AccountPool`]
+    pub fn set_rate_limits(mut self, rate_limits: RateLimits) -> Self {
+        self.rate_limits = Some(rate_limits);
+        self
+    }
+
+    /// Sets credentials shared by all the gateways created with this configuration, they take precedence over the
+    /// keys given to the gateways and rotating them affects the next requests of all the gateways
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use binance::{api::*, config::*, market::*};
+    /// let credentials = SharedCredentials::new("api key", "secret key");
+    /// let config = Config::default().set_shared_credentials(credentials.clone());
+    /// let market: Market = Binance::new_with_config(None, None, &config);
+    /// credentials.rotate("new api key", "new secret key");
+    /// assert_eq!(market.client.credentials.get().api_key, "new api key");
+    /// ```
+    pub fn set_shared_credentials(mut self, credentials: SharedCredentials) -> Self {
+        self.shared_credentials = Some(credentials);
+        self
+    }
+
+    /// Check the permissions of the key before calling restricted endpoints, see [`crate::permissions`]
+    ///
+    /// The permissions are fetched from the wallet endpoint of the configuration, endpoints set afterwards are not
+    /// taken into account.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use binance::config::Config;
+    /// let config = Config::testnet().with_permission_preflight();
+    /// ```
+    pub fn with_permission_preflight(mut self) -> Self {
+        self.permission_preflight = Some(PermissionPreflight::new(&self));
+        self
+    }
+
+    /// Report the fields of the REST responses which the models do not declare, see [`crate::schema_drift`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use binance::{config::Config, schema_drift::UnknownField};
+    /// let config = Config::default().set_schema_drift_reporter(|field: &UnknownField| eprintln!("{field}"));
+    /// ```
+    #[cfg(feature = "schema_drift")]
+    pub fn set_schema_drift_reporter<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&UnknownField) + Send + Sync + 'static,
+    {
+        self.schema_drift_reporter = Some(SchemaDriftReporter::new(callback));
+        self
+    }
+
+    /// User-Agent and custom headers, as sent with every request
+    /// # Examples
+    /// ```
+    /// use binance::config::Config;
+    /// let headers = Config::default().set_user_agent("my-broker/1.0").default_headers().unwrap();
+    /// assert_eq!(headers["user-agent"], "my-broker/1.0");
+    /// assert!(Config::default().add_header("invalid name", "").default_headers().is_err());
+    /// ```
+    pub fn default_headers(&self) -> Result<HeaderMap> {
+        let invalid = |name: &str| ConfigError::InvalidHeader(name.to_string());
+        let mut headers = HeaderMap::new();
+        let user_agent = self.user_agent.as_deref().unwrap_or(DEFAULT_USER_AGENT);
+        headers.insert(
+            USER_AGENT,
+            HeaderValue::from_str(user_agent).map_err(|_| invalid(USER_AGENT.as_str()))?,
+        );
+        for (name, value) in &self.headers {
+            headers.append(
+                HeaderName::from_bytes(name.as_bytes()).map_err(|_| invalid(name))?,
+                HeaderValue::from_str(value).map_err(|_| invalid(name))?,
+            );
+        }
+        Ok(headers)
+    }
+
+    /// Sets the reqwest client used by every gateway created with this configuration, so they share
+    /// its connection pool and TLS sessions. The timeout and proxy of the configuration are not applied to it.
+    ///
+    /// # Arguments
+    ///
+    /// * `http_client`: The client, built by the caller
+    ///
+    /// returns: Config
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use binance::config::Config;
+    /// let config = Config::default();
+    /// config.set_http_client(reqwest::Client::new());
+    /// ```
+    pub fn set_http_client(mut self, http_client: reqwest::Client) -> Self {
+        self.http_client = Some(HttpClient::new(http_client));
+        self
+    }
+
+    /// Build one reqwest client with the timeout and proxy of this configuration, shared by every gateway
+    /// created with it afterwards.
+    ///
+    /// The connections of the pool belong to the tokio runtime they were opened on,
+    /// the gateways sharing it should run on the same runtime.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use binance::{account::*, api::*, config::*, market::*};
+    /// let config = Config::default().set_timeout(10).with_shared_http_client();
+    /// let account: Account = Binance::new_with_config(None, None, &config);
+    /// let market: Market = Binance::new_with_config(None, None, &config);
+    /// ```
+    pub fn with_shared_http_client(mut self) -> Self {
+        self.http_client = Some(HttpClient::new(crate::client::http_client(
+            self.timeout,
+            self.proxy.as_deref(),
+        )));
+        self
+    }
 }
 
 impl Default for Config {
@@ -164,13 +820,280 @@ impl Default for Config {
             rest_api_endpoint: "https://api.binance.com".into(),
             ws_endpoint: "wss://stream.binance.com:9443".into(),
 
+            sapi_rest_api_endpoint: None,
+
             futures_rest_api_endpoint: "https://fapi.binance.com".into(),
             futures_ws_endpoint: "wss://fstream.binance.com".into(),
 
+            coin_futures_rest_api_endpoint: "https://dapi.binance.com".into(),
+            coin_futures_ws_endpoint: "wss://dstream.binance.com".into(),
+
+            options_rest_api_endpoint: "https://eapi.binance.com".into(),
+            options_ws_endpoint: "wss://nbstream.binance.com/eoptions".into(),
+
             recv_window: 5000,
             binance_us_api: false,
 
             timeout: None,
+
+            proxy: None,
+            credentials: None,
+            http_client: None,
+            user_agent: None,
+            headers: vec![],
+            request_id_header: None,
+            signer: None,
+            rate_limits: None,
+            shared_credentials: None,
+            permission_preflight: None,
+            #[cfg(feature = "schema_drift")]
+            schema_drift_reporter: None,
+        }
+    }
+}
+
+static REST_SCHEMES: &[&str] = &["http", "https"];
+static WS_SCHEMES: &[&str] = &["ws", "wss"];
+static PROXY_SCHEMES: &[&str] = &["http", "https", "socks5", "socks5h"];
+
+fn validate_url(field: &'static str, url: &str, schemes: &[&str]) -> Result<()> {
+    let invalid = |reason: String| ConfigError::InvalidUrl {
+        field,
+        url: url.to_string(),
+        reason,
+    };
+    let parsed = Url::parse(url).map_err(|e| invalid(e.to_string()))?;
+    if !schemes.contains(&parsed.scheme()) {
+        return Err(invalid(format!("scheme must be one of {}", schemes.join(", "))).into());
+    }
+    Ok(())
+}
+
+/// Builds a [`Config`], validated by [`ConfigBuilder::build`]
+///
+/// # Examples
+/// ```
+/// use binance::config::{Config, ConfigBuilder};
+/// let config = ConfigBuilder::from_config(Config::testnet()).recv_window(10000).build();
+/// assert!(config.is_ok(), "{:?}", config);
+/// let invalid = Config::builder().recv_window(120_000).build();
+/// assert!(invalid.is_err());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ConfigBuilder {
+    config: Config,
+}
+
+impl ConfigBuilder {
+    /// Start from an existing configuration, e.g. [`Config::testnet`]
+    pub fn from_config(config: Config) -> Self { Self { config } }
+
+    pub fn rest_api_endpoint<T: Into<String>>(mut self, rest_api_endpoint: T) -> Self {
+        self.config.rest_api_endpoint = rest_api_endpoint.into();
+        self
+    }
+
+    pub fn ws_endpoint<T: Into<String>>(mut self, ws_endpoint: T) -> Self {
+        self.config.ws_endpoint = ws_endpoint.into();
+        self
+    }
+
+    pub fn sapi_rest_api_endpoint<T: Into<String>>(mut self, sapi_rest_api_endpoint: T) -> Self {
+        self.config.sapi_rest_api_endpoint = Some(sapi_rest_api_endpoint.into());
+        self
+    }
+
+    pub fn futures_rest_api_endpoint<T: Into<String>>(mut self, futures_rest_api_endpoint: T) -> Self {
+        self.config.futures_rest_api_endpoint = futures_rest_api_endpoint.into();
+        self
+    }
+
+    pub fn futures_ws_endpoint<T: Into<String>>(mut self, futures_ws_endpoint: T) -> Self {
+        self.config.futures_ws_endpoint = futures_ws_endpoint.into();
+        self
+    }
+
+    pub fn coin_futures_rest_api_endpoint<T: Into<String>>(mut self, coin_futures_rest_api_endpoint: T) -> Self {
+        self.config.coin_futures_rest_api_endpoint = coin_futures_rest_api_endpoint.into();
+        self
+    }
+
+    pub fn coin_futures_ws_endpoint<T: Into<String>>(mut self, coin_futures_ws_endpoint: T) -> Self {
+        self.config.coin_futures_ws_endpoint = coin_futures_ws_endpoint.into();
+        self
+    }
+
+    pub fn options_rest_api_endpoint<T: Into<String>>(mut self, options_rest_api_endpoint: T) -> Self {
+        self.config.options_rest_api_endpoint = options_rest_api_endpoint.into();
+        self
+    }
+
+    pub fn options_ws_endpoint<T: Into<String>>(mut self, options_ws_endpoint: T) -> Self {
+        self.config.options_ws_endpoint = options_ws_endpoint.into();
+        self
+    }
+
+    /// Receive window in milliseconds, at most [`MAX_RECV_WINDOW`]
+    pub fn recv_window(mut self, recv_window: u64) -> Self {
+        self.config.recv_window = recv_window;
+        self
+    }
+
+    /// Client timeout in seconds
+    pub fn timeout(mut self, timeout: u64) -> Self {
+        self.config.timeout = Some(timeout);
+        self
+    }
+
+    /// Route the requests for Binance.US, cannot be combined with binance.com endpoints
+    pub fn binance_us_api(mut self, binance_us_api: bool) -> Self {
+        self.config.binance_us_api = binance_us_api;
+        self
+    }
+
+    /// Proxy for all the REST requests
+    pub fn proxy<T: Into<String>>(mut self, proxy: T) -> Self {
+        self.config.proxy = Some(proxy.into());
+        self
+    }
+
+    pub fn user_agent<T: Into<String>>(mut self, user_agent: T) -> Self {
+        self.config.user_agent = Some(user_agent.into());
+        self
+    }
+
+    /// Header added to every REST request and websocket handshake
+    pub fn header<N: Into<String>, V: Into<String>>(mut self, name: N, value: V) -> Self {
+        self.config.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Header the id of each REST request is sent in, see [`Config::set_request_id_header`]
+    pub fn request_id_header<N: Into<String>>(mut self, name: N) -> Self {
+        self.config.request_id_header = Some(name.into());
+        self
+    }
+
+    /// Signer of the `SIGNED` requests, see [`Config::set_signer`]
+    pub fn signer<S: RequestSigner + 'static>(mut self, signer: S) -> Self {
+        self.config.signer = Some(SharedSigner::new(signer));
+        self
+    }
+
+    /// Share a reqwest client between the gateways, see [`Config::set_http_client`]
+    pub fn http_client(mut self, http_client: reqwest::Client) -> Self {
+        self.config.http_client = Some(HttpClient::new(http_client));
+        self
+    }
+
+    pub fn credentials<T: Into<String>>(mut self, api_key: T, secret_key: T) -> Self {
+        self.config.credentials = Some(Credentials {
+            api_key: api_key.into(),
+            secret_key: secret_key.into(),
+        });
+        self
+    }
+
+    pub fn build(self) -> Result<Config> {
+        self.config.validate()?;
+        Ok(self.config)
+    }
+}
+
+/// Fields read from the environment or a configuration file, unset fields keep their current value
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ConfigOverrides {
+    rest_api_endpoint: Option<String>,
+    ws_endpoint: Option<String>,
+    sapi_rest_api_endpoint: Option<String>,
+    futures_rest_api_endpoint: Option<String>,
+    futures_ws_endpoint: Option<String>,
+    coin_futures_rest_api_endpoint: Option<String>,
+    coin_futures_ws_endpoint: Option<String>,
+    options_rest_api_endpoint: Option<String>,
+    options_ws_endpoint: Option<String>,
+    recv_window: Option<u64>,
+    binance_us_api: Option<bool>,
+    timeout: Option<u64>,
+    proxy: Option<String>,
+    credentials: Option<Credentials>,
+    user_agent: Option<String>,
+    #[serde(default)]
+    headers: std::collections::BTreeMap<String, String>,
+    request_id_header: Option<String>,
+}
+
+impl ConfigOverrides {
+    fn from_env() -> Result<Self> {
+        let credentials = match (var("BINANCE_API_KEY"), var("BINANCE_API_SECRET_KEY")) {
+            (Some(api_key), Some(secret_key)) => Some(Credentials { api_key, secret_key }),
+            _ => None,
+        };
+        Ok(Self {
+            rest_api_endpoint: var("BINANCE_REST_API_ENDPOINT"),
+            ws_endpoint: var("BINANCE_WS_ENDPOINT"),
+            sapi_rest_api_endpoint: var("BINANCE_SAPI_REST_API_ENDPOINT"),
+            futures_rest_api_endpoint: var("BINANCE_FUTURES_REST_API_ENDPOINT"),
+            futures_ws_endpoint: var("BINANCE_FUTURES_WS_ENDPOINT"),
+            coin_futures_rest_api_endpoint: var("BINANCE_COIN_FUTURES_REST_API_ENDPOINT"),
+            coin_futures_ws_endpoint: var("BINANCE_COIN_FUTURES_WS_ENDPOINT"),
+            options_rest_api_endpoint: var("BINANCE_OPTIONS_REST_API_ENDPOINT"),
+            options_ws_endpoint: var("BINANCE_OPTIONS_WS_ENDPOINT"),
+            recv_window: parsed_var("BINANCE_RECV_WINDOW")?,
+            binance_us_api: parsed_var("BINANCE_US_API")?,
+            timeout: parsed_var("BINANCE_TIMEOUT")?,
+            proxy: var("BINANCE_PROXY"),
+            credentials,
+            user_agent: var("BINANCE_USER_AGENT"),
+            headers: Default::default(),
+            request_id_header: var("BINANCE_REQUEST_ID_HEADER"),
+        })
+    }
+
+    fn apply(self, mut config: Config) -> Result<Config> {
+        fn set<T>(field: &mut T, value: Option<T>) {
+            if let Some(value) = value {
+                *field = value;
+            }
         }
+        set(&mut config.rest_api_endpoint, self.rest_api_endpoint);
+        set(&mut config.ws_endpoint, self.ws_endpoint);
+        set(&mut config.futures_rest_api_endpoint, self.futures_rest_api_endpoint);
+        set(&mut config.futures_ws_endpoint, self.futures_ws_endpoint);
+        set(
+            &mut config.coin_futures_rest_api_endpoint,
+            self.coin_futures_rest_api_endpoint,
+        );
+        set(&mut config.coin_futures_ws_endpoint, self.coin_futures_ws_endpoint);
+        set(&mut config.options_rest_api_endpoint, self.options_rest_api_endpoint);
+        set(&mut config.options_ws_endpoint, self.options_ws_endpoint);
+        config.sapi_rest_api_endpoint = self.sapi_rest_api_endpoint.or(config.sapi_rest_api_endpoint);
+        set(&mut config.recv_window, self.recv_window);
+        set(&mut config.binance_us_api, self.binance_us_api);
+        config.timeout = self.timeout.or(config.timeout);
+        config.proxy = self.proxy.or(config.proxy);
+        config.credentials = self.credentials.or(config.credentials);
+        config.user_agent = self.user_agent.or(config.user_agent);
+        config.headers.extend(self.headers);
+        config.request_id_header = self.request_id_header.or(config.request_id_header);
+        config.validate()?;
+        Ok(config)
     }
 }
+
+fn var(name: &str) -> Option<String> { std::env::var(name).ok().filter(|value| !value.is_empty()) }
+
+fn parsed_var<T: std::str::FromStr>(name: &'static str) -> Result<Option<T>> {
+    var(name)
+        .map(|value| {
+            value.parse().map_err(|_| {
+                ConfigError::InvalidVar {
+                    name: name.to_string(),
+                    value,
+                }
+                .into()
+            })
+        })
+        .transpose()
+}
@@ -0,0 +1,410 @@
+//! Convert endpoints, zero fee conversions between assets through a quote to accept,
+//! or through limit orders filled once the limit price is reached.
+
+use std::collections::HashMap;
+
+use crate::client::*;
+use crate::errors::*;
+use crate::rest_model::{string_or_float, string_or_u64, OrderSide};
+
+static SAPI_V1_CONVERT_EXCHANGE_INFO: &str = "/sapi/v1/convert/exchangeInfo";
+static SAPI_V1_CONVERT_ASSET_INFO: &str = "/sapi/v1/convert/assetInfo";
+static SAPI_V1_CONVERT_GET_QUOTE: &str = "/sapi/v1/convert/getQuote";
+static SAPI_V1_CONVERT_ACCEPT_QUOTE: &str = "/sapi/v1/convert/acceptQuote";
+static SAPI_V1_CONVERT_ORDER_STATUS: &str = "/sapi/v1/convert/orderStatus";
+static SAPI_V1_CONVERT_TRADE_FLOW: &str = "/sapi/v1/convert/tradeFlow";
+static SAPI_V1_CONVERT_LIMIT_PLACE_ORDER: &str = "/sapi/v1/convert/limit/placeOrder";
+static SAPI_V1_CONVERT_LIMIT_QUERY_OPEN_ORDERS: &str = "/sapi/v1/convert/limit/queryOpenOrders";
+static SAPI_V1_CONVERT_LIMIT_CANCEL_ORDER: &str = "/sapi/v1/convert/limit/cancelOrder";
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ConvertPairQuery {
+    /// At least one of from_asset and to_asset must be sent
+    pub from_asset: Option<String>,
+    pub to_asset: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ConvertPair {
+    pub from_asset: String,
+    pub to_asset: String,
+    #[serde(with = "string_or_float")]
+    pub from_asset_min_amount: f64,
+    #[serde(with = "string_or_float")]
+    pub from_asset_max_amount: f64,
+    #[serde(with = "string_or_float")]
+    pub to_asset_min_amount: f64,
+    #[serde(with = "string_or_float")]
+    pub to_asset_max_amount: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ConvertAssetPrecision {
+    pub asset: String,
+    /// Number of decimals of the amounts
+    pub fraction: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ConvertWalletType {
+    Spot,
+    Funding,
+    /// Spot first, then funding
+    SpotFunding,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub enum QuoteValidTime {
+    #[serde(rename = "10s")]
+    TenSeconds,
+    #[serde(rename = "30s")]
+    ThirtySeconds,
+    #[serde(rename = "1m")]
+    OneMinute,
+    #[serde(rename = "2m")]
+    TwoMinutes,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ConvertQuoteQuery {
+    pub from_asset: String,
+    pub to_asset: String,
+    /// Amount to convert, either from_amount or to_amount must be sent
+    pub from_amount: Option<f64>,
+    /// Amount to receive, either from_amount or to_amount must be sent
+    pub to_amount: Option<f64>,
+    /// Default: Spot
+    pub wallet_type: Option<ConvertWalletType>,
+    /// Default: 10s
+    pub valid_time: Option<QuoteValidTime>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ConvertQuote {
+    pub quote_id: String,
+    /// to_asset received per from_asset
+    #[serde(with = "string_or_float")]
+    pub ratio: f64,
+    #[serde(with = "string_or_float")]
+    pub inverse_ratio: f64,
+    /// The quote can be accepted until this time
+    pub valid_timestamp: u64,
+    #[serde(with = "string_or_float")]
+    pub to_amount: f64,
+    #[serde(with = "string_or_float")]
+    pub from_amount: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AcceptedQuote {
+    #[serde(with = "string_or_u64")]
+    pub order_id: u64,
+    pub create_time: u64,
+    /// PROCESS, ACCEPT_SUCCESS, SUCCESS or FAIL
+    pub order_status: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ConvertOrder {
+    /// Only returned by the trade history
+    pub quote_id: Option<String>,
+    #[serde(with = "string_or_u64")]
+    pub order_id: u64,
+    /// PROCESS, ACCEPT_SUCCESS, SUCCESS or FAIL
+    pub order_status: String,
+    pub from_asset: String,
+    #[serde(with = "string_or_float")]
+    pub from_amount: f64,
+    pub to_asset: String,
+    #[serde(with = "string_or_float")]
+    pub to_amount: f64,
+    #[serde(with = "string_or_float")]
+    pub ratio: f64,
+    #[serde(with = "string_or_float")]
+    pub inverse_ratio: f64,
+    pub create_time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ConvertTradeFlowQuery {
+    pub start_time: u64,
+    /// At most 30 days after start_time
+    pub end_time: u64,
+    /// Default: 100, Max: 1000
+    pub limit: Option<u32>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ConvertTradeFlow {
+    pub list: Vec<ConvertOrder>,
+    pub start_time: u64,
+    pub end_time: u64,
+    pub limit: u32,
+    /// More trades are available in the time range than the limit
+    pub more_data: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub enum ConvertLimitExpiry {
+    #[serde(rename = "1_D")]
+    OneDay,
+    #[serde(rename = "3_D")]
+    ThreeDays,
+    #[serde(rename = "7_D")]
+    SevenDays,
+    #[serde(rename = "30_D")]
+    ThirtyDays,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ConvertLimitOrderQuery {
+    pub base_asset: String,
+    pub quote_asset: String,
+    /// Price of the base asset in the quote asset
+    pub limit_price: f64,
+    /// Either base_amount or quote_amount must be sent
+    pub base_amount: Option<f64>,
+    /// Either base_amount or quote_amount must be sent
+    pub quote_amount: Option<f64>,
+    pub side: OrderSide,
+    /// Default: Spot
+    pub wallet_type: Option<ConvertWalletType>,
+    pub expired_type: ConvertLimitExpiry,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ConvertLimitOrderResponse {
+    pub quote_id: Option<String>,
+    #[serde(with = "string_or_u64")]
+    pub order_id: u64,
+    pub status: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ConvertLimitOrder {
+    pub quote_id: Option<String>,
+    #[serde(with = "string_or_u64")]
+    pub order_id: u64,
+    pub order_status: String,
+    pub from_asset: String,
+    #[serde(with = "string_or_float")]
+    pub from_amount: f64,
+    pub to_asset: String,
+    #[serde(with = "string_or_float")]
+    pub to_amount: f64,
+    #[serde(with = "string_or_float")]
+    pub ratio: f64,
+    #[serde(with = "string_or_float")]
+    pub inverse_ratio: f64,
+    pub create_time: u64,
+    pub expired_timestamp: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct ConvertLimitOrders {
+    list: Vec<ConvertLimitOrder>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CanceledConvertLimitOrder {
+    #[serde(with = "string_or_u64")]
+    pub order_id: u64,
+    pub status: String,
+}
+
+/// This struct acts as a gateway for all convert endpoints.
+/// Preferably use the trait [`crate::api::Binance`] to get an instance.
+#[derive(Clone)]
+pub struct Convert {
+    pub client: Client,
+    pub recv_window: u64,
+}
+
+impl Convert {
+    /// Pairs available for conversion and their amount limits
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, convert::*, config::*};
+    /// let convert: Convert = Binance::new_with_env(&Config::default());
+    /// let query = ConvertPairQuery {
+    ///     from_asset: Some("BTC".to_string()),
+    ///     ..Default::default()
+    /// };
+    /// let pairs = tokio_test::block_on(convert.exchange_info(query));
+    /// assert!(pairs.is_ok(), "{:?}", pairs);
+    /// ```
+    pub async fn exchange_info(&self, query: ConvertPairQuery) -> Result<Vec<ConvertPair>> {
+        self.client.get_d(SAPI_V1_CONVERT_EXCHANGE_INFO, Some(query)).await
+    }
+
+    /// Precision of the amounts of each asset
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, convert::*, config::*};
+    /// let convert: Convert = Binance::new_with_env(&Config::default());
+    /// let assets = tokio_test::block_on(convert.asset_info());
+    /// assert!(assets.is_ok(), "{:?}", assets);
+    /// ```
+    pub async fn asset_info(&self) -> Result<Vec<ConvertAssetPrecision>> {
+        self.client
+            .get_signed_p(SAPI_V1_CONVERT_ASSET_INFO, Option::<String>::None, self.recv_window)
+            .await
+    }
+
+    /// Request a quote, to accept with [`Convert::accept_quote`] before it expires
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, convert::*, config::*};
+    /// let convert: Convert = Binance::new_with_env(&Config::default());
+    /// let query = ConvertQuoteQuery {
+    ///     from_asset: "USDT".to_string(),
+    ///     to_asset: "BTC".to_string(),
+    ///     from_amount: Some(100.0),
+    ///     ..Default::default()
+    /// };
+    /// let quote = tokio_test::block_on(convert.get_quote(query));
+    /// assert!(quote.is_ok(), "{:?}", quote);
+    /// ```
+    pub async fn get_quote(&self, query: ConvertQuoteQuery) -> Result<ConvertQuote> {
+        self.client
+            .post_signed_p(SAPI_V1_CONVERT_GET_QUOTE, Some(query), self.recv_window)
+            .await
+    }
+
+    /// Accept a quote, which places a convert order
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, convert::*, config::*};
+    /// let convert: Convert = Binance::new_with_env(&Config::default());
+    /// let order = tokio_test::block_on(convert.accept_quote("12415572564"));
+    /// assert!(order.is_ok(), "{:?}", order);
+    /// ```
+    pub async fn accept_quote<S>(&self, quote_id: S) -> Result<AcceptedQuote>
+    where
+        S: Into<String>,
+    {
+        let mut params = HashMap::new();
+        params.insert("quoteId", quote_id.into());
+        self.client
+            .post_signed_p(SAPI_V1_CONVERT_ACCEPT_QUOTE, Some(params), self.recv_window)
+            .await
+    }
+
+    /// Status of a convert order
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, convert::*, config::*};
+    /// let convert: Convert = Binance::new_with_env(&Config::default());
+    /// let order = tokio_test::block_on(convert.order_status(933256278426274426));
+    /// assert!(order.is_ok(), "{:?}", order);
+    /// ```
+    pub async fn order_status(&self, order_id: u64) -> Result<ConvertOrder> {
+        let mut params = HashMap::new();
+        params.insert("orderId", order_id);
+        self.client
+            .get_signed_p(SAPI_V1_CONVERT_ORDER_STATUS, Some(params), self.recv_window)
+            .await
+    }
+
+    /// Convert trades over at most 30 days
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, convert::*, config::*};
+    /// let convert: Convert = Binance::new_with_env(&Config::default());
+    /// let query = ConvertTradeFlowQuery {
+    ///     start_time: 1_623_319_461_670,
+    ///     end_time: 1_625_911_461_670,
+    ///     limit: None,
+    /// };
+    /// let trades = tokio_test::block_on(convert.trade_history(query));
+    /// assert!(trades.is_ok(), "{:?}", trades);
+    /// ```
+    pub async fn trade_history(&self, query: ConvertTradeFlowQuery) -> Result<ConvertTradeFlow> {
+        self.client
+            .get_signed_p(SAPI_V1_CONVERT_TRADE_FLOW, Some(query), self.recv_window)
+            .await
+    }
+
+    /// Place a limit order, filled once the conversion ratio reaches the limit price
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, convert::*, config::*, rest_model::OrderSide};
+    /// let convert: Convert = Binance::new_with_env(&Config::default());
+    /// let query = ConvertLimitOrderQuery {
+    ///     base_asset: "BTC".to_string(),
+    ///     quote_asset: "USDT".to_string(),
+    ///     limit_price: 20000.0,
+    ///     base_amount: None,
+    ///     quote_amount: Some(100.0),
+    ///     side: OrderSide::Buy,
+    ///     wallet_type: None,
+    ///     expired_type: ConvertLimitExpiry::SevenDays,
+    /// };
+    /// let order = tokio_test::block_on(convert.place_limit_order(query));
+    /// assert!(order.is_ok(), "{:?}", order);
+    /// ```
+    pub async fn place_limit_order(&self, query: ConvertLimitOrderQuery) -> Result<ConvertLimitOrderResponse> {
+        self.client
+            .post_signed_p(SAPI_V1_CONVERT_LIMIT_PLACE_ORDER, Some(query), self.recv_window)
+            .await
+    }
+
+    /// Limit orders which are not filled, canceled or expired yet
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, convert::*, config::*};
+    /// let convert: Convert = Binance::new_with_env(&Config::default());
+    /// let orders = tokio_test::block_on(convert.open_limit_orders());
+    /// assert!(orders.is_ok(), "{:?}", orders);
+    /// ```
+    pub async fn open_limit_orders(&self) -> Result<Vec<ConvertLimitOrder>> {
+        let orders: ConvertLimitOrders = self
+            .client
+            .post_signed_p(
+                SAPI_V1_CONVERT_LIMIT_QUERY_OPEN_ORDERS,
+                Option::<String>::None,
+                self.recv_window,
+            )
+            .await?;
+        Ok(orders.list)
+    }
+
+    /// Cancel a limit order
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, convert::*, config::*};
+    /// let convert: Convert = Binance::new_with_env(&Config::default());
+    /// let canceled = tokio_test::block_on(convert.cancel_limit_order(1603680255057330400));
+    /// assert!(canceled.is_ok(), "{:?}", canceled);
+    /// ```
+    pub async fn cancel_limit_order(&self, order_id: u64) -> Result<CanceledConvertLimitOrder> {
+        let mut params = HashMap::new();
+        params.insert("orderId", order_id);
+        self.client
+            .post_signed_p(SAPI_V1_CONVERT_LIMIT_CANCEL_ORDER, Some(params), self.recv_window)
+            .await
+    }
+}
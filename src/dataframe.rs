@@ -0,0 +1,125 @@
+//! Conversion of market data into Arrow record batches, enabled with the `dataframe` feature.
+//!
+//! Record batches can be handed to any Arrow based library, e.g. Polars with `DataFrame::try_from`.
+
+use std::sync::Arc;
+
+use arrow_array::{ArrayRef, BooleanArray, Float64Array, Int64Array, RecordBatch, StringArray, UInt64Array};
+
+use crate::errors::*;
+use crate::rest_model::{AggTrade, KlineSummaries, KlineSummary, SymbolPrice, Tickers};
+
+/// Columnar export of a collection of market data records
+pub trait ToRecordBatch {
+    fn to_record_batch(&self) -> Result<RecordBatch>;
+}
+
+fn f64_column<T>(rows: &[T], value: impl Fn(&T) -> f64) -> ArrayRef {
+    Arc::new(rows.iter().map(value).collect::<Float64Array>())
+}
+
+fn i64_column<T>(rows: &[T], value: impl Fn(&T) -> i64) -> ArrayRef {
+    Arc::new(rows.iter().map(value).collect::<Int64Array>())
+}
+
+fn u64_column<T>(rows: &[T], value: impl Fn(&T) -> u64) -> ArrayRef {
+    Arc::new(rows.iter().map(value).collect::<UInt64Array>())
+}
+
+fn bool_column<T>(rows: &[T], value: impl Fn(&T) -> bool) -> ArrayRef {
+    Arc::new(rows.iter().map(|row| Some(value(row))).collect::<BooleanArray>())
+}
+
+fn string_column<T>(rows: &[T], value: impl Fn(&T) -> &str) -> ArrayRef {
+    Arc::new(rows.iter().map(value).map(Some).collect::<StringArray>())
+}
+
+impl ToRecordBatch for [KlineSummary] {
+    fn to_record_batch(&self) -> Result<RecordBatch> {
+        Ok(RecordBatch::try_from_iter([
+            ("open_time", i64_column(self, |k| k.open_time)),
+            ("open", f64_column(self, |k| k.open)),
+            ("high", f64_column(self, |k| k.high)),
+            ("low", f64_column(self, |k| k.low)),
+            ("close", f64_column(self, |k| k.close)),
+            ("volume", f64_column(self, |k| k.volume)),
+            ("close_time", i64_column(self, |k| k.close_time)),
+            ("quote_asset_volume", f64_column(self, |k| k.quote_asset_volume)),
+            ("number_of_trades", i64_column(self, |k| k.number_of_trades)),
+            (
+                "taker_buy_base_asset_volume",
+                f64_column(self, |k| k.taker_buy_base_asset_volume),
+            ),
+            (
+                "taker_buy_quote_asset_volume",
+                f64_column(self, |k| k.taker_buy_quote_asset_volume),
+            ),
+        ])?)
+    }
+}
+
+impl ToRecordBatch for KlineSummaries {
+    fn to_record_batch(&self) -> Result<RecordBatch> {
+        let KlineSummaries::AllKlineSummaries(klines) = self;
+        klines.to_record_batch()
+    }
+}
+
+impl ToRecordBatch for [AggTrade] {
+    fn to_record_batch(&self) -> Result<RecordBatch> {
+        Ok(RecordBatch::try_from_iter([
+            ("time", u64_column(self, |t| t.time)),
+            ("agg_id", u64_column(self, |t| t.agg_id)),
+            ("first_id", u64_column(self, |t| t.first_id)),
+            ("last_id", u64_column(self, |t| t.last_id)),
+            ("maker", bool_column(self, |t| t.maker)),
+            ("best_match", bool_column(self, |t| t.best_match)),
+            ("price", f64_column(self, |t| t.price)),
+            ("qty", f64_column(self, |t| t.qty)),
+        ])?)
+    }
+}
+
+impl ToRecordBatch for [Tickers] {
+    fn to_record_batch(&self) -> Result<RecordBatch> {
+        Ok(RecordBatch::try_from_iter([
+            ("symbol", string_column(self, |t| &t.symbol)),
+            ("bid_price", f64_column(self, |t| t.bid_price)),
+            ("bid_qty", f64_column(self, |t| t.bid_qty)),
+            ("ask_price", f64_column(self, |t| t.ask_price)),
+            ("ask_qty", f64_column(self, |t| t.ask_qty)),
+        ])?)
+    }
+}
+
+impl ToRecordBatch for [SymbolPrice] {
+    fn to_record_batch(&self) -> Result<RecordBatch> {
+        Ok(RecordBatch::try_from_iter([
+            ("symbol", string_column(self, |p| &p.symbol)),
+            ("price", f64_column(self, |p| p.price)),
+        ])?)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ToRecordBatch;
+    use crate::rest_model::SymbolPrice;
+
+    #[test]
+    fn prices_record_batch() {
+        let prices = [
+            SymbolPrice {
+                symbol: "BTCUSDT".to_string(),
+                price: 30000.0,
+            },
+            SymbolPrice {
+                symbol: "ETHUSDT".to_string(),
+                price: 2000.0,
+            },
+        ];
+        let batch = prices.to_record_batch().unwrap();
+        assert_eq!(batch.num_rows(), 2);
+        assert_eq!(batch.schema().field(1).name(), "price");
+    }
+}
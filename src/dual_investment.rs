@@ -0,0 +1,153 @@
+//! Dual Investment positions and their auto-compound plans.
+
+use crate::client::*;
+use crate::errors::*;
+use crate::rest_model::string_or_float;
+
+static SAPI_V1_DCI_PRODUCT_POSITIONS: &str = "/sapi/v1/dci/product/positions";
+static SAPI_V1_DCI_PRODUCT_AUTO_COMPOUND_EDIT_STATUS: &str = "/sapi/v1/dci/product/auto_compound/edit-status";
+
+/// What happens to a position at settlement
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AutoCompoundPlan {
+    /// The position is settled to the spot wallet
+    #[default]
+    None,
+    /// The position is reinvested in the same coin
+    Standard,
+    /// The position is reinvested in the invested or the exercised coin, depending on the settlement
+    Advanced,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum DualInvestmentOptionType {
+    Call,
+    Put,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum DualInvestmentStatus {
+    Pending,
+    Purchased,
+    Settled,
+    PurchaseFail,
+    RefundFail,
+    Refunded,
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct DualInvestmentPositionsQuery {
+    /// All the positions if None
+    pub status: Option<DualInvestmentStatus>,
+    /// Default: 10, Max: 100
+    pub page_size: Option<u32>,
+    /// Page number, starting at 1
+    pub page_index: Option<u32>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DualInvestmentPositions {
+    pub total: u64,
+    #[serde(default)]
+    pub list: Vec<DualInvestmentPosition>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DualInvestmentPosition {
+    pub id: String,
+    pub invest_coin: String,
+    pub exercised_coin: String,
+    #[serde(with = "string_or_float")]
+    pub subscription_amount: f64,
+    #[serde(with = "string_or_float")]
+    pub strike_price: f64,
+    /// In days
+    pub duration: u32,
+    pub settle_date: u64,
+    pub purchase_status: DualInvestmentStatus,
+    #[serde(with = "string_or_float")]
+    pub apr: f64,
+    pub order_id: u64,
+    pub purchase_time: u64,
+    pub option_type: DualInvestmentOptionType,
+    pub auto_compound_plan: AutoCompoundPlan,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AutoCompoundPlanRequest {
+    position_id: String,
+    #[serde(rename = "AutoCompoundPlan")]
+    auto_compound_plan: AutoCompoundPlan,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AutoCompoundPlanChange {
+    pub position_id: String,
+    pub auto_compound_plan: AutoCompoundPlan,
+}
+
+/// This struct acts as a gateway for all Dual Investment endpoints.
+/// Preferably use the trait [`crate::api::Binance`] to get an instance.
+#[derive(Clone)]
+pub struct DualInvestment {
+    pub client: Client,
+    pub recv_window: u64,
+}
+
+impl DualInvestment {
+    /// Positions, with the auto-compound plan of each of them
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, config::*, dual_investment::*};
+    /// let dual_investment: DualInvestment = Binance::new_with_env(&Config::default());
+    /// let query = DualInvestmentPositionsQuery {
+    ///     status: Some(DualInvestmentStatus::Purchased),
+    ///     ..DualInvestmentPositionsQuery::default()
+    /// };
+    /// let positions = tokio_test::block_on(dual_investment.positions(query));
+    /// assert!(positions.is_ok(), "{:?}", positions);
+    /// ```
+    pub async fn positions(&self, query: DualInvestmentPositionsQuery) -> Result<DualInvestmentPositions> {
+        self.client
+            .get_signed_p(SAPI_V1_DCI_PRODUCT_POSITIONS, Some(query), self.recv_window)
+            .await
+    }
+
+    /// Change the auto-compound plan of the position `position_id`, [`AutoCompoundPlan::None`] turns it off
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, config::*, dual_investment::*};
+    /// let dual_investment: DualInvestment = Binance::new_with_env(&Config::default());
+    /// let change = tokio_test::block_on(dual_investment.set_auto_compound_plan("1234", AutoCompoundPlan::Standard));
+    /// assert!(change.is_ok(), "{:?}", change);
+    /// ```
+    pub async fn set_auto_compound_plan<S: Into<String>>(
+        &self,
+        position_id: S,
+        plan: AutoCompoundPlan,
+    ) -> Result<AutoCompoundPlanChange> {
+        let request = AutoCompoundPlanRequest {
+            position_id: position_id.into(),
+            auto_compound_plan: plan,
+        };
+        self.client
+            .post_signed_p(
+                SAPI_V1_DCI_PRODUCT_AUTO_COMPOUND_EDIT_STATUS,
+                Some(request),
+                self.recv_window,
+            )
+            .await
+    }
+}
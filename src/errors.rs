@@ -1,17 +1,71 @@
 use serde_json::Value;
 use std::collections::HashMap;
+use std::fmt;
+use std::time::Duration;
 use thiserror::Error;
 
 #[derive(Debug, Deserialize, Error)]
-#[error("code: {code}, msg: {msg}")]
+#[error("code: {code}, msg: {msg}{}", context_suffix(.context))]
 pub struct BinanceContentError {
     pub code: i32,
     pub msg: String,
 
+    /// Response the error was read from, None for errors carried in the body of a successful response
+    #[serde(skip)]
+    pub context: Option<Box<ResponseContext>>,
+
     #[serde(flatten)]
     extra: HashMap<String, Value>,
 }
 
+/// HTTP response an error was read from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResponseContext {
+    /// Path of the request, e.g. `/api/v3/order`
+    pub endpoint: String,
+    pub status: u16,
+    /// `x-mbx-uuid` header of the response, to quote when contacting Binance
+    pub request_id: Option<String>,
+    /// Id generated by the client for the request, logged and sent in the header set with
+    /// [`crate::config::Config::set_request_id_header`]
+    pub client_request_id: Option<String>,
+    /// `Retry-After` header, set when rate limited (429) or banned (418)
+    pub retry_after: Option<Duration>,
+}
+
+impl fmt::Display for ResponseContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} on {}", self.status, self.endpoint)?;
+        if let Some(request_id) = &self.request_id {
+            write!(f, ", request id {request_id}")?;
+        }
+        if let Some(client_request_id) = &self.client_request_id {
+            write!(f, ", client request id {client_request_id}")?;
+        }
+        if let Some(retry_after) = self.retry_after {
+            write!(f, ", retry after {}s", retry_after.as_secs())?;
+        }
+        Ok(())
+    }
+}
+
+fn context_suffix(context: &Option<Box<ResponseContext>>) -> String {
+    context.as_ref().map(|c| format!(" ({c})")).unwrap_or_default()
+}
+
+impl BinanceContentError {
+    /// Error carried in the body of a successful response, e.g. endpoints wrapping their data with a code
+    #[cfg_attr(not(feature = "mining_api"), allow(dead_code))]
+    pub(crate) fn new(code: i32, msg: String) -> Self {
+        Self {
+            code,
+            msg,
+            context: None,
+            extra: HashMap::new(),
+        }
+    }
+}
+
 /// First errors are technical errors
 /// All unhandled binance content errors are BinanceError
 /// The rest are binance content errors that are properly handled
@@ -32,12 +86,19 @@ pub enum Error {
     Json(#[from] serde_json::Error),
     #[error(transparent)]
     Qs(#[from] serde_qs::Error),
+    #[cfg(feature = "websockets")]
     #[error(transparent)]
-    Tungstenite(#[from] tokio_tungstenite::tungstenite::Error),
+    Tungstenite(Box<tokio_tungstenite::tungstenite::Error>),
     #[error(transparent)]
     TimestampError(#[from] std::time::SystemTimeError),
     #[error(transparent)]
     UTF8Err(#[from] std::str::Utf8Error),
+    #[cfg(feature = "csv_export")]
+    #[error(transparent)]
+    Csv(#[from] csv::Error),
+    #[cfg(feature = "dataframe")]
+    #[error(transparent)]
+    Arrow(#[from] arrow_schema::ArrowError),
     #[error("{response}")]
     BinanceError {
         #[from]
@@ -45,24 +106,139 @@ pub enum Error {
     },
     #[error("invalid listen key : {0}")]
     InvalidListenKey(String),
+    #[error("{0} is not available on Binance.US")]
+    UnsupportedOnBinanceUs(String),
     #[error("unknown symbol {0}")]
     UnknownSymbol(String),
     #[error("{msg}")]
     InvalidOrderError { msg: String },
     #[error("invalid price")]
     InvalidPrice,
+    #[error("estimated slippage {slippage} of the {symbol} order exceeds {max_slippage}")]
+    SlippageExceeded {
+        symbol: String,
+        slippage: f64,
+        max_slippage: f64,
+    },
     #[error("invalid period {0}")]
     InvalidPeriod(String),
-    #[error("internal server error")]
-    InternalServerError,
-    #[error("service unavailable")]
-    ServiceUnavailable,
-    #[error("Unauthorized")]
-    Unauthorized,
+    #[error("invalid withdrawal: {0}")]
+    InvalidWithdrawal(String),
+    #[error("invalid transfer: {0}")]
+    InvalidTransfer(String),
+    /// The request could not be sent or its response could not be read, e.g. on a timeout
+    #[error("request {client_request_id} failed: {source}")]
+    Transport {
+        /// Id generated by the client for the request, see [`ResponseContext::client_request_id`]
+        client_request_id: String,
+        source: reqwest::Error,
+    },
+    /// Unsuccessful response without a Binance error code, e.g. 401, 502 or 503
+    #[error("{context}: {body}")]
+    Http {
+        context: Box<ResponseContext>,
+        body: String,
+    },
+    #[error(transparent)]
+    Config(#[from] ConfigError),
+    #[error("{0} not enabled for this key")]
+    MissingPermission(crate::permissions::Permission),
     #[error("{0}")]
     Msg(String),
 }
 
+/// Invalid [`crate::config::Config`], returned by [`crate::config::ConfigBuilder::build`]
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ConfigError {
+    #[error("invalid {field} url {url}: {reason}")]
+    InvalidUrl {
+        field: &'static str,
+        url: String,
+        reason: String,
+    },
+    #[error("recv window must be between 1 and {max}ms, got {0}", max = crate::config::MAX_RECV_WINDOW)]
+    RecvWindowOutOfRange(u64),
+    #[error("timeout must be at least one second")]
+    ZeroTimeout,
+    #[error("the Binance.US api cannot be used with the {0} endpoint")]
+    BinanceUsEndpoint(String),
+    #[error("invalid value {value} for {name}")]
+    InvalidVar { name: String, value: String },
+    #[error("could not read the configuration file: {0}")]
+    File(String),
+    #[error("invalid header {0}")]
+    InvalidHeader(String),
+}
+
+#[cfg(feature = "websockets")]
+impl From<tokio_tungstenite::tungstenite::Error> for Error {
+    fn from(e: tokio_tungstenite::tungstenite::Error) -> Self { Error::Tungstenite(Box::new(e)) }
+}
+
+impl Error {
+    /// Response the error was read from, if it comes from an unsuccessful response
+    pub fn context(&self) -> Option<&ResponseContext> {
+        match self {
+            Error::BinanceError { response } => response.context.as_deref(),
+            Error::Http { context, .. } => Some(context),
+            _ => None,
+        }
+    }
+
+    /// Binance error code, see <https://binance-docs.github.io/apidocs/spot/en/#error-codes>
+    pub fn binance_code(&self) -> Option<i32> {
+        match self {
+            Error::BinanceError { response } => Some(response.code),
+            _ => None,
+        }
+    }
+
+    pub fn status(&self) -> Option<u16> { self.context().map(|c| c.status) }
+
+    /// Id generated by the client for the request which failed, to find it in the logs
+    /// # Examples
+    /// ```
+    /// use binance::errors::*;
+    /// let error = Error::Http {
+    ///     context: Box::new(ResponseContext {
+    ///         endpoint: "/api/v3/order".into(),
+    ///         status: 503,
+    ///         request_id: None,
+    ///         client_request_id: Some("18c2b3e4f00-42".into()),
+    ///         retry_after: None,
+    ///     }),
+    ///     body: "Service unavailable".into(),
+    /// };
+    /// assert_eq!(error.client_request_id(), Some("18c2b3e4f00-42"));
+    /// ```
+    pub fn client_request_id(&self) -> Option<&str> {
+        match self {
+            Error::Transport { client_request_id, .. } => Some(client_request_id),
+            _ => self.context().and_then(|c| c.client_request_id.as_deref()),
+        }
+    }
+
+    /// How long to wait before sending requests again, when rate limited or banned
+    /// # Examples
+    /// ```
+    /// use std::time::Duration;
+    /// use binance::errors::*;
+    /// let error = Error::Http {
+    ///     context: Box::new(ResponseContext {
+    ///         endpoint: "/api/v3/order".into(),
+    ///         status: 429,
+    ///         request_id: None,
+    ///         client_request_id: None,
+    ///         retry_after: Some(Duration::from_secs(30)),
+    ///     }),
+    ///     body: "Too many requests".into(),
+    /// };
+    /// assert_eq!(error.retry_after(), Some(Duration::from_secs(30)));
+    /// assert_eq!(error.to_string(), "429 on /api/v3/order, retry after 30s: Too many requests");
+    /// ```
+    pub fn retry_after(&self) -> Option<Duration> { self.context().and_then(|c| c.retry_after) }
+}
+
 /// Custom error messages
 pub mod error_messages {
     pub const INVALID_PRICE: &str = "Invalid price.";
@@ -0,0 +1,228 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use tokio::sync::RwLock;
+
+use crate::errors::*;
+#[cfg(feature = "futures_api")]
+use crate::futures::exchange_info::{ContractRules, FuturesExchangeInfoCache};
+use crate::general::General;
+use crate::rest_model as spot_model;
+
+/// Error code returned when an order is rejected by one of the symbol filters
+pub const FILTER_FAILURE_CODE: i32 = -1013;
+
+/// Trading rules of a symbol, extracted from its exchange information filters
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SymbolRules {
+    pub symbol: String,
+    pub min_price: Option<f64>,
+    pub max_price: Option<f64>,
+    pub tick_size: Option<f64>,
+    pub min_qty: Option<f64>,
+    pub max_qty: Option<f64>,
+    pub step_size: Option<f64>,
+    pub min_notional: Option<f64>,
+}
+
+impl From<&spot_model::Symbol> for SymbolRules {
+    fn from(symbol: &spot_model::Symbol) -> Self {
+        let mut rules = SymbolRules {
+            symbol: symbol.symbol.clone(),
+            ..Default::default()
+        };
+        for filter in &symbol.filters {
+            match filter {
+                spot_model::Filters::PriceFilter {
+                    min_price,
+                    max_price,
+                    tick_size,
+                } => {
+                    rules.min_price = Some(*min_price);
+                    rules.max_price = Some(*max_price);
+                    rules.tick_size = Some(*tick_size);
+                }
+                spot_model::Filters::LotSize {
+                    min_qty,
+                    max_qty,
+                    step_size,
+                } => {
+                    rules.min_qty = Some(*min_qty);
+                    rules.max_qty = Some(*max_qty);
+                    rules.step_size = Some(*step_size);
+                }
+                spot_model::Filters::MinNotional { min_notional, .. }
+                | spot_model::Filters::Notional { min_notional, .. } => rules.min_notional = Some(*min_notional),
+                _ => {}
+            }
+        }
+        rules
+    }
+}
+
+#[cfg(feature = "futures_api")]
+impl From<&ContractRules> for SymbolRules {
+    fn from(rules: &ContractRules) -> Self {
+        SymbolRules {
+            symbol: rules.symbol.clone(),
+            min_price: rules.min_price,
+            max_price: rules.max_price,
+            tick_size: rules.tick_size,
+            min_qty: rules.min_qty,
+            max_qty: rules.max_qty,
+            step_size: rules.step_size,
+            min_notional: rules.min_notional,
+        }
+    }
+}
+
+#[derive(Clone)]
+enum Source {
+    Spot {
+        general: General,
+        ttl: Duration,
+        state: Arc<RwLock<CacheState>>,
+    },
+    #[cfg(feature = "futures_api")]
+    Futures(FuturesExchangeInfoCache),
+}
+
+#[derive(Default)]
+struct CacheState {
+    rules: HashMap<String, SymbolRules>,
+    fetched_at: Option<Instant>,
+}
+
+/// Caches the exchange information of spot or futures markets and exposes the symbol filters.
+///
+/// The exchange information is fetched on first use and refetched once it is older than the ttl.
+/// Clones share the same cache, a futures cache shares its state with the `FuturesExchangeInfoCache` it wraps.
+///
+/// # Examples
+/// ```rust,no_run
+/// use std::time::Duration;
+/// use binance::{api::*, config::*, exchange_info::*, general::*};
+/// let general: General = Binance::new_with_env(&Config::default());
+/// let cache = ExchangeInfoCache::spot(general, Duration::from_secs(3600));
+/// let tick_size = tokio_test::block_on(cache.tick_size("BTCUSDT"));
+/// assert!(tick_size.is_ok(), "{:?}", tick_size);
+/// ```
+#[derive(Clone)]
+pub struct ExchangeInfoCache {
+    source: Source,
+}
+
+impl ExchangeInfoCache {
+    /// Cache the spot exchange information
+    pub fn spot(general: General, ttl: Duration) -> Self {
+        Self {
+            source: Source::Spot {
+                general,
+                ttl,
+                state: Arc::new(RwLock::new(CacheState::default())),
+            },
+        }
+    }
+
+    /// Expose the symbol filters of a futures exchange information cache
+    #[cfg(feature = "futures_api")]
+    pub fn futures(cache: FuturesExchangeInfoCache) -> Self {
+        Self {
+            source: Source::Futures(cache),
+        }
+    }
+
+    /// Fetch the exchange information, regardless of the ttl
+    pub async fn refresh(&self) -> Result<()> {
+        match &self.source {
+            Source::Spot { general, state, .. } => {
+                let rules: HashMap<String, SymbolRules> = general
+                    .exchange_info()
+                    .await?
+                    .symbols
+                    .iter()
+                    .map(|symbol| (symbol.symbol.clone(), symbol.into()))
+                    .collect();
+                let mut state = state.write().await;
+                state.rules = rules;
+                state.fetched_at = Some(Instant::now());
+                Ok(())
+            }
+            #[cfg(feature = "futures_api")]
+            Source::Futures(cache) => cache.refresh().await,
+        }
+    }
+
+    /// Mark the cache as stale, the next access will fetch the exchange information again
+    pub async fn invalidate(&self) {
+        match &self.source {
+            Source::Spot { state, .. } => state.write().await.fetched_at = None,
+            #[cfg(feature = "futures_api")]
+            Source::Futures(cache) => cache.invalidate().await,
+        }
+    }
+
+    /// Refresh the cache if `error` is a filter failure (`-1013`), which usually means the cached filters are outdated.
+    /// Returns whether the cache was refreshed.
+    pub async fn refresh_on_filter_error(&self, error: &Error) -> Result<bool> {
+        match error {
+            Error::BinanceError { response } if response.code == FILTER_FAILURE_CODE => {
+                self.refresh().await?;
+                Ok(true)
+            }
+            _ => Ok(false),
+        }
+    }
+
+    /// Trading rules of `symbol`
+    pub async fn symbol_rules<S>(&self, symbol: S) -> Result<SymbolRules>
+    where
+        S: AsRef<str>,
+    {
+        let (ttl, state) = match &self.source {
+            Source::Spot { ttl, state, .. } => (ttl, state),
+            #[cfg(feature = "futures_api")]
+            Source::Futures(cache) => return Ok((&cache.contract_rules(symbol).await?).into()),
+        };
+        let symbol = symbol.as_ref().to_uppercase();
+        let is_stale = match state.read().await.fetched_at {
+            Some(fetched_at) => fetched_at.elapsed() >= *ttl,
+            None => true,
+        };
+        if is_stale {
+            self.refresh().await?;
+        }
+        state
+            .read()
+            .await
+            .rules
+            .get(&symbol)
+            .cloned()
+            .ok_or(Error::UnknownSymbol(symbol))
+    }
+
+    /// Price increment of `symbol`, from the `PRICE_FILTER`
+    pub async fn tick_size<S>(&self, symbol: S) -> Result<Option<f64>>
+    where
+        S: AsRef<str>,
+    {
+        Ok(self.symbol_rules(symbol).await?.tick_size)
+    }
+
+    /// Quantity increment of `symbol`, from the `LOT_SIZE` filter
+    pub async fn step_size<S>(&self, symbol: S) -> Result<Option<f64>>
+    where
+        S: AsRef<str>,
+    {
+        Ok(self.symbol_rules(symbol).await?.step_size)
+    }
+
+    /// Minimum order value of `symbol`, from the `MIN_NOTIONAL` or `NOTIONAL` filter
+    pub async fn min_notional<S>(&self, symbol: S) -> Result<Option<f64>>
+    where
+        S: AsRef<str>,
+    {
+        Ok(self.symbol_rules(symbol).await?.min_notional)
+    }
+}
@@ -0,0 +1,234 @@
+//! CSV export of historical market data, enabled with the `csv_export` feature.
+
+use std::fs::File;
+use std::io::Write;
+use std::path::Path;
+
+use futures::{Stream, TryStreamExt};
+
+use crate::errors::*;
+#[cfg(all(
+    feature = "futures_api",
+    feature = "margin_api",
+    feature = "wallet_api",
+    feature = "convert_api"
+))]
+use crate::ledger::LedgerEntry;
+use crate::rest_model::{AggTrade, KlineSummary, MarketTrade};
+
+/// A market data record that can be written as a CSV row
+pub trait CsvRecord {
+    /// Names of all the columns, in the order of [`CsvRecord::values`]
+    fn columns() -> &'static [&'static str];
+
+    fn values(&self) -> Vec<String>;
+}
+
+impl CsvRecord for KlineSummary {
+    fn columns() -> &'static [&'static str] {
+        &[
+            "open_time",
+            "open",
+            "high",
+            "low",
+            "close",
+            "volume",
+            "close_time",
+            "quote_asset_volume",
+            "number_of_trades",
+            "taker_buy_base_asset_volume",
+            "taker_buy_quote_asset_volume",
+        ]
+    }
+
+    fn values(&self) -> Vec<String> {
+        vec![
+            self.open_time.to_string(),
+            self.open.to_string(),
+            self.high.to_string(),
+            self.low.to_string(),
+            self.close.to_string(),
+            self.volume.to_string(),
+            self.close_time.to_string(),
+            self.quote_asset_volume.to_string(),
+            self.number_of_trades.to_string(),
+            self.taker_buy_base_asset_volume.to_string(),
+            self.taker_buy_quote_asset_volume.to_string(),
+        ]
+    }
+}
+
+impl CsvRecord for MarketTrade {
+    fn columns() -> &'static [&'static str] {
+        &[
+            "id",
+            "price",
+            "qty",
+            "quote_qty",
+            "time",
+            "is_buyer_maker",
+            "is_best_match",
+        ]
+    }
+
+    fn values(&self) -> Vec<String> {
+        vec![
+            self.id.to_string(),
+            self.price.to_string(),
+            self.qty.to_string(),
+            self.quote_qty.to_string(),
+            self.time.to_string(),
+            self.is_buyer_maker.to_string(),
+            self.is_best_match.to_string(),
+        ]
+    }
+}
+
+impl CsvRecord for AggTrade {
+    fn columns() -> &'static [&'static str] {
+        &[
+            "agg_id",
+            "price",
+            "qty",
+            "first_id",
+            "last_id",
+            "time",
+            "maker",
+            "best_match",
+        ]
+    }
+
+    fn values(&self) -> Vec<String> {
+        vec![
+            self.agg_id.to_string(),
+            self.price.to_string(),
+            self.qty.to_string(),
+            self.first_id.to_string(),
+            self.last_id.to_string(),
+            self.time.to_string(),
+            self.maker.to_string(),
+            self.best_match.to_string(),
+        ]
+    }
+}
+
+#[cfg(all(
+    feature = "futures_api",
+    feature = "margin_api",
+    feature = "wallet_api",
+    feature = "convert_api"
+))]
+impl CsvRecord for LedgerEntry {
+    fn columns() -> &'static [&'static str] {
+        &[
+            "time",
+            "kind",
+            "sent_asset",
+            "sent_amount",
+            "received_asset",
+            "received_amount",
+            "fee_asset",
+            "fee_amount",
+            "reference",
+            "description",
+        ]
+    }
+
+    fn values(&self) -> Vec<String> {
+        let mut values = vec![self.time.to_string(), self.kind.as_str().to_string()];
+        for amount in [&self.sent, &self.received, &self.fee] {
+            match amount {
+                Some(amount) => values.extend([amount.asset.clone(), amount.amount.to_string()]),
+                None => values.extend([String::new(), String::new()]),
+            }
+        }
+        values.push(self.reference.clone());
+        values.push(self.description.clone().unwrap_or_default());
+        values
+    }
+}
+
+/// Writes records as CSV rows, with a header of the selected columns
+///
+/// # Examples
+/// ```rust,no_run
+/// use binance::{api::*, config::*, export::*, market::*, rest_model::AggTrade};
+/// let market: Market = Binance::new_with_env(&Config::default());
+/// let trades = market.agg_trades_range("BTCUSDT", 1_650_000_000_000, 1_650_086_400_000);
+/// let mut writer = CsvExporter::<_, AggTrade>::create("trades.csv", Some(&["time", "price", "qty"])).unwrap();
+/// let rows = tokio_test::block_on(writer.write_stream(trades));
+/// assert!(rows.is_ok(), "{:?}", rows);
+/// ```
+pub struct CsvExporter<W: Write, T: CsvRecord> {
+    writer: csv::Writer<W>,
+    selected: Vec<usize>,
+    _record: std::marker::PhantomData<T>,
+}
+
+impl<T: CsvRecord> CsvExporter<File, T> {
+    /// Export to the file at `path`, see [`CsvExporter::new`]
+    pub fn create<P: AsRef<Path>>(path: P, columns: Option<&[&str]>) -> Result<Self> {
+        Self::new(File::create(path)?, columns)
+    }
+}
+
+impl<W: Write, T: CsvRecord> CsvExporter<W, T> {
+    /// Export the `columns` of records to `writer`, all columns if None
+    pub fn new(writer: W, columns: Option<&[&str]>) -> Result<Self> {
+        let selected = match columns {
+            None => (0..T::columns().len()).collect(),
+            Some(columns) => columns
+                .iter()
+                .map(|column| {
+                    T::columns()
+                        .iter()
+                        .position(|c| c == column)
+                        .ok_or_else(|| Error::Msg(format!("unknown column {column}")))
+                })
+                .collect::<Result<Vec<usize>>>()?,
+        };
+        let mut writer = csv::Writer::from_writer(writer);
+        writer.write_record(selected.iter().map(|&i| T::columns()[i]))?;
+        Ok(Self {
+            writer,
+            selected,
+            _record: std::marker::PhantomData,
+        })
+    }
+
+    pub fn write(&mut self, record: &T) -> Result<()> {
+        let values = record.values();
+        self.writer.write_record(self.selected.iter().map(|&i| &values[i]))?;
+        Ok(())
+    }
+
+    /// Write all the records, returns the number of rows written
+    pub fn write_all<'a, I>(&mut self, records: I) -> Result<u64>
+    where
+        I: IntoIterator<Item = &'a T>,
+        T: 'a,
+    {
+        let mut rows = 0;
+        for record in records {
+            self.write(record)?;
+            rows += 1;
+        }
+        self.writer.flush()?;
+        Ok(rows)
+    }
+
+    /// Write records as they are fetched, without keeping them in memory, returns the number of rows written
+    pub async fn write_stream<S>(&mut self, records: S) -> Result<u64>
+    where
+        S: Stream<Item = Result<T>>,
+    {
+        let mut records = std::pin::pin!(records);
+        let mut rows = 0;
+        while let Some(record) = records.try_next().await? {
+            self.write(&record)?;
+            rows += 1;
+        }
+        self.writer.flush()?;
+        Ok(rows)
+    }
+}
@@ -1,17 +1,22 @@
 use std::collections::BTreeMap;
 
+use super::income::{summarize_income, IncomePeriod, IncomeSummary};
 use super::rest_model::{
-    AccountBalance, AccountInformation, CanceledOrder, ChangeLeverageResponse, Order, OrderType, Position,
-    PositionSide, Transaction, WorkingType,
+    AccountBalance, AccountInformation, CanceledOrder, ChangeLeverageResponse, CommissionRate, FeeBurnStatus, Income,
+    IncomeQuery, MarginType, Order, OrderType, Position, PositionMarginChange, PositionMarginHistoryQuery,
+    PositionMarginRequest, PositionMarginResponse, PositionSide, SettingChange, Transaction, WorkingType,
 };
 use crate::account::OrderCancellation;
 use crate::client::Client;
 use crate::errors::*;
 use crate::rest_model::{OrderSide, TimeInForce};
 use crate::rest_model::{PairAndWindowQuery, PairQuery};
+use crate::util::serde_helpers::{string_or_bool, uppercase_bool_opt};
 use crate::util::*;
-use serde::Serializer;
-use std::fmt;
+use serde_json::Value;
+
+/// Error code returned when the margin type of a symbol is already the requested one
+pub const NO_NEED_TO_CHANGE_MARGIN_TYPE: i32 = -4046;
 
 #[derive(Clone)]
 pub struct FuturesAccount {
@@ -19,27 +24,6 @@ pub struct FuturesAccount {
     pub recv_window: u64,
 }
 
-/// Serialize bool as str
-fn serialize_as_str<S, T>(t: &T, serializer: S) -> std::result::Result<S::Ok, S::Error>
-where
-    S: Serializer,
-    T: fmt::Display,
-{
-    serializer.collect_str(t)
-}
-
-/// Serialize opt bool as str
-fn serialize_opt_as_uppercase<S, T>(t: &Option<T>, serializer: S) -> std::result::Result<S::Ok, S::Error>
-where
-    S: Serializer,
-    T: ToString,
-{
-    match *t {
-        Some(ref v) => serializer.serialize_some(&v.to_string().to_uppercase()),
-        None => serializer.serialize_none(),
-    }
-}
-
 #[derive(Serialize, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct OrderRequest {
@@ -58,15 +42,24 @@ pub struct OrderRequest {
     pub activation_price: Option<f64>,
     pub callback_rate: Option<f64>,
     pub working_type: Option<WorkingType>,
-    #[serde(serialize_with = "serialize_opt_as_uppercase")]
+    #[serde(with = "uppercase_bool_opt")]
     pub price_protect: Option<bool>,
     pub new_client_order_id: Option<String>,
 }
 
+/// Open order lookup, either `order_id` or `orig_client_order_id` must be set
+#[derive(Serialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenOrderQuery {
+    pub symbol: String,
+    pub order_id: Option<u64>,
+    pub orig_client_order_id: Option<String>,
+}
+
 #[derive(Serialize)]
 #[serde(rename_all = "camelCase")]
 struct ChangePositionModeRequest {
-    #[serde(serialize_with = "serialize_as_str")]
+    #[serde(with = "string_or_bool")]
     pub dual_side_position: bool,
 }
 
@@ -82,6 +75,26 @@ impl FuturesAccount {
         self.client.get_signed("/fapi/v1/openOrders", &payload).await
     }
 
+    /// Query one open order, fails with `-2013` if the order is not open anymore
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, futures::account::*, config::*};
+    /// let account: FuturesAccount = Binance::new_with_env(&Config::testnet());
+    /// let query = OpenOrderQuery {
+    ///     symbol: "BTCUSDT".into(),
+    ///     order_id: Some(1),
+    ///     ..Default::default()
+    /// };
+    /// let order = tokio_test::block_on(account.get_open_order(query));
+    /// assert!(order.is_ok(), "{:?}", order);
+    /// ```
+    pub async fn get_open_order(&self, query: OpenOrderQuery) -> Result<Order> {
+        self.client
+            .get_signed_p("/fapi/v1/openOrder", Some(query), self.recv_window)
+            .await
+    }
+
     pub async fn limit_buy(
         &self,
         symbol: impl Into<String>,
@@ -222,6 +235,15 @@ impl FuturesAccount {
         self.client.get_signed_d("/fapi/v2/balance", request.as_str()).await
     }
 
+    /// Change the initial leverage of a symbol, returns the leverage and the maximum notional value it allows
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, futures::account::*, config::*};
+    /// let account: FuturesAccount = Binance::new_with_env(&Config::testnet());
+    /// let leverage = tokio_test::block_on(account.change_initial_leverage("BTCUSDT", 10));
+    /// assert!(leverage.is_ok(), "{:?}", leverage);
+    /// ```
     pub async fn change_initial_leverage<S>(&self, symbol: S, leverage: u8) -> Result<ChangeLeverageResponse>
     where
         S: Into<String>,
@@ -234,8 +256,83 @@ impl FuturesAccount {
         self.client.post_signed_d("/fapi/v1/leverage", request.as_str()).await
     }
 
-    pub async fn change_position_mode(&self, dual_side_position: bool) -> Result<()> {
+    /// Add margin to or remove margin from an isolated position
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, futures::account::*, futures::rest_model::*, config::*};
+    /// let account: FuturesAccount = Binance::new_with_env(&Config::testnet());
+    /// let request = PositionMarginRequest {
+    ///     symbol: "BTCUSDT".into(),
+    ///     position_side: None,
+    ///     amount: 10.0,
+    ///     margin_type: PositionMarginType::Add,
+    /// };
+    /// let response = tokio_test::block_on(account.adjust_position_margin(request));
+    /// assert!(response.is_ok(), "{:?}", response);
+    /// ```
+    pub async fn adjust_position_margin(&self, request: PositionMarginRequest) -> Result<PositionMarginResponse> {
+        self.client
+            .post_signed_p("/fapi/v1/positionMargin", request, self.recv_window)
+            .await
+    }
+
+    /// History of the margin changes of the isolated positions of a symbol
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, futures::account::*, futures::rest_model::*, config::*};
+    /// let account: FuturesAccount = Binance::new_with_env(&Config::testnet());
+    /// let query = PositionMarginHistoryQuery {
+    ///     symbol: "BTCUSDT".into(),
+    ///     ..Default::default()
+    /// };
+    /// let history = tokio_test::block_on(account.position_margin_history(query));
+    /// assert!(history.is_ok(), "{:?}", history);
+    /// ```
+    pub async fn position_margin_history(
+        &self,
+        query: PositionMarginHistoryQuery,
+    ) -> Result<Vec<PositionMarginChange>> {
         self.client
+            .get_signed_p("/fapi/v1/positionMargin/history", Some(query), self.recv_window)
+            .await
+    }
+
+    /// Change the margin type of a symbol, which Binance rejects with `-4046` when it does not change
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, futures::account::*, futures::rest_model::*, config::*};
+    /// let account: FuturesAccount = Binance::new_with_env(&Config::testnet());
+    /// let change = tokio_test::block_on(account.change_margin_type("BTCUSDT", MarginType::Isolated));
+    /// assert!(change.is_ok(), "{:?}", change);
+    /// ```
+    pub async fn change_margin_type<S>(&self, symbol: S, margin_type: MarginType) -> Result<SettingChange>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("marginType".into(), margin_type.as_request_param().into());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        match self
+            .client
+            .post_signed_d::<Value>("/fapi/v1/marginType", request.as_str())
+            .await
+        {
+            Ok(_) => Ok(SettingChange::Changed),
+            Err(Error::BinanceError { response }) if response.code == NO_NEED_TO_CHANGE_MARGIN_TYPE => {
+                Ok(SettingChange::Unchanged)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    pub async fn change_position_mode(&self, dual_side_position: bool) -> Result<()> {
+        let _: Value = self
+            .client
             .post_signed_p(
                 "/fapi/v1/positionSide/dual",
                 ChangePositionModeRequest { dual_side_position },
@@ -245,11 +342,103 @@ impl FuturesAccount {
         Ok(())
     }
 
-    pub async fn cancel_all_open_orders<S>(&self, symbol: S) -> Result<()>
+    /// Income history: realized pnl, funding fees, commissions, transfers...
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, futures::account::*, futures::rest_model::*, config::*};
+    /// let account: FuturesAccount = Binance::new_with_env(&Config::testnet());
+    /// let query = IncomeQuery {
+    ///     income_type: Some(IncomeType::FundingFee),
+    ///     ..IncomeQuery::default()
+    /// };
+    /// let incomes = tokio_test::block_on(account.income(query));
+    /// assert!(incomes.is_ok(), "{:?}", incomes);
+    /// ```
+    pub async fn income(&self, query: IncomeQuery) -> Result<Vec<Income>> {
+        self.client
+            .get_signed_p("/fapi/v1/income", Some(query), self.recv_window)
+            .await
+    }
+
+    /// Income of the query grouped by period and asset, every page of the query is fetched
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, futures::account::*, futures::income::*, futures::rest_model::*, config::*};
+    /// let account: FuturesAccount = Binance::new_with_env(&Config::testnet());
+    /// let summaries = tokio_test::block_on(account.income_summary(IncomeQuery::default(), IncomePeriod::Daily));
+    /// assert!(summaries.is_ok(), "{:?}", summaries);
+    /// ```
+    pub async fn income_summary(&self, mut query: IncomeQuery, period: IncomePeriod) -> Result<Vec<IncomeSummary>> {
+        let limit = query.limit.unwrap_or(1000);
+        query.limit = Some(limit);
+        query.page = Some(query.page.unwrap_or(1));
+        let mut incomes = vec![];
+        loop {
+            let page = self.income(query.clone()).await?;
+            let count = page.len();
+            incomes.extend(page);
+            if count < limit as usize {
+                break;
+            }
+            query.page = query.page.map(|p| p + 1);
+        }
+        Ok(summarize_income(&incomes, period))
+    }
+
+    /// Maker and taker commission rates of a symbol
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, futures::account::*, config::*};
+    /// let account: FuturesAccount = Binance::new_with_env(&Config::testnet());
+    /// let rate = tokio_test::block_on(account.commission_rate("BTCUSDT"));
+    /// assert!(rate.is_ok(), "{:?}", rate);
+    /// ```
+    pub async fn commission_rate<S>(&self, symbol: S) -> Result<CommissionRate>
     where
         S: Into<String>,
     {
         self.client
+            .get_signed_p(
+                "/fapi/v1/commissionRate",
+                Some(PairQuery { symbol: symbol.into() }),
+                self.recv_window,
+            )
+            .await
+    }
+
+    /// Whether fees are paid in BNB
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, futures::account::*, config::*};
+    /// let account: FuturesAccount = Binance::new_with_env(&Config::testnet());
+    /// let status = tokio_test::block_on(account.fee_burn_status());
+    /// assert!(status.is_ok(), "{:?}", status);
+    /// ```
+    pub async fn fee_burn_status(&self) -> Result<FeeBurnStatus> {
+        self.client
+            .get_signed_p("/fapi/v1/feeBurn", Option::<PairQuery>::None, self.recv_window)
+            .await
+    }
+
+    /// Cancel all the open orders of a symbol
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, futures::account::*, config::*};
+    /// let account: FuturesAccount = Binance::new_with_env(&Config::testnet());
+    /// let canceled = tokio_test::block_on(account.cancel_all_open_orders("BTCUSDT"));
+    /// assert!(canceled.is_ok(), "{:?}", canceled);
+    /// ```
+    pub async fn cancel_all_open_orders<S>(&self, symbol: S) -> Result<()>
+    where
+        S: Into<String>,
+    {
+        let _: Value = self
+            .client
             .delete_signed_p(
                 "/fapi/v1/allOpenOrders",
                 PairQuery { symbol: symbol.into() },
@@ -0,0 +1,380 @@
+//! Cached exchange information of the USD-M and COIN-M futures, with the contract rules of each symbol.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use tokio::sync::RwLock;
+
+use super::account::OrderRequest;
+use super::rest_model::{ContractStatus, ContractType, Filters, OrderType};
+use crate::client::Client;
+use crate::config::Config;
+use crate::errors::*;
+use crate::util::round_down;
+use crate::util::serde_helpers::string_or_float_opt;
+
+/// USD-M (`/fapi`) or COIN-M (`/dapi`) futures
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FuturesProduct {
+    UsdM,
+    CoinM,
+}
+
+impl FuturesProduct {
+    fn exchange_info_endpoint(self) -> &'static str {
+        match self {
+            FuturesProduct::UsdM => "/fapi/v1/exchangeInfo",
+            FuturesProduct::CoinM => "/dapi/v1/exchangeInfo",
+        }
+    }
+
+    fn leverage_bracket_endpoint(self) -> &'static str {
+        match self {
+            FuturesProduct::UsdM => "/fapi/v1/leverageBracket",
+            FuturesProduct::CoinM => "/dapi/v2/leverageBracket",
+        }
+    }
+}
+
+/// Trading rules of a contract, extracted from the exchange information
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContractRules {
+    pub symbol: String,
+    pub pair: String,
+    pub contract_type: Option<ContractType>,
+    pub status: ContractStatus,
+    /// Value of one contract in the quote asset, COIN-M only
+    pub contract_size: Option<f64>,
+    pub price_precision: u16,
+    pub quantity_precision: u16,
+    pub min_price: Option<f64>,
+    pub max_price: Option<f64>,
+    pub tick_size: Option<f64>,
+    pub min_qty: Option<f64>,
+    pub max_qty: Option<f64>,
+    pub step_size: Option<f64>,
+    pub market_min_qty: Option<f64>,
+    pub market_max_qty: Option<f64>,
+    pub market_step_size: Option<f64>,
+    pub min_notional: Option<f64>,
+}
+
+impl ContractRules {
+    pub fn is_trading(&self) -> bool { self.status == ContractStatus::Trading }
+}
+
+/// Symbol of the exchange information, with the fields common to USD-M and COIN-M
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ContractSymbol {
+    symbol: String,
+    pair: String,
+    #[serde(default)]
+    contract_type: Option<ContractType>,
+    #[serde(alias = "contractStatus")]
+    status: ContractStatus,
+    #[serde(default, with = "string_or_float_opt")]
+    contract_size: Option<f64>,
+    price_precision: u16,
+    quantity_precision: u16,
+    filters: Vec<Filters>,
+}
+
+#[derive(Deserialize)]
+struct ContractExchangeInformation {
+    symbols: Vec<ContractSymbol>,
+}
+
+impl From<ContractSymbol> for ContractRules {
+    fn from(symbol: ContractSymbol) -> Self {
+        let mut rules = ContractRules {
+            symbol: symbol.symbol,
+            pair: symbol.pair,
+            contract_type: symbol.contract_type,
+            status: symbol.status,
+            contract_size: symbol.contract_size,
+            price_precision: symbol.price_precision,
+            quantity_precision: symbol.quantity_precision,
+            min_price: None,
+            max_price: None,
+            tick_size: None,
+            min_qty: None,
+            max_qty: None,
+            step_size: None,
+            market_min_qty: None,
+            market_max_qty: None,
+            market_step_size: None,
+            min_notional: None,
+        };
+        for filter in symbol.filters {
+            match filter {
+                Filters::PriceFilter {
+                    min_price,
+                    max_price,
+                    tick_size,
+                } => {
+                    rules.min_price = Some(min_price);
+                    rules.max_price = Some(max_price);
+                    rules.tick_size = Some(tick_size);
+                }
+                Filters::LotSize {
+                    min_qty,
+                    max_qty,
+                    step_size,
+                } => {
+                    rules.min_qty = Some(min_qty);
+                    rules.max_qty = Some(max_qty);
+                    rules.step_size = Some(step_size);
+                }
+                Filters::MarketLotSize {
+                    min_qty,
+                    max_qty,
+                    step_size,
+                } => {
+                    rules.market_min_qty = min_qty.parse().ok();
+                    rules.market_max_qty = max_qty.parse().ok();
+                    rules.market_step_size = step_size.parse().ok();
+                }
+                Filters::MinNotional { notional } => rules.min_notional = Some(notional),
+                _ => {}
+            }
+        }
+        rules
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Bracket {
+    initial_leverage: u8,
+}
+
+#[derive(Deserialize)]
+struct SymbolLeverage {
+    symbol: String,
+    brackets: Vec<Bracket>,
+}
+
+#[derive(Default)]
+struct CacheState {
+    rules: HashMap<String, ContractRules>,
+    fetched_at: Option<Instant>,
+    max_leverage: HashMap<String, u8>,
+    leverage_fetched_at: Option<Instant>,
+}
+
+/// Caches the exchange information of the USD-M or COIN-M futures and exposes the contract rules.
+///
+/// The exchange information is fetched on first use and refetched once it is older than the ttl,
+/// the leverage brackets, which require an API key, are only fetched by [`FuturesExchangeInfoCache::max_leverage`].
+/// Clones share the same cache, wrap it in [`crate::exchange_info::ExchangeInfoCache::futures`] to read the symbol filters
+/// the same way as on spot.
+///
+/// # Examples
+/// ```rust,no_run
+/// use std::time::Duration;
+/// use binance::{config::*, futures::account::*, futures::exchange_info::*, futures::rest_model::*};
+/// let cache = FuturesExchangeInfoCache::new(FuturesProduct::UsdM, None, None, &Config::default(), Duration::from_secs(3600));
+/// let rules = tokio_test::block_on(cache.contract_rules("BTCUSDT"));
+/// assert!(rules.is_ok(), "{:?}", rules);
+/// let mut order = OrderRequest {
+///     symbol: "BTCUSDT".into(),
+///     order_type: OrderType::Limit,
+///     price: Some(30000.123),
+///     quantity: Some(0.0123),
+///     ..Default::default()
+/// };
+/// let normalized = tokio_test::block_on(cache.normalize_order(&mut order));
+/// assert!(normalized.is_ok(), "{:?}", normalized);
+/// ```
+#[derive(Clone)]
+pub struct FuturesExchangeInfoCache {
+    client: Client,
+    product: FuturesProduct,
+    recv_window: u64,
+    ttl: Duration,
+    state: Arc<RwLock<CacheState>>,
+}
+
+impl FuturesExchangeInfoCache {
+    /// Cache the exchange information of `product`, from the futures endpoints of `config`
+    pub fn new(
+        product: FuturesProduct,
+        api_key: Option<String>,
+        secret_key: Option<String>,
+        config: &Config,
+        ttl: Duration,
+    ) -> Self {
+        let host = match product {
+            FuturesProduct::UsdM => config.futures_rest_api_endpoint.clone(),
+            FuturesProduct::CoinM => config.coin_futures_rest_api_endpoint.clone(),
+        };
+        Self {
+            client: Client::from_config(api_key, secret_key, host, config),
+            product,
+            recv_window: config.recv_window,
+            ttl,
+            state: Arc::new(RwLock::new(CacheState::default())),
+        }
+    }
+
+    pub fn product(&self) -> FuturesProduct { self.product }
+
+    /// Fetch the exchange information, regardless of the ttl
+    pub async fn refresh(&self) -> Result<()> {
+        let info: ContractExchangeInformation = self.client.get_p(self.product.exchange_info_endpoint(), None).await?;
+        let rules = info
+            .symbols
+            .into_iter()
+            .map(|symbol| (symbol.symbol.clone(), symbol.into()))
+            .collect();
+        let mut state = self.state.write().await;
+        state.rules = rules;
+        state.fetched_at = Some(Instant::now());
+        Ok(())
+    }
+
+    /// Mark the cache as stale, the next access will fetch the exchange information and leverage brackets again
+    pub async fn invalidate(&self) {
+        let mut state = self.state.write().await;
+        state.fetched_at = None;
+        state.leverage_fetched_at = None;
+    }
+
+    /// Trading rules of `symbol`
+    pub async fn contract_rules<S>(&self, symbol: S) -> Result<ContractRules>
+    where
+        S: AsRef<str>,
+    {
+        let symbol = symbol.as_ref().to_uppercase();
+        let is_stale = match self.state.read().await.fetched_at {
+            Some(fetched_at) => fetched_at.elapsed() >= self.ttl,
+            None => true,
+        };
+        if is_stale {
+            self.refresh().await?;
+        }
+        self.state
+            .read()
+            .await
+            .rules
+            .get(&symbol)
+            .cloned()
+            .ok_or(Error::UnknownSymbol(symbol))
+    }
+
+    /// Status of the contract, e.g. `Trading` or `Settling`
+    pub async fn contract_status<S>(&self, symbol: S) -> Result<ContractStatus>
+    where
+        S: AsRef<str>,
+    {
+        Ok(self.contract_rules(symbol).await?.status)
+    }
+
+    /// Price increment of `symbol`, from the `PRICE_FILTER`
+    pub async fn tick_size<S>(&self, symbol: S) -> Result<Option<f64>>
+    where
+        S: AsRef<str>,
+    {
+        Ok(self.contract_rules(symbol).await?.tick_size)
+    }
+
+    /// Quantity increment of limit orders on `symbol`, from the `LOT_SIZE` filter
+    pub async fn step_size<S>(&self, symbol: S) -> Result<Option<f64>>
+    where
+        S: AsRef<str>,
+    {
+        Ok(self.contract_rules(symbol).await?.step_size)
+    }
+
+    /// Quantity increment of market orders on `symbol`, from the `MARKET_LOT_SIZE` filter
+    pub async fn market_step_size<S>(&self, symbol: S) -> Result<Option<f64>>
+    where
+        S: AsRef<str>,
+    {
+        Ok(self.contract_rules(symbol).await?.market_step_size)
+    }
+
+    /// Minimum order value of `symbol`, from the `MIN_NOTIONAL` filter
+    pub async fn min_notional<S>(&self, symbol: S) -> Result<Option<f64>>
+    where
+        S: AsRef<str>,
+    {
+        Ok(self.contract_rules(symbol).await?.min_notional)
+    }
+
+    /// Highest initial leverage of `symbol`, from the leverage brackets of the account, requires an API key
+    pub async fn max_leverage<S>(&self, symbol: S) -> Result<u8>
+    where
+        S: AsRef<str>,
+    {
+        let symbol = symbol.as_ref().to_uppercase();
+        let is_stale = match self.state.read().await.leverage_fetched_at {
+            Some(fetched_at) => fetched_at.elapsed() >= self.ttl,
+            None => true,
+        };
+        if is_stale {
+            let brackets: Vec<SymbolLeverage> = self
+                .client
+                .get_signed_p(
+                    self.product.leverage_bracket_endpoint(),
+                    Option::<String>::None,
+                    self.recv_window,
+                )
+                .await?;
+            let mut state = self.state.write().await;
+            state.max_leverage = brackets
+                .into_iter()
+                .map(|symbol| {
+                    let max = symbol.brackets.iter().map(|b| b.initial_leverage).max().unwrap_or(1);
+                    (symbol.symbol, max)
+                })
+                .collect();
+            state.leverage_fetched_at = Some(Instant::now());
+        }
+        self.state
+            .read()
+            .await
+            .max_leverage
+            .get(&symbol)
+            .copied()
+            .ok_or(Error::UnknownSymbol(symbol))
+    }
+
+    /// Round the price of `order` down to the tick size and its quantity down to the step size of its order type,
+    /// fails if the contract is not trading or the quantity falls under the minimum
+    pub async fn normalize_order(&self, order: &mut OrderRequest) -> Result<()> {
+        let rules = self.contract_rules(&order.symbol).await?;
+        if !rules.is_trading() {
+            return Err(Error::InvalidOrderError {
+                msg: format!("{} is not trading ({:?})", rules.symbol, rules.status),
+            });
+        }
+        let (step_size, min_qty) = match order.order_type {
+            OrderType::Market | OrderType::StopMarket | OrderType::TakeProfitMarket | OrderType::TrailingStopMarket => {
+                (
+                    rules.market_step_size.or(rules.step_size),
+                    rules.market_min_qty.or(rules.min_qty),
+                )
+            }
+            _ => (rules.step_size, rules.min_qty),
+        };
+        if let (Some(price), Some(tick_size)) = (order.price, rules.tick_size) {
+            order.price = Some(round_down(price, tick_size));
+        }
+        if let (Some(stop_price), Some(tick_size)) = (order.stop_price, rules.tick_size) {
+            order.stop_price = Some(round_down(stop_price, tick_size));
+        }
+        if let Some(quantity) = order.quantity {
+            let quantity = step_size.map_or(quantity, |step_size| round_down(quantity, step_size));
+            if min_qty.is_some_and(|min_qty| quantity < min_qty) {
+                return Err(Error::InvalidOrderError {
+                    msg: format!("quantity {quantity} is under the minimum of {}", rules.symbol),
+                });
+            }
+            order.quantity = Some(quantity);
+        }
+        Ok(())
+    }
+}
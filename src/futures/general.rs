@@ -2,6 +2,7 @@ use crate::client::*;
 use crate::errors::*;
 use crate::futures::rest_model::*;
 use crate::rest_model::ServerTime;
+use serde_json::Value;
 
 #[derive(Clone)]
 pub struct FuturesGeneral {
@@ -11,7 +12,7 @@ pub struct FuturesGeneral {
 impl FuturesGeneral {
     // Test connectivity
     pub async fn ping(&self) -> Result<String> {
-        self.client.get("/fapi/v1/ping", None).await?;
+        let _: Value = self.client.get("/fapi/v1/ping", None).await?;
         Ok("pong".into())
     }
 
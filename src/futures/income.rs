@@ -0,0 +1,100 @@
+//! Daily or monthly totals of the futures income history, by asset.
+
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Datelike, NaiveDate};
+
+use crate::futures::rest_model::{Income, IncomeType};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum IncomePeriod {
+    /// UTC days
+    #[default]
+    Daily,
+    /// UTC calendar months
+    Monthly,
+}
+
+impl IncomePeriod {
+    /// Start of the period containing `time`, in milliseconds
+    pub fn start(&self, time: u64) -> u64 {
+        let date = DateTime::from_timestamp_millis(time as i64)
+            .unwrap_or_default()
+            .date_naive();
+        let start = match self {
+            IncomePeriod::Daily => date,
+            IncomePeriod::Monthly => NaiveDate::from_ymd_opt(date.year(), date.month(), 1).unwrap_or(date),
+        };
+        start
+            .and_hms_opt(0, 0, 0)
+            .unwrap_or_default()
+            .and_utc()
+            .timestamp_millis() as u64
+    }
+}
+
+/// Income of an asset over a period, negative amounts are paid
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IncomeSummary {
+    /// Start of the period, in milliseconds
+    pub period_start: u64,
+    pub asset: String,
+    pub realized_pnl: f64,
+    pub funding_fee: f64,
+    pub commission: f64,
+    /// Every other income type, e.g. transfers and rebates
+    pub other: f64,
+}
+
+impl IncomeSummary {
+    /// Trading result: realized pnl, funding fees and commissions
+    pub fn net_trading(&self) -> f64 { self.realized_pnl + self.funding_fee + self.commission }
+}
+
+/// Group `incomes` by period and asset, oldest period first
+///
+/// # Examples
+/// ```rust
+/// use binance::futures::{income::*, rest_model::{Income, IncomeType}};
+/// let income = |income_type, income, time| Income {
+///     symbol: "BTCUSDT".to_string(),
+///     income_type,
+///     income,
+///     asset: "USDT".to_string(),
+///     info: String::new(),
+///     time,
+///     tran_id: 1,
+///     trade_id: String::new(),
+/// };
+/// let incomes = vec![
+///     income(IncomeType::RealizedPnl, 10.0, 1_700_000_000_000),
+///     income(IncomeType::Commission, -0.5, 1_700_000_100_000),
+///     income(IncomeType::FundingFee, -0.25, 1_700_100_000_000),
+/// ];
+/// let summaries = summarize_income(&incomes, IncomePeriod::Monthly);
+/// assert_eq!(summaries.len(), 1);
+/// assert_eq!(summaries[0].net_trading(), 9.25);
+/// ```
+pub fn summarize_income(incomes: &[Income], period: IncomePeriod) -> Vec<IncomeSummary> {
+    let mut summaries: BTreeMap<(u64, &str), IncomeSummary> = BTreeMap::new();
+    for income in incomes {
+        let period_start = period.start(income.time);
+        let summary = summaries
+            .entry((period_start, income.asset.as_str()))
+            .or_insert_with(|| IncomeSummary {
+                period_start,
+                asset: income.asset.clone(),
+                realized_pnl: 0.0,
+                funding_fee: 0.0,
+                commission: 0.0,
+                other: 0.0,
+            });
+        match income.income_type {
+            IncomeType::RealizedPnl => summary.realized_pnl += income.income,
+            IncomeType::FundingFee => summary.funding_fee += income.income,
+            IncomeType::Commission => summary.commission += income.income,
+            _ => summary.other += income.income,
+        }
+    }
+    summaries.into_values().collect()
+}
@@ -1,10 +1,7 @@
 use crate::client::*;
 use crate::errors::*;
 use crate::futures::rest_model::*;
-use crate::rest_model::{
-    BookTickers, KlineSummaries, KlineSummary, PairAndWindowQuery, PairQuery, SymbolPrice, Tickers,
-};
-use crate::util::*;
+use crate::rest_model::{KlineSummaries, KlineSummaryRow, PairAndWindowQuery, PairQuery, SymbolPrice};
 use serde_json::Value;
 
 //TODO : Validate intervals and start/end times in history queries
@@ -311,26 +308,9 @@ impl FuturesMarket {
             from_id: None,
             period: None,
         };
-        let data: Vec<Vec<Value>> = self.client.get_d("/fapi/v1/klines", Some(query)).await?;
-
-        let klines = KlineSummaries::AllKlineSummaries(
-            data.iter()
-                .map(|row| KlineSummary {
-                    open_time: to_i64(&row[0]),
-                    open: to_f64(&row[1]),
-                    high: to_f64(&row[2]),
-                    low: to_f64(&row[3]),
-                    close: to_f64(&row[4]),
-                    volume: to_f64(&row[5]),
-                    close_time: to_i64(&row[6]),
-                    quote_asset_volume: to_f64(&row[7]),
-                    number_of_trades: to_i64(&row[8]),
-                    taker_buy_base_asset_volume: to_f64(&row[9]),
-                    taker_buy_quote_asset_volume: to_f64(&row[10]),
-                })
-                .collect(),
-        );
-        Ok(klines)
+        let rows: Vec<KlineSummaryRow> = self.client.get_d("/fapi/v1/klines", Some(query)).await?;
+
+        Ok(KlineSummaries::from_rows(rows))
     }
 
     /// Returns up to 'limit' blvt klines for given symbol and interval ("1m", "5m", ...)
@@ -517,13 +497,14 @@ impl FuturesMarket {
     }
 
     /// Symbols order book ticker
-    /// -> Best price/qty on the order book for ALL symbols.
-    pub async fn get_all_book_tickers(&self) -> Result<BookTickers> {
+    /// -> Best price/qty on the order book for ALL symbols, with the update ids and times
+    pub async fn get_all_book_tickers(&self) -> Result<Vec<BookTicker>> {
         self.client.get_p("/fapi/v1/ticker/bookTicker", None).await
     }
 
-    // -> Best price/qty on the order book for ONE symbol
-    pub async fn get_book_ticker<S>(&self, symbol: S) -> Result<Tickers>
+    /// Best price/qty on the order book for ONE symbol, with the update id and time
+    /// https://binance-docs.github.io/apidocs/futures/en/#symbol-order-book-ticker
+    pub async fn get_book_ticker<S>(&self, symbol: S) -> Result<BookTicker>
     where
         S: Into<String>,
     {
@@ -532,15 +513,35 @@ impl FuturesMarket {
             .await
     }
 
+    /// Mark price, index price and funding of ONE symbol
+    /// https://binance-docs.github.io/apidocs/futures/en/#mark-price
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, config::*, futures::market::*};
+    /// let market: FuturesMarket = Binance::new_with_env(&Config::testnet());
+    /// let premium_index = tokio_test::block_on(market.get_premium_index("BTCUSDT"));
+    /// assert!(premium_index.is_ok(), "{:?}", premium_index);
+    /// ```
+    pub async fn get_premium_index<S>(&self, symbol: S) -> Result<MarkPrice>
+    where
+        S: Into<String>,
+    {
+        self.client
+            .get_d("/fapi/v1/premiumIndex", Some(PairQuery { symbol: symbol.into() }))
+            .await
+    }
+
+    /// Mark price, index price and funding of ALL symbols
+    pub async fn get_all_premium_indexes(&self) -> Result<Vec<MarkPrice>> {
+        self.client.get_p("/fapi/v1/premiumIndex", None).await
+    }
+
+    /// Premium index of ONE symbol, or of ALL symbols if None
     pub async fn get_mark_prices(&self, symbol: Option<String>) -> Result<Vec<MarkPrice>> {
-        if let Some(symbol) = symbol {
-            Ok(vec![
-                self.client
-                    .get_d::<MarkPrice, PairQuery>("/fapi/v1/premiumIndex", Some(PairQuery { symbol }))
-                    .await?,
-            ])
-        } else {
-            self.client.get_p("/fapi/v1/premiumIndex", None).await
+        match symbol {
+            Some(symbol) => Ok(vec![self.get_premium_index(symbol).await?]),
+            None => self.get_all_premium_indexes().await,
         }
     }
 
@@ -548,6 +549,8 @@ impl FuturesMarket {
         self.client.get_p("/fapi/v1/allForceOrders", None).await
     }
 
+    /// Present open interest of a symbol
+    /// https://binance-docs.github.io/apidocs/futures/en/#open-interest
     pub async fn open_interest<S>(&self, symbol: S) -> Result<OpenInterest>
     where
         S: Into<String>,
@@ -556,4 +559,20 @@ impl FuturesMarket {
             .get_d("/fapi/v1/openInterest", Some(PairQuery { symbol: symbol.into() }))
             .await
     }
+
+    /// Latest price and its time for ONE symbol
+    /// https://binance-docs.github.io/apidocs/futures/en/#symbol-price-ticker-v2
+    pub async fn get_price_ticker<S>(&self, symbol: S) -> Result<SymbolPriceTicker>
+    where
+        S: Into<String>,
+    {
+        self.client
+            .get_d("/fapi/v2/ticker/price", Some(PairQuery { symbol: symbol.into() }))
+            .await
+    }
+
+    /// Latest price and its time for ALL symbols
+    pub async fn get_all_price_tickers(&self) -> Result<Vec<SymbolPriceTicker>> {
+        self.client.get_p("/fapi/v2/ticker/price", None).await
+    }
 }
@@ -1,4 +1,7 @@
 pub mod account;
+pub mod exchange_info;
 pub mod general;
+pub mod income;
 pub mod market;
 pub mod rest_model;
+pub mod ws_model;
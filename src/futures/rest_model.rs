@@ -1,8 +1,9 @@
 use serde::{Deserialize, Serialize};
+use serde_repr::{Deserialize_repr, Serialize_repr};
 
 use crate::rest_model::{string_or_bool, string_or_float, string_or_float_opt, string_or_u64};
 pub use crate::rest_model::{
-    Asks, Bids, BookTickers, KlineSummaries, KlineSummary, OrderSide, OrderStatus, RateLimit, ServerTime, SymbolPrice,
+    Asks, Bids, BookTickers, KlineSummaries, KlineSummary, Level, OrderSide, OrderStatus, RateLimit, ServerTime, SymbolPrice,
     SymbolStatus, Tickers, TimeInForce,
 };
 
@@ -56,7 +57,7 @@ pub struct Symbol {
     pub time_in_force: Vec<TimeInForce>,
 }
 
-#[derive(Debug, Deserialize, Serialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum ContractType {
     Perpetual,
@@ -64,8 +65,28 @@ pub enum ContractType {
     NextMonth,
     CurrentQuarter,
     NextQuarter,
+    #[serde(rename = "PERPETUAL DELIVERING")]
+    PerpetualDelivering,
     #[serde(rename = "")]
     Empty,
+    #[serde(other)]
+    Other,
+}
+
+/// Lifecycle of a futures contract
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ContractStatus {
+    PendingTrading,
+    Trading,
+    PreDelivering,
+    Delivering,
+    Delivered,
+    PreSettle,
+    Settling,
+    Close,
+    #[serde(other)]
+    Other,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -109,6 +130,24 @@ pub enum MarginType {
     Cross,
 }
 
+impl MarginType {
+    /// Name of the margin type in the requests, which differs from the one of the positions
+    pub fn as_request_param(&self) -> &'static str {
+        match self {
+            MarginType::Isolated => "ISOLATED",
+            MarginType::Cross => "CROSSED",
+        }
+    }
+}
+
+/// Confirmation of a setting change
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SettingChange {
+    Changed,
+    /// The setting already had the requested value
+    Unchanged,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(tag = "filterType")]
 pub enum Filters {
@@ -179,6 +218,22 @@ pub struct OrderBook {
     pub asks: Vec<Asks>,
 }
 
+impl OrderBook {
+    /// Highest bid
+    pub fn best_bid(&self) -> Option<&Level> { self.bids.first() }
+
+    /// Lowest ask
+    pub fn best_ask(&self) -> Option<&Level> { self.asks.first() }
+
+    /// Price halfway between the best bid and the best ask
+    pub fn mid_price(&self) -> Option<f64> { crate::rest_model::mid_price(&self.bids, &self.asks) }
+
+    /// Volume weighted average price of the `depth` first levels of both sides of the book
+    pub fn vwap(&self, depth: usize) -> Option<f64> {
+        crate::rest_model::vwap(self.bids.iter().take(depth).chain(self.asks.iter().take(depth)))
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct PriceStats {
@@ -258,6 +313,7 @@ pub struct AggTrade {
 //     AllMarkPrices(Vec<MarkPrice>),
 // }
 
+/// Premium index of a symbol
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct MarkPrice {
@@ -266,16 +322,28 @@ pub struct MarkPrice {
     pub mark_price: f64,
     #[serde(with = "string_or_float")]
     pub index_price: f64,
+    /// Only meaningful in the last hour before the settlement of delivery contracts
     #[serde(with = "string_or_float")]
     pub estimated_settle_price: f64,
     #[serde(with = "string_or_float")]
     pub last_funding_rate: f64,
+    /// In milliseconds
     pub next_funding_time: u64,
     #[serde(with = "string_or_float")]
     pub interest_rate: f64,
     pub time: u64,
 }
 
+impl MarkPrice {
+    /// Relative premium of the mark price over the index price
+    pub fn premium(&self) -> f64 {
+        if self.index_price == 0.0 {
+            return 0.0;
+        }
+        (self.mark_price - self.index_price) / self.index_price
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(untagged)]
 pub enum LiquidationOrders {
@@ -307,6 +375,34 @@ pub struct OpenInterest {
     #[serde(with = "string_or_float")]
     pub open_interest: f64,
     pub symbol: String,
+    #[serde(default)]
+    pub time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SymbolPriceTicker {
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub price: f64,
+    pub time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct BookTicker {
+    #[serde(default)]
+    pub last_update_id: u64,
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub bid_price: f64,
+    #[serde(with = "string_or_float")]
+    pub bid_qty: f64,
+    #[serde(with = "string_or_float")]
+    pub ask_price: f64,
+    #[serde(with = "string_or_float")]
+    pub ask_qty: f64,
+    pub time: u64,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -662,3 +758,136 @@ pub struct SymbolBrackets {
     pub symbol: String,
     pub brackets: Vec<LeverageBracket>,
 }
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum IncomeType {
+    Transfer,
+    WelcomeBonus,
+    RealizedPnl,
+    FundingFee,
+    Commission,
+    InsuranceClear,
+    ReferralKickback,
+    CommissionRebate,
+    ApiRebate,
+    ContestReward,
+    CrossCollateralTransfer,
+    OptionsPremiumFee,
+    OptionsSettleProfit,
+    InternalTransfer,
+    AutoExchange,
+    #[serde(rename = "DELIVERED_SETTELMENT")]
+    DeliveredSettlement,
+    CoinSwapDeposit,
+    CoinSwapWithdraw,
+    PositionLimitIncreaseFee,
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct IncomeQuery {
+    pub symbol: Option<String>,
+    pub income_type: Option<IncomeType>,
+    /// Default: 7 days before end_time, at most 3 months ago
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    pub page: Option<u64>,
+    /// Default: 100, Max: 1000
+    pub limit: Option<u16>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Income {
+    /// Empty for incomes not related to a symbol, e.g. transfers
+    pub symbol: String,
+    pub income_type: IncomeType,
+    /// Negative when paid
+    #[serde(with = "string_or_float")]
+    pub income: f64,
+    pub asset: String,
+    pub info: String,
+    pub time: u64,
+    #[serde(with = "string_or_u64")]
+    pub tran_id: u64,
+    /// Empty for incomes not related to a trade
+    pub trade_id: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CommissionRate {
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub maker_commission_rate: f64,
+    #[serde(with = "string_or_float")]
+    pub taker_commission_rate: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FeeBurnStatus {
+    /// Whether fees are paid in BNB
+    pub fee_burn: bool,
+}
+
+/// Direction of an isolated position margin change
+#[derive(Debug, Serialize_repr, Deserialize_repr, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum PositionMarginType {
+    Add = 1,
+    Reduce = 2,
+}
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionMarginRequest {
+    pub symbol: String,
+    /// Required in hedge mode
+    pub position_side: Option<PositionSide>,
+    pub amount: f64,
+    #[serde(rename = "type")]
+    pub margin_type: PositionMarginType,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionMarginResponse {
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    pub code: i32,
+    pub msg: String,
+    #[serde(rename = "type")]
+    pub margin_type: PositionMarginType,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionMarginHistoryQuery {
+    pub symbol: String,
+    #[serde(rename = "type")]
+    pub margin_type: Option<PositionMarginType>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    /// Default: 500
+    pub limit: Option<u16>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionMarginChange {
+    pub symbol: String,
+    #[serde(rename = "type")]
+    pub margin_type: PositionMarginType,
+    /// e.g. `USER_ADJUST`
+    #[serde(default)]
+    pub delta_type: String,
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    pub asset: String,
+    pub time: u64,
+    pub position_side: PositionSide,
+}
@@ -0,0 +1,69 @@
+use crate::futures::rest_model::PositionSide;
+use crate::rest_model::string_or_float;
+
+/// Futures user stream events
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "e")]
+pub enum FuturesWebsocketEvent {
+    #[serde(rename = "ACCOUNT_UPDATE")]
+    AccountUpdate(Box<AccountUpdateEvent>),
+}
+
+/// Balances and positions changed by a trade, a funding fee or a transfer
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountUpdateEvent {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    #[serde(rename = "T")]
+    pub transaction_time: u64,
+    #[serde(rename = "a")]
+    pub data: AccountUpdateData,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountUpdateData {
+    /// Reason of the update, e.g. ORDER, FUNDING_FEE, DEPOSIT or WITHDRAW
+    #[serde(rename = "m")]
+    pub reason: String,
+    /// Only the balances which changed
+    #[serde(rename = "B")]
+    pub balances: Vec<AccountUpdateBalance>,
+    /// Only the positions which changed
+    #[serde(rename = "P")]
+    pub positions: Vec<AccountUpdatePosition>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountUpdateBalance {
+    #[serde(rename = "a")]
+    pub asset: String,
+    #[serde(rename = "wb", with = "string_or_float")]
+    pub wallet_balance: f64,
+    #[serde(rename = "cw", with = "string_or_float")]
+    pub cross_wallet_balance: f64,
+    /// Balance change except pnl and commission
+    #[serde(rename = "bc", with = "string_or_float")]
+    pub balance_change: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountUpdatePosition {
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "pa", with = "string_or_float")]
+    pub position_amount: f64,
+    #[serde(rename = "ep", with = "string_or_float")]
+    pub entry_price: f64,
+    /// Accumulated realized pnl
+    #[serde(rename = "cr", with = "string_or_float")]
+    pub accumulated_realized: f64,
+    #[serde(rename = "up", with = "string_or_float")]
+    pub unrealized_pnl: f64,
+    /// cross or isolated
+    #[serde(rename = "mt")]
+    pub margin_type: String,
+    #[serde(rename = "iw", with = "string_or_float")]
+    pub isolated_wallet: f64,
+    #[serde(rename = "ps")]
+    pub position_side: PositionSide,
+}
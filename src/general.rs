@@ -1,14 +1,27 @@
+use std::time::{Duration, Instant};
+
+use chrono::Utc;
 use serde_json::Value;
 
 use crate::client::*;
 use crate::errors::*;
 use crate::rest_model::*;
+use crate::util::*;
 
 #[derive(Clone)]
 pub struct General {
     pub client: Client,
 }
 
+/// Difference between the server clock and the local clock
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClockOffset {
+    /// Server time minus local time, in milliseconds
+    pub offset_ms: i64,
+    /// Round trip time of the server time request the offset was measured with
+    pub round_trip: Duration,
+}
+
 impl General {
     /// Test connectivity
     /// # Examples
@@ -37,6 +50,43 @@ impl General {
     /// ```
     pub async fn get_server_time(&self) -> Result<ServerTime> { self.client.get("/api/v3/time", None).await }
 
+    /// Round trip time of a ping
+    /// # Examples
+    /// ```rust
+    /// use binance::{api::*, general::*, config::*};
+    /// let conf = Config::default().set_rest_api_endpoint(DATA_REST_ENDPOINT);
+    /// let general: General = Binance::new_with_env(&conf);
+    /// let latency = tokio_test::block_on(general.ping_latency());
+    /// assert!(latency.is_ok(), "{:?}", latency);
+    /// ```
+    pub async fn ping_latency(&self) -> Result<Duration> {
+        let start = Instant::now();
+        self.ping().await?;
+        Ok(start.elapsed())
+    }
+
+    /// Measure the offset between the server clock and the local clock,
+    /// assuming the server time was read halfway through the request
+    /// # Examples
+    /// ```rust
+    /// use binance::{api::*, general::*, config::*};
+    /// let conf = Config::default().set_rest_api_endpoint(DATA_REST_ENDPOINT);
+    /// let general: General = Binance::new_with_env(&conf);
+    /// let offset = tokio_test::block_on(general.measure_clock_offset());
+    /// assert!(offset.is_ok(), "{:?}", offset);
+    /// ```
+    pub async fn measure_clock_offset(&self) -> Result<ClockOffset> {
+        let local_start = Utc::now().timestamp_millis();
+        let start = Instant::now();
+        let server_time = self.get_server_time().await?;
+        let round_trip = start.elapsed();
+        let local_time = local_start + (round_trip.as_millis() / 2) as i64;
+        Ok(ClockOffset {
+            offset_ms: server_time.server_time as i64 - local_time,
+            round_trip,
+        })
+    }
+
     /// Obtain exchange information (rate limits, symbol metadata etc)
     /// # Examples
     /// ```rust
@@ -49,4 +99,29 @@ impl General {
     pub async fn exchange_info(&self) -> Result<ExchangeInformation> {
         self.client.get("/api/v3/exchangeInfo", None).await
     }
+
+    /// Obtain exchange information for a subset of symbols, selected either by name or by permissions.
+    /// This avoids downloading the full exchange information when only a few symbols are needed.
+    /// # Examples
+    /// ```rust
+    /// use binance::{api::*, general::*, config::*, rest_model::*};
+    /// let conf = Config::default().set_rest_api_endpoint(DATA_REST_ENDPOINT);
+    /// let general: General = Binance::new_with_env(&conf);
+    /// let query = ExchangeInfoQuery { symbols: vec!["BTCUSDT".to_string(), "BNBBTC".to_string()], ..Default::default() };
+    /// let exchange_info = tokio_test::block_on(general.exchange_info_filtered(query));
+    /// assert!(exchange_info.is_ok(), "{:?}", exchange_info);
+    /// assert_eq!(exchange_info.unwrap().symbols.len(), 2);
+    /// ```
+    pub async fn exchange_info_filtered(&self, query: ExchangeInfoQuery) -> Result<ExchangeInformation> {
+        let parameters = IntoIterator::into_iter([
+            json_array_param("symbols", &query.symbols)?,
+            json_array_param("permissions", &query.permissions)?,
+            query
+                .show_permission_sets
+                .map(|show| ("showPermissionSets", show.to_string())),
+        ])
+        .flatten();
+        let request = build_request(parameters);
+        self.client.get("/api/v3/exchangeInfo", Some(&request)).await
+    }
 }
@@ -0,0 +1,582 @@
+//! Chronological ledger of the movements of assets across the Binance products.
+
+use std::collections::HashMap;
+use std::io::Write;
+
+use chrono::NaiveDateTime;
+use futures::future::try_join_all;
+use futures::TryStreamExt;
+
+use crate::account::{Account, TradesQuery};
+use crate::convert::{Convert, ConvertTradeFlowQuery};
+use crate::errors::*;
+use crate::futures::account::FuturesAccount;
+use crate::futures::rest_model::{IncomeQuery, IncomeType};
+use crate::general::General;
+use crate::margin::Margin;
+use crate::rest_model::{
+    DepositHistoryQuery, ExchangeInfoQuery, RecordsQuery, TradeHistory, UniversalTransferHistoryQuery,
+    UniversalTransferStatus, UniversalTransferType, WithdrawalHistoryQuery,
+};
+use crate::util::time_windows;
+use crate::wallet::Wallet;
+
+static DAY_MS: u64 = 24 * 60 * 60 * 1000;
+/// Longest time range accepted by the deposit and withdrawal histories
+static DEPOSIT_WITHDRAWAL_WINDOW_MS: u64 = 90 * DAY_MS;
+/// Longest time range accepted by the convert, margin interest and transfer histories
+static THIRTY_DAYS_WINDOW_MS: u64 = 30 * DAY_MS;
+static CONVERT_PAGE_SIZE: u32 = 1000;
+static TRADE_PAGE_SIZE: u32 = 1000;
+
+/// Transfers between the spot account and the other products
+pub static DEFAULT_TRANSFER_TYPES: [UniversalTransferType; 8] = [
+    UniversalTransferType::MainFunding,
+    UniversalTransferType::FundingMain,
+    UniversalTransferType::MainMargin,
+    UniversalTransferType::MarginMain,
+    UniversalTransferType::MainUmfuture,
+    UniversalTransferType::UmfutureMain,
+    UniversalTransferType::MainCmfuture,
+    UniversalTransferType::CmfutureMain,
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LedgerEntryKind {
+    Deposit,
+    Withdrawal,
+    Trade,
+    Convert,
+    Dust,
+    Dividend,
+    FundingFee,
+    Interest,
+    Transfer,
+}
+
+impl LedgerEntryKind {
+    pub const ALL: [LedgerEntryKind; 9] = [
+        LedgerEntryKind::Deposit,
+        LedgerEntryKind::Withdrawal,
+        LedgerEntryKind::Trade,
+        LedgerEntryKind::Convert,
+        LedgerEntryKind::Dust,
+        LedgerEntryKind::Dividend,
+        LedgerEntryKind::FundingFee,
+        LedgerEntryKind::Interest,
+        LedgerEntryKind::Transfer,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LedgerEntryKind::Deposit => "deposit",
+            LedgerEntryKind::Withdrawal => "withdrawal",
+            LedgerEntryKind::Trade => "trade",
+            LedgerEntryKind::Convert => "convert",
+            LedgerEntryKind::Dust => "dust",
+            LedgerEntryKind::Dividend => "dividend",
+            LedgerEntryKind::FundingFee => "funding_fee",
+            LedgerEntryKind::Interest => "interest",
+            LedgerEntryKind::Transfer => "transfer",
+        }
+    }
+}
+
+/// A positive quantity of an asset
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LedgerAmount {
+    pub asset: String,
+    pub amount: f64,
+}
+
+impl LedgerAmount {
+    fn new<S: Into<String>>(asset: S, amount: f64) -> Self {
+        Self {
+            asset: asset.into(),
+            amount,
+        }
+    }
+}
+
+/// A movement of assets, normalized from the record of one of the history endpoints
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LedgerEntry {
+    pub time: u64,
+    pub kind: LedgerEntryKind,
+    /// Asset leaving the account
+    pub sent: Option<LedgerAmount>,
+    /// Asset entering the account
+    pub received: Option<LedgerAmount>,
+    pub fee: Option<LedgerAmount>,
+    /// Id of the record in its endpoint, e.g. a transaction hash, a trade or an order id
+    pub reference: String,
+    /// e.g. the symbol of a trade or the network of a deposit
+    pub description: Option<String>,
+}
+
+impl LedgerEntry {
+    fn new(time: u64, kind: LedgerEntryKind, reference: String) -> Self {
+        Self {
+            time,
+            kind,
+            sent: None,
+            received: None,
+            fee: None,
+            reference,
+            description: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct LedgerQuery {
+    pub start_time: u64,
+    pub end_time: u64,
+    /// Spot symbols to pull the trades of, trades are only available for explicitly listed symbols
+    pub symbols: Vec<String>,
+    /// Assets to pull the margin interests of, interests are only available for explicitly listed assets
+    pub assets: Vec<String>,
+}
+
+/// Pulls the history endpoints of the Binance products into a single chronological ledger.
+///
+/// Only settled records are kept: successful deposits, completed withdrawals, successful conversions
+/// and confirmed transfers. Spot trades are paged by trade id, from the first trade of the range found by a binary
+/// search over the ids, which takes a few dozen requests per symbol rather than one per day.
+///
+/// # Examples
+/// ```rust,no_run
+/// use binance::{api::*, config::*, ledger::*};
+/// let ledger: Ledger = Binance::new_with_env(&Config::default());
+/// let query = LedgerQuery {
+///     start_time: 1_672_531_200_000,
+///     end_time: 1_704_067_199_999,
+///     symbols: vec!["BTCUSDT".to_string()],
+///     assets: vec!["USDT".to_string()],
+/// };
+/// let entries = tokio_test::block_on(ledger.entries(query));
+/// assert!(entries.is_ok(), "{:?}", entries);
+/// ```
+#[derive(Clone)]
+pub struct Ledger {
+    pub account: Account,
+    pub general: General,
+    pub wallet: Wallet,
+    pub margin: Margin,
+    pub futures: FuturesAccount,
+    pub convert: Convert,
+    /// Kinds of entries to pull, all by default
+    pub kinds: Vec<LedgerEntryKind>,
+    /// Transfers to pull, transfers between the spot account and the other products by default
+    pub transfer_types: Vec<UniversalTransferType>,
+}
+
+impl Ledger {
+    /// Only pull the entries of `kinds`, e.g. to skip products which are not enabled for the account
+    pub fn with_kinds(mut self, kinds: &[LedgerEntryKind]) -> Self {
+        self.kinds = kinds.to_vec();
+        self
+    }
+
+    /// Only pull the transfers of `transfer_types`
+    pub fn with_transfer_types(mut self, transfer_types: &[UniversalTransferType]) -> Self {
+        self.transfer_types = transfer_types.to_vec();
+        self
+    }
+
+    /// All the entries between `query.start_time` and `query.end_time`, oldest first
+    pub async fn entries(&self, query: LedgerQuery) -> Result<Vec<LedgerEntry>> {
+        let entries = try_join_all(self.kinds.iter().map(|kind| self.kind_entries(*kind, &query))).await?;
+        let mut entries: Vec<LedgerEntry> = entries
+            .into_iter()
+            .flatten()
+            .filter(|e| e.time >= query.start_time && e.time <= query.end_time)
+            .collect();
+        entries.sort_by_key(|e| e.time);
+        Ok(entries)
+    }
+
+    async fn kind_entries(&self, kind: LedgerEntryKind, query: &LedgerQuery) -> Result<Vec<LedgerEntry>> {
+        match kind {
+            LedgerEntryKind::Deposit => self.deposits(query).await,
+            LedgerEntryKind::Withdrawal => self.withdrawals(query).await,
+            LedgerEntryKind::Trade => self.trades(query).await,
+            LedgerEntryKind::Convert => self.conversions(query).await,
+            LedgerEntryKind::Dust => self.dust(query).await,
+            LedgerEntryKind::Dividend => self.dividends(query).await,
+            LedgerEntryKind::FundingFee => self.funding_fees(query).await,
+            LedgerEntryKind::Interest => self.interests(query).await,
+            LedgerEntryKind::Transfer => self.transfers(query).await,
+        }
+    }
+
+    async fn deposits(&self, query: &LedgerQuery) -> Result<Vec<LedgerEntry>> {
+        let mut entries = vec![];
+        for (start_time, end_time) in time_windows(query.start_time, query.end_time, DEPOSIT_WITHDRAWAL_WINDOW_MS) {
+            let mut offset = 0;
+            loop {
+                let records = self
+                    .wallet
+                    .deposit_history(&DepositHistoryQuery {
+                        status: Some(1),
+                        start_time: Some(start_time),
+                        end_time: Some(end_time),
+                        limit: Some(1000),
+                        offset: Some(offset),
+                        ..DepositHistoryQuery::default()
+                    })
+                    .await?;
+                let count = records.len() as u64;
+                entries.extend(records.into_iter().map(|r| LedgerEntry {
+                    received: Some(LedgerAmount::new(r.coin, r.amount)),
+                    description: Some(r.network),
+                    ..LedgerEntry::new(r.insert_time.unwrap_or_default(), LedgerEntryKind::Deposit, r.tx_id)
+                }));
+                if count < 1000 {
+                    break;
+                }
+                offset += count;
+            }
+        }
+        Ok(entries)
+    }
+
+    async fn withdrawals(&self, query: &LedgerQuery) -> Result<Vec<LedgerEntry>> {
+        let mut entries = vec![];
+        for (start_time, end_time) in time_windows(query.start_time, query.end_time, DEPOSIT_WITHDRAWAL_WINDOW_MS) {
+            let mut offset = 0;
+            loop {
+                let records = self
+                    .wallet
+                    .withdraw_history(&WithdrawalHistoryQuery {
+                        status: Some(6),
+                        start_time: Some(start_time),
+                        end_time: Some(end_time),
+                        limit: Some(1000),
+                        offset: Some(offset),
+                        ..WithdrawalHistoryQuery::default()
+                    })
+                    .await?;
+                let count = records.len() as u64;
+                for r in records {
+                    let time = NaiveDateTime::parse_from_str(&r.apply_time, "%Y-%m-%d %H:%M:%S")
+                        .map_err(|e| Error::Msg(format!("invalid withdrawal apply time {}: {e}", r.apply_time)))?
+                        .and_utc()
+                        .timestamp_millis() as u64;
+                    entries.push(LedgerEntry {
+                        sent: Some(LedgerAmount::new(r.coin.clone(), r.amount)),
+                        fee: (r.transaction_fee != 0.0).then(|| LedgerAmount::new(r.coin, r.transaction_fee)),
+                        description: Some(r.network),
+                        ..LedgerEntry::new(time, LedgerEntryKind::Withdrawal, r.tx_id.unwrap_or(r.id))
+                    });
+                }
+                if count < 1000 {
+                    break;
+                }
+                offset += count;
+            }
+        }
+        Ok(entries)
+    }
+
+    async fn trades(&self, query: &LedgerQuery) -> Result<Vec<LedgerEntry>> {
+        if query.symbols.is_empty() {
+            return Ok(vec![]);
+        }
+        let symbols: HashMap<String, (String, String)> = self
+            .general
+            .exchange_info_filtered(ExchangeInfoQuery {
+                symbols: query.symbols.clone(),
+                ..ExchangeInfoQuery::default()
+            })
+            .await?
+            .symbols
+            .into_iter()
+            .map(|s| (s.symbol, (s.base_asset, s.quote_asset)))
+            .collect();
+        let mut entries = vec![];
+        for symbol in &query.symbols {
+            let (base, quote) = symbols
+                .get(symbol)
+                .ok_or_else(|| Error::UnknownSymbol(symbol.clone()))?;
+            for t in self.symbol_trades(symbol, query).await? {
+                let base_amount = LedgerAmount::new(base.clone(), t.qty);
+                let quote_amount = LedgerAmount::new(quote.clone(), t.quote_qty);
+                let (sent, received) = if t.is_buyer {
+                    (quote_amount, base_amount)
+                } else {
+                    (base_amount, quote_amount)
+                };
+                entries.push(LedgerEntry {
+                    sent: Some(sent),
+                    received: Some(received),
+                    fee: (t.commission != 0.0).then(|| LedgerAmount::new(t.commission_asset, t.commission)),
+                    description: Some(symbol.clone()),
+                    ..LedgerEntry::new(t.time, LedgerEntryKind::Trade, t.id.to_string())
+                });
+            }
+        }
+        Ok(entries)
+    }
+
+    /// Trades of `symbol` in the query range, paged by trade id from the first trade of the range
+    async fn symbol_trades(&self, symbol: &str, query: &LedgerQuery) -> Result<Vec<TradeHistory>> {
+        let Some(first) = self.first_trade(symbol, query.start_time).await? else {
+            return Ok(vec![]);
+        };
+        let mut trades = vec![];
+        let mut from_id = first.id;
+        loop {
+            let page = self.trades_from(symbol, from_id, TRADE_PAGE_SIZE).await?;
+            let full = page.len() == TRADE_PAGE_SIZE as usize;
+            let next_id = page.last().map(|t| t.id + 1);
+            let before = trades.len();
+            trades.extend(page.into_iter().take_while(|t| t.time <= query.end_time));
+            let past_range = trades.len() - before < TRADE_PAGE_SIZE as usize;
+            match next_id {
+                Some(next_id) if full && !past_range => from_id = next_id,
+                _ => break,
+            }
+        }
+        Ok(trades)
+    }
+
+    /// First trade of `symbol` at or after `start_time`, found by a binary search over the trade ids since the
+    /// trade history only accepts time ranges of a day
+    async fn first_trade(&self, symbol: &str, start_time: u64) -> Result<Option<TradeHistory>> {
+        let latest = self
+            .account
+            .get_trades(TradesQuery {
+                symbol: symbol.to_string(),
+                limit: Some(1),
+                ..TradesQuery::default()
+            })
+            .await?;
+        let Some(mut first) = latest.into_iter().next().filter(|t| t.time >= start_time) else {
+            return Ok(None);
+        };
+        // `first` is the first trade from the id `high`, the trades from the ids below `low` are before start_time
+        let (mut low, mut high) = (0, first.id);
+        while low < high {
+            let mid = low + (high - low) / 2;
+            let trade = self
+                .trades_from(symbol, mid, 1)
+                .await?
+                .into_iter()
+                .next()
+                .ok_or_else(|| Error::Msg(format!("no {symbol} trade from id {mid}")))?;
+            if trade.time >= start_time {
+                high = mid;
+                first = trade;
+            } else {
+                low = trade.id + 1;
+            }
+        }
+        Ok(Some(first))
+    }
+
+    async fn trades_from(&self, symbol: &str, from_id: u64, limit: u32) -> Result<Vec<TradeHistory>> {
+        self.account
+            .get_trades(TradesQuery {
+                symbol: symbol.to_string(),
+                from_id: Some(from_id),
+                limit: Some(limit),
+                ..TradesQuery::default()
+            })
+            .await
+    }
+
+    async fn conversions(&self, query: &LedgerQuery) -> Result<Vec<LedgerEntry>> {
+        let mut entries = vec![];
+        let mut pending = time_windows(query.start_time, query.end_time, THIRTY_DAYS_WINDOW_MS);
+        while let Some((start_time, end_time)) = pending.pop() {
+            let flow = self
+                .convert
+                .trade_history(ConvertTradeFlowQuery {
+                    start_time,
+                    end_time,
+                    limit: Some(CONVERT_PAGE_SIZE),
+                })
+                .await?;
+            // The history has no cursor, a window holding more than a page is split in halves until none does
+            if flow.more_data || flow.list.len() >= CONVERT_PAGE_SIZE as usize {
+                if start_time == end_time {
+                    return Err(Error::Msg(format!(
+                        "more than {CONVERT_PAGE_SIZE} conversions at {start_time}, they cannot be paged"
+                    )));
+                }
+                let middle = start_time + (end_time - start_time) / 2;
+                pending.push((middle + 1, end_time));
+                pending.push((start_time, middle));
+                continue;
+            }
+            entries.extend(
+                flow.list
+                    .into_iter()
+                    .filter(|o| o.order_status == "SUCCESS")
+                    .map(|o| LedgerEntry {
+                        sent: Some(LedgerAmount::new(o.from_asset, o.from_amount)),
+                        received: Some(LedgerAmount::new(o.to_asset, o.to_amount)),
+                        ..LedgerEntry::new(o.create_time, LedgerEntryKind::Convert, o.order_id.to_string())
+                    }),
+            );
+        }
+        Ok(entries)
+    }
+
+    async fn dust(&self, query: &LedgerQuery) -> Result<Vec<LedgerEntry>> {
+        let log = self
+            .wallet
+            .dust_log(Some(query.start_time), Some(query.end_time))
+            .await?;
+        Ok(log
+            .user_asset_dribblets
+            .into_iter()
+            .flat_map(|d| d.user_asset_dribblet_details)
+            .map(|d| LedgerEntry {
+                sent: Some(LedgerAmount::new(d.from_asset, d.amount)),
+                received: Some(LedgerAmount::new("BNB", d.transfered_amount)),
+                fee: Some(LedgerAmount::new("BNB", d.service_charge_amount)),
+                ..LedgerEntry::new(d.operate_time, LedgerEntryKind::Dust, d.trans_id.to_string())
+            })
+            .collect())
+    }
+
+    async fn dividends(&self, query: &LedgerQuery) -> Result<Vec<LedgerEntry>> {
+        self.wallet
+            .asset_dividends_range(None, query.start_time, query.end_time)
+            .map_ok(|d| LedgerEntry {
+                received: Some(LedgerAmount::new(d.asset, d.amount)),
+                description: Some(d.en_info),
+                ..LedgerEntry::new(d.div_time, LedgerEntryKind::Dividend, d.tran_id.to_string())
+            })
+            .try_collect()
+            .await
+    }
+
+    async fn funding_fees(&self, query: &LedgerQuery) -> Result<Vec<LedgerEntry>> {
+        let mut entries = vec![];
+        let mut page = 1;
+        loop {
+            let incomes = self
+                .futures
+                .income(IncomeQuery {
+                    income_type: Some(IncomeType::FundingFee),
+                    start_time: Some(query.start_time),
+                    end_time: Some(query.end_time),
+                    page: Some(page),
+                    limit: Some(1000),
+                    ..IncomeQuery::default()
+                })
+                .await?;
+            let count = incomes.len();
+            entries.extend(incomes.into_iter().map(|i| {
+                let amount = LedgerAmount::new(i.asset, i.income.abs());
+                let (sent, received) = if i.income < 0.0 {
+                    (Some(amount), None)
+                } else {
+                    (None, Some(amount))
+                };
+                LedgerEntry {
+                    sent,
+                    received,
+                    description: Some(i.symbol),
+                    ..LedgerEntry::new(i.time, LedgerEntryKind::FundingFee, i.tran_id.to_string())
+                }
+            }));
+            if count < 1000 {
+                break;
+            }
+            page += 1;
+        }
+        Ok(entries)
+    }
+
+    async fn interests(&self, query: &LedgerQuery) -> Result<Vec<LedgerEntry>> {
+        let mut entries = vec![];
+        for asset in &query.assets {
+            for (start_time, end_time) in time_windows(query.start_time, query.end_time, THIRTY_DAYS_WINDOW_MS) {
+                let mut current = 1;
+                loop {
+                    let interests = self
+                        .margin
+                        .interests(RecordsQuery {
+                            asset: asset.clone(),
+                            start_time: Some(start_time),
+                            end_time: Some(end_time),
+                            current: Some(current),
+                            size: Some(100),
+                            ..RecordsQuery::default()
+                        })
+                        .await?;
+                    let rows = interests.rows.unwrap_or_default();
+                    let count = rows.len();
+                    entries.extend(rows.into_iter().map(|i| {
+                        // Interests have no id, the assets and isolated symbols accrue at the same hours
+                        let reference = match &i.isolated_symbol {
+                            Some(symbol) => format!("{}-{}-{symbol}", i.interest_accured_time, i.asset),
+                            None => format!("{}-{}", i.interest_accured_time, i.asset),
+                        };
+                        LedgerEntry {
+                            sent: Some(LedgerAmount::new(i.asset, i.interest)),
+                            description: i.isolated_symbol,
+                            ..LedgerEntry::new(i.interest_accured_time, LedgerEntryKind::Interest, reference)
+                        }
+                    }));
+                    if count < 100 || (current as u64) * 100 >= interests.total {
+                        break;
+                    }
+                    current += 1;
+                }
+            }
+        }
+        Ok(entries)
+    }
+
+    async fn transfers(&self, query: &LedgerQuery) -> Result<Vec<LedgerEntry>> {
+        let mut entries = vec![];
+        for transfer_type in &self.transfer_types {
+            for (start_time, end_time) in time_windows(query.start_time, query.end_time, THIRTY_DAYS_WINDOW_MS) {
+                let mut current = 1;
+                loop {
+                    let transfers = self
+                        .wallet
+                        .universal_transfer_history(UniversalTransferHistoryQuery {
+                            transfer_type: transfer_type.clone(),
+                            start_time: Some(start_time),
+                            end_time: Some(end_time),
+                            current: Some(current),
+                            size: Some(100),
+                            from_symbol: None,
+                            to_symbol: None,
+                        })
+                        .await?;
+                    let rows = transfers.rows.unwrap_or_default();
+                    let count = rows.len();
+                    entries.extend(
+                        rows.into_iter()
+                            .filter(|t| t.status == UniversalTransferStatus::Confirmed)
+                            .map(|t| LedgerEntry {
+                                sent: Some(LedgerAmount::new(t.asset.clone(), t.amount)),
+                                received: Some(LedgerAmount::new(t.asset, t.amount)),
+                                description: Some(format!("{:?}", t.transfer_type)),
+                                ..LedgerEntry::new(t.timestamp, LedgerEntryKind::Transfer, t.tran_id.to_string())
+                            }),
+                    );
+                    if count < 100 || current * 100 >= transfers.total {
+                        break;
+                    }
+                    current += 1;
+                }
+            }
+        }
+        Ok(entries)
+    }
+}
+
+/// Write the entries to `writer` as a JSON array
+pub fn write_json<W: Write>(entries: &[LedgerEntry], writer: W) -> Result<()> {
+    serde_json::to_writer_pretty(writer, entries)?;
+    Ok(())
+}
@@ -52,10 +52,10 @@
 //! # Details
 //!
 //! - Credentials are not enforced, you will get authentication errors if you don't provide
-//! credentials and they are required by an endpoint
+//!   credentials and they are required by an endpoint
 //!
 //! - Error codes are handled on a best effort basis as some are inconsistent and not even
-//! documented on Binance's side
+//!   documented on Binance's side
 //!
 //! - Errors are implemented using [![thiserror]](https://docs.rs/thiserror/1.0.25/thiserror/)
 //!
@@ -76,15 +76,91 @@ pub mod errors;
 pub mod util;
 
 pub mod account;
+pub mod account_pool;
+#[cfg(feature = "algo_api")]
+pub mod algo;
 pub mod api;
+#[cfg(all(
+    feature = "futures_api",
+    feature = "margin_api",
+    feature = "wallet_api",
+    feature = "simple_earn_api"
+))]
+pub mod balance_book;
+pub mod binance_us;
+#[cfg(feature = "blocking")]
+pub mod blocking;
+#[cfg(all(feature = "wasm", target_arch = "wasm32"))]
+pub mod browser_websockets;
+#[cfg(feature = "blvt_api")]
+pub mod blvt;
+#[cfg(feature = "broker_api")]
+pub mod broker;
+#[cfg(feature = "c2c_api")]
+pub mod c2c;
 pub mod config;
+#[cfg(feature = "convert_api")]
+pub mod convert;
+#[cfg(feature = "dataframe")]
+pub mod dataframe;
+#[cfg(feature = "dual_investment_api")]
+pub mod dual_investment;
+pub mod exchange_info;
+#[cfg(feature = "csv_export")]
+pub mod export;
+#[cfg(feature = "futures_api")]
 pub mod futures;
 pub mod general;
+#[cfg(all(
+    feature = "futures_api",
+    feature = "margin_api",
+    feature = "wallet_api",
+    feature = "convert_api"
+))]
+pub mod ledger;
+#[cfg(feature = "loans_api")]
+pub mod loans;
+#[cfg(feature = "margin_api")]
 pub mod margin;
 pub mod market;
+#[cfg(feature = "mining_api")]
+pub mod mining;
+#[cfg(feature = "options_api")]
+pub mod options;
+#[cfg(feature = "pay_api")]
+pub mod pay;
+pub mod permissions;
+#[cfg(all(
+    feature = "futures_api",
+    feature = "margin_api",
+    feature = "wallet_api",
+    feature = "simple_earn_api"
+))]
+pub mod portfolio;
+#[cfg(feature = "portfolio_margin_api")]
+pub mod portfolio_margin;
+#[cfg(feature = "rebate_api")]
+pub mod rebate;
+pub mod resample;
 pub mod rest_model;
+#[cfg(feature = "savings_api")]
 pub mod savings;
+pub mod scheduler;
+#[cfg(feature = "schema_drift")]
+pub mod schema_drift;
+pub mod signer;
+pub mod slippage;
+#[cfg(feature = "simple_earn_api")]
+pub mod simple_earn;
+#[cfg(feature = "staking_api")]
+pub mod staking;
+#[cfg(feature = "sub_account_api")]
+pub mod sub_account;
 pub mod userstream;
+#[cfg(feature = "vip_loans_api")]
+pub mod vip_loans;
+#[cfg(feature = "wallet_api")]
 pub mod wallet;
+#[cfg(any(feature = "websockets", feature = "wasm"))]
 pub mod websockets;
 pub mod ws_model;
@@ -0,0 +1,423 @@
+//! Flexible rate crypto loans, borrowing against collateral without a fixed term.
+
+use std::collections::HashMap;
+
+use crate::client::*;
+use crate::errors::*;
+use crate::rest_model::{string_or_float, AdjustmentDirection, RecordsQueryResult};
+
+static SAPI_V2_LOAN_FLEXIBLE_BORROW: &str = "/sapi/v2/loan/flexible/borrow";
+static SAPI_V2_LOAN_FLEXIBLE_REPAY: &str = "/sapi/v2/loan/flexible/repay";
+static SAPI_V2_LOAN_FLEXIBLE_ADJUST_LTV: &str = "/sapi/v2/loan/flexible/adjust/ltv";
+static SAPI_V2_LOAN_FLEXIBLE_ONGOING_ORDERS: &str = "/sapi/v2/loan/flexible/ongoing/orders";
+static SAPI_V2_LOAN_FLEXIBLE_LOANABLE_DATA: &str = "/sapi/v2/loan/flexible/loanable/data";
+static SAPI_V2_LOAN_FLEXIBLE_COLLATERAL_DATA: &str = "/sapi/v2/loan/flexible/collateral/data";
+static SAPI_V2_LOAN_FLEXIBLE_BORROW_HISTORY: &str = "/sapi/v2/loan/flexible/borrow/history";
+static SAPI_V2_LOAN_FLEXIBLE_REPAY_HISTORY: &str = "/sapi/v2/loan/flexible/repay/history";
+static SAPI_V2_LOAN_FLEXIBLE_LTV_ADJUSTMENT_HISTORY: &str = "/sapi/v2/loan/flexible/ltv/adjustment/history";
+static SAPI_V2_LOAN_FLEXIBLE_LIQUIDATION_HISTORY: &str = "/sapi/v2/loan/flexible/liquidation/history";
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct FlexibleBorrowQuery {
+    pub loan_coin: String,
+    /// Mandatory when collateral_amount is empty
+    pub loan_amount: Option<f64>,
+    pub collateral_coin: String,
+    /// Mandatory when loan_amount is empty
+    pub collateral_amount: Option<f64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FlexibleBorrowResponse {
+    pub loan_coin: String,
+    #[serde(with = "string_or_float")]
+    pub loan_amount: f64,
+    pub collateral_coin: String,
+    #[serde(with = "string_or_float")]
+    pub collateral_amount: f64,
+    /// Succeeds, Failed or Processing
+    pub status: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct FlexibleRepayQuery {
+    pub loan_coin: String,
+    pub collateral_coin: String,
+    pub repay_amount: f64,
+    /// Return the collateral in excess of the LTV after a partial repayment, default: true
+    pub collateral_return: Option<bool>,
+    /// Default: false
+    pub full_repayment: Option<bool>,
+    /// 1: repay with the loan coin, 2: repay with the collateral, default: 1
+    pub repayment_type: Option<u8>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FlexibleRepayResponse {
+    pub loan_coin: String,
+    pub collateral_coin: String,
+    #[serde(with = "string_or_float")]
+    pub remaining_debt: f64,
+    #[serde(with = "string_or_float")]
+    pub remaining_collateral: f64,
+    pub full_repayment: bool,
+    #[serde(rename = "currentLTV", with = "string_or_float")]
+    pub current_ltv: f64,
+    /// Repaid, Repaying or Failed
+    pub repay_status: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FlexibleAdjustLtvQuery {
+    pub loan_coin: String,
+    pub collateral_coin: String,
+    pub adjustment_amount: f64,
+    pub direction: AdjustmentDirection,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FlexibleAdjustLtvResponse {
+    pub loan_coin: String,
+    pub collateral_coin: String,
+    pub direction: AdjustmentDirection,
+    #[serde(with = "string_or_float")]
+    pub adjustment_amount: f64,
+    #[serde(rename = "currentLTV", with = "string_or_float")]
+    pub current_ltv: f64,
+    pub status: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct FlexibleLoanOrderQuery {
+    pub loan_coin: Option<String>,
+    pub collateral_coin: Option<String>,
+    /// Page number, starting at 1
+    pub current: Option<u64>,
+    /// Default: 10, Max: 100
+    pub limit: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FlexibleLoanOrder {
+    pub loan_coin: String,
+    #[serde(with = "string_or_float")]
+    pub total_debt: f64,
+    pub collateral_coin: String,
+    #[serde(with = "string_or_float")]
+    pub collateral_amount: f64,
+    #[serde(rename = "currentLTV", with = "string_or_float")]
+    pub current_ltv: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LoanableAsset {
+    pub loan_coin: String,
+    /// Hourly interest rate
+    #[serde(with = "string_or_float")]
+    pub flexible_interest_rate: f64,
+    #[serde(with = "string_or_float")]
+    pub flexible_min_limit: f64,
+    #[serde(with = "string_or_float")]
+    pub flexible_max_limit: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CollateralAsset {
+    pub collateral_coin: String,
+    #[serde(rename = "initialLTV", with = "string_or_float")]
+    pub initial_ltv: f64,
+    #[serde(rename = "marginCallLTV", with = "string_or_float")]
+    pub margin_call_ltv: f64,
+    #[serde(rename = "liquidationLTV", with = "string_or_float")]
+    pub liquidation_ltv: f64,
+    #[serde(with = "string_or_float")]
+    pub max_limit: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct FlexibleLoanHistoryQuery {
+    pub loan_coin: Option<String>,
+    pub collateral_coin: Option<String>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    /// Page number, starting at 1
+    pub current: Option<u64>,
+    /// Default: 10, Max: 100
+    pub limit: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FlexibleBorrowRecord {
+    pub loan_coin: String,
+    #[serde(with = "string_or_float")]
+    pub initial_loan_amount: f64,
+    pub collateral_coin: String,
+    #[serde(with = "string_or_float")]
+    pub initial_collateral_amount: f64,
+    pub borrow_time: u64,
+    pub status: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FlexibleRepayRecord {
+    pub loan_coin: String,
+    #[serde(with = "string_or_float")]
+    pub repay_amount: f64,
+    pub collateral_coin: String,
+    #[serde(with = "string_or_float")]
+    pub collateral_return: f64,
+    pub repay_status: String,
+    pub repay_time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LtvAdjustmentRecord {
+    pub loan_coin: String,
+    pub collateral_coin: String,
+    pub direction: AdjustmentDirection,
+    #[serde(with = "string_or_float")]
+    pub collateral_amount: f64,
+    #[serde(rename = "preLTV", with = "string_or_float")]
+    pub pre_ltv: f64,
+    #[serde(rename = "afterLTV", with = "string_or_float")]
+    pub after_ltv: f64,
+    pub adjust_time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LiquidationRecord {
+    pub loan_coin: String,
+    #[serde(with = "string_or_float")]
+    pub liquidation_debt: f64,
+    pub collateral_coin: String,
+    #[serde(with = "string_or_float")]
+    pub liquidation_collateral_amount: f64,
+    #[serde(with = "string_or_float")]
+    pub return_collateral_amount: f64,
+    #[serde(with = "string_or_float")]
+    pub liquidation_fee: f64,
+    #[serde(with = "string_or_float")]
+    pub liquidation_starting_price: f64,
+    pub liquidation_starting_time: u64,
+    pub status: String,
+}
+
+/// This struct acts as a gateway for all flexible loan endpoints.
+/// Preferably use the trait [`crate::api::Binance`] to get an instance.
+#[derive(Clone)]
+pub struct Loans {
+    pub client: Client,
+    pub recv_window: u64,
+}
+
+impl Loans {
+    /// Borrow `loan_coin` against `collateral_coin`
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, loans::*, config::*};
+    /// let loans: Loans = Binance::new_with_env(&Config::testnet());
+    /// let query = FlexibleBorrowQuery {
+    ///     loan_coin: "USDT".to_string(),
+    ///     loan_amount: Some(100.0),
+    ///     collateral_coin: "BNB".to_string(),
+    ///     ..Default::default()
+    /// };
+    /// let borrow = tokio_test::block_on(loans.borrow(query));
+    /// assert!(borrow.is_ok(), "{:?}", borrow);
+    /// ```
+    pub async fn borrow(&self, query: FlexibleBorrowQuery) -> Result<FlexibleBorrowResponse> {
+        self.client
+            .post_signed_p(SAPI_V2_LOAN_FLEXIBLE_BORROW, Some(query), self.recv_window)
+            .await
+    }
+
+    /// Repay a loan, partially or entirely
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, loans::*, config::*};
+    /// let loans: Loans = Binance::new_with_env(&Config::testnet());
+    /// let query = FlexibleRepayQuery {
+    ///     loan_coin: "USDT".to_string(),
+    ///     collateral_coin: "BNB".to_string(),
+    ///     repay_amount: 50.0,
+    ///     ..Default::default()
+    /// };
+    /// let repay = tokio_test::block_on(loans.repay(query));
+    /// assert!(repay.is_ok(), "{:?}", repay);
+    /// ```
+    pub async fn repay(&self, query: FlexibleRepayQuery) -> Result<FlexibleRepayResponse> {
+        self.client
+            .post_signed_p(SAPI_V2_LOAN_FLEXIBLE_REPAY, Some(query), self.recv_window)
+            .await
+    }
+
+    /// Add or remove collateral of a loan
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, loans::*, config::*, rest_model::AdjustmentDirection};
+    /// let loans: Loans = Binance::new_with_env(&Config::testnet());
+    /// let query = FlexibleAdjustLtvQuery {
+    ///     loan_coin: "USDT".to_string(),
+    ///     collateral_coin: "BNB".to_string(),
+    ///     adjustment_amount: 1.0,
+    ///     direction: AdjustmentDirection::Additional,
+    /// };
+    /// let adjustment = tokio_test::block_on(loans.adjust_ltv(query));
+    /// assert!(adjustment.is_ok(), "{:?}", adjustment);
+    /// ```
+    pub async fn adjust_ltv(&self, query: FlexibleAdjustLtvQuery) -> Result<FlexibleAdjustLtvResponse> {
+        self.client
+            .post_signed_p(SAPI_V2_LOAN_FLEXIBLE_ADJUST_LTV, Some(query), self.recv_window)
+            .await
+    }
+
+    /// Loans not repaid yet
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, loans::*, config::*};
+    /// let loans: Loans = Binance::new_with_env(&Config::testnet());
+    /// let orders = tokio_test::block_on(loans.ongoing_orders(FlexibleLoanOrderQuery::default()));
+    /// assert!(orders.is_ok(), "{:?}", orders);
+    /// ```
+    pub async fn ongoing_orders(&self, query: FlexibleLoanOrderQuery) -> Result<RecordsQueryResult<FlexibleLoanOrder>> {
+        self.client
+            .get_signed_p(SAPI_V2_LOAN_FLEXIBLE_ONGOING_ORDERS, Some(query), self.recv_window)
+            .await
+    }
+
+    /// Assets which can be borrowed, all of them if `loan_coin` is None
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, loans::*, config::*};
+    /// let loans: Loans = Binance::new_with_env(&Config::testnet());
+    /// let assets = tokio_test::block_on(loans.loanable_assets(Some("USDT".to_string())));
+    /// assert!(assets.is_ok(), "{:?}", assets);
+    /// ```
+    pub async fn loanable_assets(&self, loan_coin: Option<String>) -> Result<RecordsQueryResult<LoanableAsset>> {
+        let mut params = HashMap::new();
+        if let Some(loan_coin) = loan_coin {
+            params.insert("loanCoin", loan_coin);
+        }
+        self.client
+            .get_signed_p(SAPI_V2_LOAN_FLEXIBLE_LOANABLE_DATA, Some(params), self.recv_window)
+            .await
+    }
+
+    /// Assets which can be used as collateral and their LTV thresholds, all of them if `collateral_coin` is None
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, loans::*, config::*};
+    /// let loans: Loans = Binance::new_with_env(&Config::testnet());
+    /// let assets = tokio_test::block_on(loans.collateral_assets(Some("BNB".to_string())));
+    /// assert!(assets.is_ok(), "{:?}", assets);
+    /// ```
+    pub async fn collateral_assets(
+        &self,
+        collateral_coin: Option<String>,
+    ) -> Result<RecordsQueryResult<CollateralAsset>> {
+        let mut params = HashMap::new();
+        if let Some(collateral_coin) = collateral_coin {
+            params.insert("collateralCoin", collateral_coin);
+        }
+        self.client
+            .get_signed_p(SAPI_V2_LOAN_FLEXIBLE_COLLATERAL_DATA, Some(params), self.recv_window)
+            .await
+    }
+
+    /// Borrow history
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, loans::*, config::*};
+    /// let loans: Loans = Binance::new_with_env(&Config::testnet());
+    /// let records = tokio_test::block_on(loans.borrow_history(FlexibleLoanHistoryQuery::default()));
+    /// assert!(records.is_ok(), "{:?}", records);
+    /// ```
+    pub async fn borrow_history(
+        &self,
+        query: FlexibleLoanHistoryQuery,
+    ) -> Result<RecordsQueryResult<FlexibleBorrowRecord>> {
+        self.client
+            .get_signed_p(SAPI_V2_LOAN_FLEXIBLE_BORROW_HISTORY, Some(query), self.recv_window)
+            .await
+    }
+
+    /// Repayment history
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, loans::*, config::*};
+    /// let loans: Loans = Binance::new_with_env(&Config::testnet());
+    /// let records = tokio_test::block_on(loans.repay_history(FlexibleLoanHistoryQuery::default()));
+    /// assert!(records.is_ok(), "{:?}", records);
+    /// ```
+    pub async fn repay_history(
+        &self,
+        query: FlexibleLoanHistoryQuery,
+    ) -> Result<RecordsQueryResult<FlexibleRepayRecord>> {
+        self.client
+            .get_signed_p(SAPI_V2_LOAN_FLEXIBLE_REPAY_HISTORY, Some(query), self.recv_window)
+            .await
+    }
+
+    /// Collateral adjustment history
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, loans::*, config::*};
+    /// let loans: Loans = Binance::new_with_env(&Config::testnet());
+    /// let records = tokio_test::block_on(loans.ltv_adjustment_history(FlexibleLoanHistoryQuery::default()));
+    /// assert!(records.is_ok(), "{:?}", records);
+    /// ```
+    pub async fn ltv_adjustment_history(
+        &self,
+        query: FlexibleLoanHistoryQuery,
+    ) -> Result<RecordsQueryResult<LtvAdjustmentRecord>> {
+        self.client
+            .get_signed_p(
+                SAPI_V2_LOAN_FLEXIBLE_LTV_ADJUSTMENT_HISTORY,
+                Some(query),
+                self.recv_window,
+            )
+            .await
+    }
+
+    /// Liquidation history
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, loans::*, config::*};
+    /// let loans: Loans = Binance::new_with_env(&Config::testnet());
+    /// let records = tokio_test::block_on(loans.liquidation_history(FlexibleLoanHistoryQuery::default()));
+    /// assert!(records.is_ok(), "{:?}", records);
+    /// ```
+    pub async fn liquidation_history(
+        &self,
+        query: FlexibleLoanHistoryQuery,
+    ) -> Result<RecordsQueryResult<LiquidationRecord>> {
+        self.client
+            .get_signed_p(SAPI_V2_LOAN_FLEXIBLE_LIQUIDATION_HISTORY, Some(query), self.recv_window)
+            .await
+    }
+}
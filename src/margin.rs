@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use crate::client::*;
 use crate::errors::*;
 use crate::rest_model::*;
@@ -21,6 +23,8 @@ static SAPI_V1_MARGIN_ALL_ASSETS: &str = "/sapi/v1/margin/allAssets";
 static SAPI_V1_MARGIN_ALL_PAIRS: &str = "/sapi/v1/margin/allPairs";
 static SAPI_V1_MARGIN_ALL_ISOLATED_PAIRS: &str = "/sapi/v1/margin/isolated/allPairs";
 static SAPI_V1_MARGIN_ISOLATED_ACCOUNT_LIMIT: &str = "/sapi/v1/margin/isolated/accountLimit";
+static SAPI_V1_MARGIN_LOAN_GROUP_LTV: &str = "/sapi/v1/margin/loan-group/ltv";
+static SAPI_V1_MARGIN_LOAN_GROUP_DETAIL: &str = "/sapi/v1/margin/loan-group/detail";
 static SAPI_V1_MARGIN_PRICE_INDEX: &str = "/sapi/v1/margin/priceIndex";
 static SAPI_V1_MARGIN_INTEREST_HISTORY: &str = "/sapi/v1/margin/interestHistory";
 static SAPI_V1_MARGIN_FORCED_LIQUIDATION_RECORD: &str = "/sapi/v1/margin/forceLiquidationRec";
@@ -988,4 +992,43 @@ impl Margin {
             )
             .await
     }
+
+    /// Loan-to-value of the institutional loan groups, or of a single group if `group_id` is given
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, margin::*, config::*};
+    /// let margin: Margin = Binance::new_with_env(&Config::default());
+    /// let ltv = tokio_test::block_on(margin.loan_group_ltv(None));
+    /// assert!(ltv.is_ok(), "{:?}", ltv);
+    /// ```
+    pub async fn loan_group_ltv(&self, group_id: Option<String>) -> Result<Vec<LoanGroupLtv>> {
+        let mut params = HashMap::new();
+        if let Some(group_id) = group_id {
+            params.insert("groupId", group_id);
+        }
+        self.client
+            .get_signed_p(SAPI_V1_MARGIN_LOAN_GROUP_LTV, Some(params), self.recv_window)
+            .await
+    }
+
+    /// Borrowed and collateral assets of an institutional loan group
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, margin::*, config::*};
+    /// let margin: Margin = Binance::new_with_env(&Config::default());
+    /// let details = tokio_test::block_on(margin.loan_group_details("12345"));
+    /// assert!(details.is_ok(), "{:?}", details);
+    /// ```
+    pub async fn loan_group_details<S>(&self, group_id: S) -> Result<LoanGroupDetails>
+    where
+        S: Into<String>,
+    {
+        let mut params = HashMap::new();
+        params.insert("groupId", group_id.into());
+        self.client
+            .get_signed_p(SAPI_V1_MARGIN_LOAN_GROUP_DETAIL, Some(params), self.recv_window)
+            .await
+    }
 }
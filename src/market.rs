@@ -2,7 +2,7 @@ use crate::client::*;
 use crate::errors::*;
 use crate::rest_model::*;
 use crate::util::*;
-use serde_json::Value;
+use futures::{Stream, TryStreamExt};
 // use std::collections::BTreeMap;
 
 static API_V3_DEPTH: &str = "/api/v3/depth";
@@ -10,9 +10,25 @@ static API_V3_TICKER_PRICE: &str = "/api/v3/ticker/price";
 static API_V3_AVG_PRICE: &str = "/api/v3/avgPrice";
 static API_V3_BOOK_TICKER: &str = "/api/v3/ticker/bookTicker";
 static API_V3_24H_TICKER: &str = "/api/v3/ticker/24hr";
+static API_V3_HISTORICAL_TRADES: &str = "/api/v3/historicalTrades";
 static API_V3_AGG_TRADES: &str = "/api/v3/aggTrades";
 static API_V3_KLINES: &str = "/api/v3/klines";
 
+/// Maximum number of klines returned by a single request
+static KLINES_MAX_LIMIT: u16 = 1000;
+/// Maximum number of aggregated trades returned by a single request
+static AGG_TRADES_MAX_LIMIT: u16 = 1000;
+/// Maximum time range between startTime and endTime for aggregated trades, in milliseconds
+static AGG_TRADES_MAX_WINDOW_MS: u64 = 60 * 60 * 1000;
+
+/// Position of an aggregated trades range query
+enum AggTradesCursor {
+    /// Looking for the first trade in the window starting at this time
+    Time(u64),
+    /// Paging from this aggregated trade id
+    FromId(u64),
+}
+
 #[derive(Clone)]
 pub struct Market {
     pub client: Client,
@@ -28,6 +44,14 @@ impl Market {
         build_request([("symbol", symbol)])
     }
 
+    fn symbols_request<S>(&self, symbols: &[S]) -> Result<String>
+    where
+        S: AsRef<str>,
+    {
+        let symbols: Vec<&str> = symbols.iter().map(AsRef::as_ref).collect();
+        Ok(build_request(json_array_param("symbols", &symbols)?))
+    }
+
     /// Order book (Default 100; max 5000)
     /// # Examples
     /// ```rust
@@ -94,6 +118,26 @@ impl Market {
         self.client.get(API_V3_TICKER_PRICE, Some(&request)).await
     }
 
+    /// Latest price for several symbols in a single request, none for an empty `symbols`.
+    /// # Examples
+    /// ```rust
+    /// use binance::{api::*, market::*, config::*};
+    /// let conf = Config::default().set_rest_api_endpoint(DATA_REST_ENDPOINT);
+    /// let market: Market = Binance::new_with_env(&conf);
+    /// let prices = tokio_test::block_on(market.get_prices(&["BTCUSDT", "ETHUSDT"]));
+    /// assert!(prices.is_ok(), "{:?}", prices);
+    /// ```
+    pub async fn get_prices<S>(&self, symbols: &[S]) -> Result<Vec<SymbolPrice>>
+    where
+        S: AsRef<str>,
+    {
+        if symbols.is_empty() {
+            return Ok(vec![]);
+        }
+        let request = self.symbols_request(symbols)?;
+        self.client.get(API_V3_TICKER_PRICE, Some(&request)).await
+    }
+
     /// Average price for ONE symbol.
     /// # Examples
     /// ```rust
@@ -140,6 +184,26 @@ impl Market {
         self.client.get(API_V3_BOOK_TICKER, Some(&request)).await
     }
 
+    /// -> Best price/qty on the order book for several symbols in a single request, none for an empty `symbols`
+    /// # Examples
+    /// ```rust
+    /// use binance::{api::*, market::*, config::*};
+    /// let conf = Config::default().set_rest_api_endpoint(DATA_REST_ENDPOINT);
+    /// let market: Market = Binance::new_with_env(&conf);
+    /// let tickers = tokio_test::block_on(market.get_book_tickers(&["BTCUSDT", "ETHUSDT"]));
+    /// assert!(tickers.is_ok(), "{:?}", tickers);
+    /// ```
+    pub async fn get_book_tickers<S>(&self, symbols: &[S]) -> Result<Vec<Tickers>>
+    where
+        S: AsRef<str>,
+    {
+        if symbols.is_empty() {
+            return Ok(vec![]);
+        }
+        let request = self.symbols_request(symbols)?;
+        self.client.get(API_V3_BOOK_TICKER, Some(&request)).await
+    }
+
     /// 24hr ticker price change statistics
     /// # Examples
     /// ```rust
@@ -157,6 +221,40 @@ impl Market {
         self.client.get(API_V3_24H_TICKER, Some(&request)).await
     }
 
+    /// Get older market trades.
+    /// Trades are returned starting at from_id, if omitted the most recent trades are fetched.
+    /// Default limit 500; max 1000
+    /// # Examples
+    /// ```rust
+    /// use binance::{api::*, market::*, config::*};
+    /// let conf = Config::default().set_rest_api_endpoint(DATA_REST_ENDPOINT);
+    /// let market: Market = Binance::new_with_env(&conf);
+    /// let trades = tokio_test::block_on(market.get_historical_trades("BNBETH", None, Some(10)));
+    /// assert!(trades.is_ok(), "{:?}", trades);
+    /// ```
+    pub async fn get_historical_trades<S1, S2, S3>(
+        &self,
+        symbol: S1,
+        from_id: S2,
+        limit: S3,
+    ) -> Result<Vec<MarketTrade>>
+    where
+        S1: AsRef<str>,
+        S2: Into<Option<u64>>,
+        S3: Into<Option<u16>>,
+    {
+        let parameters = IntoIterator::into_iter([
+            Some(("symbol", symbol.as_ref().to_string())),
+            limit.into().map(|l| ("limit", l.to_string())),
+            from_id.into().map(|f| ("fromId", f.to_string())),
+        ])
+        .flatten();
+
+        let request = build_request(parameters);
+
+        self.client.get(API_V3_HISTORICAL_TRADES, Some(&request)).await
+    }
+
     /// Get aggregated historical trades.
     /// If you provide start_time, you also need to provide end_time.
     /// If from_id, start_time and end_time are omitted, the most recent trades are fetched.
@@ -197,6 +295,81 @@ impl Market {
         self.client.get_p(API_V3_AGG_TRADES, Some(&request)).await
     }
 
+    /// Stream all aggregated trades between start_time and end_time (inclusive, in milliseconds).
+    /// The first trade is located by scanning one hour windows from start_time, after which
+    /// requests page by `fromId` until a trade past end_time is found.
+    /// # Examples
+    /// ```rust
+    /// use binance::{api::*, market::*, config::*};
+    /// use futures::TryStreamExt;
+    /// let conf = Config::default().set_rest_api_endpoint(DATA_REST_ENDPOINT);
+    /// let market: Market = Binance::new_with_env(&conf);
+    /// let end = chrono::Utc::now().timestamp_millis() as u64;
+    /// let agg_trades = tokio_test::block_on(market.agg_trades_range("BNBETH", end - 60_000, end).try_collect::<Vec<_>>());
+    /// assert!(agg_trades.is_ok(), "{:?}", agg_trades);
+    /// ```
+    pub fn agg_trades_range<S>(
+        &self,
+        symbol: S,
+        start_time: u64,
+        end_time: u64,
+    ) -> impl Stream<Item = Result<AggTrade>> + '_
+    where
+        S: Into<String>,
+    {
+        let cursor = Some(AggTradesCursor::Time(start_time));
+        futures::stream::try_unfold((symbol.into(), cursor), move |(symbol, cursor)| async move {
+            match cursor {
+                None => Ok(None),
+                Some(cursor) => self
+                    .agg_trades_page(&symbol, cursor, end_time)
+                    .await
+                    .map(|(trades, next)| Some((trades, (symbol, next)))),
+            }
+        })
+        .map_ok(|trades| futures::stream::iter(trades.into_iter().map(Ok)))
+        .try_flatten()
+    }
+
+    /// Fetch the page of aggregated trades at cursor, along with the cursor of the next page
+    async fn agg_trades_page(
+        &self,
+        symbol: &str,
+        cursor: AggTradesCursor,
+        end_time: u64,
+    ) -> Result<(Vec<AggTrade>, Option<AggTradesCursor>)> {
+        match cursor {
+            AggTradesCursor::Time(window_start) => {
+                if window_start > end_time {
+                    return Ok((vec![], None));
+                }
+                let window_end = (window_start + AGG_TRADES_MAX_WINDOW_MS - 1).min(end_time);
+                let trades = self
+                    .get_agg_trades(symbol, None, window_start, window_end, AGG_TRADES_MAX_LIMIT)
+                    .await?;
+                let next = match trades.last() {
+                    Some(last) => AggTradesCursor::FromId(last.agg_id + 1),
+                    None => AggTradesCursor::Time(window_end + 1),
+                };
+                Ok((trades, Some(next)))
+            }
+            AggTradesCursor::FromId(from_id) => {
+                let mut trades = self
+                    .get_agg_trades(symbol, from_id, None, None, AGG_TRADES_MAX_LIMIT)
+                    .await?;
+                let fetched = trades.len();
+                trades.retain(|t| t.time <= end_time);
+                // Stop once the most recent trade or the end of the range has been reached
+                let next = if fetched < AGG_TRADES_MAX_LIMIT as usize || trades.len() < fetched {
+                    None
+                } else {
+                    trades.last().map(|last| AggTradesCursor::FromId(last.agg_id + 1))
+                };
+                Ok((trades, next))
+            }
+        }
+    }
+
     /// Returns up to 'limit' klines for given symbol and interval ("1m", "5m", ...)
     /// <https://github.com/binance-exchange/binance-official-api-docs/blob/master/rest-api.md#klinecandlestick-data>
     /// # Examples
@@ -233,26 +406,61 @@ impl Market {
 
         let request = build_request(parameters);
 
-        let parsed_data: Vec<Vec<Value>> = self.client.get(API_V3_KLINES, Some(&request)).await?;
+        let rows: Vec<KlineSummaryRow> = self.client.get(API_V3_KLINES, Some(&request)).await?;
+
+        Ok(KlineSummaries::from_rows(rows))
+    }
+
+    /// Returns all klines for given symbol and interval ("1m", "5m", ...) between start_time and end_time,
+    /// issuing as many requests as needed to get past the 1000 klines limit.
+    /// Klines are sorted by open time and klines present at the boundary of two requests are only kept once.
+    /// # Examples
+    /// ```rust
+    /// use binance::{api::*, market::*, config::*};
+    /// let conf = Config::default().set_rest_api_endpoint(DATA_REST_ENDPOINT);
+    /// let market: Market = Binance::new_with_env(&conf);
+    /// let end = chrono::Utc::now().timestamp_millis() as u64;
+    /// let klines = tokio_test::block_on(market.get_klines_range("BTCUSDT", "1m", end - 2 * 24 * 3600 * 1000, end));
+    /// assert!(klines.is_ok(), "{:?}", klines);
+    /// ```
+    pub async fn get_klines_range<S1, S2>(
+        &self,
+        symbol: S1,
+        interval: S2,
+        start_time: u64,
+        end_time: u64,
+    ) -> Result<KlineSummaries>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+    {
+        let symbol = symbol.into();
+        let interval = interval.into();
+        let mut klines: Vec<KlineSummary> = vec![];
+        let mut current_start = start_time;
+
+        while current_start <= end_time {
+            let KlineSummaries::AllKlineSummaries(page) = self
+                .get_klines(
+                    symbol.as_str(),
+                    interval.as_str(),
+                    KLINES_MAX_LIMIT,
+                    current_start,
+                    end_time,
+                )
+                .await?;
+            let fetched = page.len();
+            let last_open_time = klines.last().map(|k| k.open_time);
+            klines.extend(page.into_iter().filter(|k| match last_open_time {
+                Some(last) => k.open_time > last,
+                None => true,
+            }));
+            match klines.last() {
+                Some(last) if fetched == KLINES_MAX_LIMIT as usize => current_start = last.close_time as u64 + 1,
+                _ => break,
+            }
+        }
 
-        let klines = KlineSummaries::AllKlineSummaries(
-            parsed_data
-                .iter()
-                .map(|row| KlineSummary {
-                    open_time: to_i64(&row[0]),
-                    open: to_f64(&row[1]),
-                    high: to_f64(&row[2]),
-                    low: to_f64(&row[3]),
-                    close: to_f64(&row[4]),
-                    volume: to_f64(&row[5]),
-                    close_time: to_i64(&row[6]),
-                    quote_asset_volume: to_f64(&row[7]),
-                    number_of_trades: to_i64(&row[8]),
-                    taker_buy_base_asset_volume: to_f64(&row[9]),
-                    taker_buy_quote_asset_volume: to_f64(&row[10]),
-                })
-                .collect(),
-        );
-        Ok(klines)
+        Ok(KlineSummaries::AllKlineSummaries(klines))
     }
 }
@@ -0,0 +1,512 @@
+//! Mining pool, workers, earnings, hashrate resale and account statistics.
+
+use std::collections::HashMap;
+
+use serde::de::DeserializeOwned;
+
+use crate::client::*;
+use crate::errors::*;
+use crate::rest_model::string_or_float;
+
+static SAPI_V1_MINING_WORKER_DETAIL: &str = "/sapi/v1/mining/worker/detail";
+static SAPI_V1_MINING_WORKER_LIST: &str = "/sapi/v1/mining/worker/list";
+static SAPI_V1_MINING_PAYMENT_LIST: &str = "/sapi/v1/mining/payment/list";
+static SAPI_V1_MINING_PAYMENT_OTHER: &str = "/sapi/v1/mining/payment/other";
+static SAPI_V1_MINING_HASH_TRANSFER_CONFIG_LIST: &str = "/sapi/v1/mining/hash-transfer/config/details/list";
+static SAPI_V1_MINING_HASH_TRANSFER_PROFIT_DETAILS: &str = "/sapi/v1/mining/hash-transfer/profit/details";
+static SAPI_V1_MINING_HASH_TRANSFER_CONFIG: &str = "/sapi/v1/mining/hash-transfer/config";
+static SAPI_V1_MINING_HASH_TRANSFER_CONFIG_CANCEL: &str = "/sapi/v1/mining/hash-transfer/config/cancel";
+static SAPI_V1_MINING_STATISTICS_USER_STATUS: &str = "/sapi/v1/mining/statistics/user/status";
+static SAPI_V1_MINING_STATISTICS_USER_LIST: &str = "/sapi/v1/mining/statistics/user/list";
+
+/// Mining endpoints wrap their data in an envelope carrying an error code, 0 on success
+#[derive(Deserialize)]
+struct MiningResponse<T> {
+    code: i32,
+    msg: String,
+    data: Option<T>,
+}
+
+impl<T> MiningResponse<T> {
+    fn into_result(self) -> Result<T> {
+        match self.data {
+            Some(data) if self.code == 0 => Ok(data),
+            _ => Err(BinanceContentError::new(self.code, self.msg).into()),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub enum WorkerStatus {
+    #[serde(rename = "0")]
+    All,
+    #[serde(rename = "1")]
+    Valid,
+    #[serde(rename = "2")]
+    Invalid,
+    #[serde(rename = "3")]
+    Failure,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkerListQuery {
+    /// Algorithm, e.g. sha256
+    pub algo: String,
+    /// Mining account
+    pub user_name: String,
+    /// Page number, starting at 1
+    pub page_index: Option<u64>,
+    /// 0 for positive sequence, 1 for negative sequence
+    pub sort: Option<u8>,
+    /// 1: worker name, 2: real-time hashrate, 3: daily average hashrate, 4: real-time rejection rate, 5: last submission time
+    pub sort_column: Option<u8>,
+    pub worker_status: Option<WorkerStatus>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkerList {
+    pub worker_datas: Vec<Worker>,
+    pub total_num: u64,
+    pub page_size: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Worker {
+    pub worker_id: String,
+    pub worker_name: String,
+    /// 1: valid, 2: invalid, 3: no longer valid
+    pub status: u8,
+    #[serde(with = "string_or_float")]
+    pub hash_rate: f64,
+    #[serde(with = "string_or_float")]
+    pub day_hash_rate: f64,
+    #[serde(with = "string_or_float")]
+    pub reject_rate: f64,
+    pub last_share_time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkerDetail {
+    pub worker_name: String,
+    /// H_hashrate for hourly or D_hashrate for daily
+    #[serde(rename = "type")]
+    pub hashrate_type: String,
+    pub hashrate_datas: Vec<HashrateData>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct HashrateData {
+    pub time: u64,
+    #[serde(with = "string_or_float")]
+    pub hashrate: f64,
+    #[serde(with = "string_or_float")]
+    pub reject: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct EarningsQuery {
+    /// Algorithm, e.g. sha256
+    pub algo: String,
+    /// Mining account
+    pub user_name: String,
+    pub coin: Option<String>,
+    /// Millisecond timestamp
+    pub start_date: Option<u64>,
+    /// Millisecond timestamp
+    pub end_date: Option<u64>,
+    /// Page number, starting at 1
+    pub page_index: Option<u64>,
+    /// Min: 10, Max: 200
+    pub page_size: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct EarningsList {
+    #[serde(default)]
+    pub account_profits: Vec<AccountProfit>,
+    pub total_num: u64,
+    pub page_size: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountProfit {
+    pub time: u64,
+    /// 0: referral, 1: refund, 2: distribution, 3: savings, 5: mining, 31: income transfer...
+    #[serde(rename = "type")]
+    pub profit_type: i32,
+    /// Transferred hashrate, only for hashrate resale
+    pub hash_transfer: Option<f64>,
+    /// Transferred income, only for hashrate resale
+    pub transfer_amount: Option<f64>,
+    #[serde(with = "string_or_float")]
+    pub day_hash_rate: f64,
+    #[serde(with = "string_or_float")]
+    pub profit_amount: f64,
+    pub coin_name: String,
+    /// 0: unpaid, 1: paying, 2: paid
+    pub status: u8,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ExtraBonusList {
+    #[serde(default)]
+    pub other_profits: Vec<OtherProfit>,
+    pub total_num: u64,
+    pub page_size: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OtherProfit {
+    pub time: u64,
+    pub coin_name: String,
+    /// 1: merged mining, 2: activity bonus, 3: rebate, 4: smart pool, 6: income transfer, 7: pool savings
+    #[serde(rename = "type")]
+    pub profit_type: i32,
+    #[serde(with = "string_or_float")]
+    pub profit_amount: f64,
+    /// 0: unpaid, 1: paying, 2: paid
+    pub status: u8,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct HashrateResaleList {
+    #[serde(default)]
+    pub config_details: Vec<HashrateResale>,
+    pub total_num: u64,
+    pub page_size: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct HashrateResale {
+    pub config_id: u64,
+    pub pool_username: String,
+    pub to_pool_username: String,
+    pub algo_name: String,
+    #[serde(with = "string_or_float")]
+    pub hash_rate: f64,
+    /// Formatted as yyyyMMdd
+    pub start_day: u64,
+    /// Formatted as yyyyMMdd
+    pub end_day: u64,
+    /// 0: processing, 1: cancelled, 2: terminated
+    pub status: u8,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct HashrateResaleDetailList {
+    #[serde(default)]
+    pub profit_transfer_details: Vec<HashrateResaleDetail>,
+    pub total_num: u64,
+    pub page_size: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct HashrateResaleDetail {
+    pub pool_username: String,
+    pub to_pool_username: String,
+    pub algo_name: String,
+    #[serde(with = "string_or_float")]
+    pub hash_rate: f64,
+    /// Formatted as yyyyMMdd
+    pub day: u64,
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    pub coin_name: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct HashrateResaleQuery {
+    /// Mining account
+    pub user_name: String,
+    /// Algorithm, e.g. sha256
+    pub algo: String,
+    /// Millisecond timestamp
+    pub start_date: u64,
+    /// Millisecond timestamp
+    pub end_date: u64,
+    /// Mining account receiving the hashrate
+    pub to_pool_user: String,
+    /// Resale hashrate in H/s
+    pub hash_rate: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MiningStatistics {
+    #[serde(with = "string_or_float")]
+    pub fifteen_min_hash_rate: f64,
+    #[serde(with = "string_or_float")]
+    pub day_hash_rate: f64,
+    pub valid_num: u64,
+    pub invalid_num: u64,
+    /// Earnings of the day per coin
+    pub profit_today: HashMap<String, String>,
+    /// Earnings of the previous day per coin
+    pub profit_yesterday: HashMap<String, String>,
+    pub user_name: String,
+    /// Hashrate unit, e.g. h/s
+    pub unit: String,
+    pub algo: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountHashrate {
+    /// H_hashrate for hourly or D_hashrate for daily
+    #[serde(rename = "type")]
+    pub hashrate_type: String,
+    pub user_name: String,
+    pub list: Vec<HashrateData>,
+}
+
+/// This struct acts as a gateway for all mining endpoints.
+/// Preferably use the trait [`crate::api::Binance`] to get an instance.
+#[derive(Clone)]
+pub struct Mining {
+    pub client: Client,
+    pub recv_window: u64,
+}
+
+impl Mining {
+    async fn get_data<T: DeserializeOwned, P: serde::Serialize>(&self, endpoint: &str, payload: P) -> Result<T> {
+        let response: MiningResponse<T> = self
+            .client
+            .get_signed_p(endpoint, Some(payload), self.recv_window)
+            .await?;
+        response.into_result()
+    }
+
+    async fn post_data<T: DeserializeOwned, P: serde::Serialize>(&self, endpoint: &str, payload: P) -> Result<T> {
+        let response: MiningResponse<T> = self
+            .client
+            .post_signed_p(endpoint, Some(payload), self.recv_window)
+            .await?;
+        response.into_result()
+    }
+
+    /// Workers of a mining account
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, mining::*, config::*};
+    /// let mining: Mining = Binance::new_with_env(&Config::testnet());
+    /// let query = WorkerListQuery {
+    ///     algo: "sha256".to_string(),
+    ///     user_name: "miner".to_string(),
+    ///     ..WorkerListQuery::default()
+    /// };
+    /// let workers = tokio_test::block_on(mining.worker_list(query));
+    /// assert!(workers.is_ok(), "{:?}", workers);
+    /// ```
+    pub async fn worker_list(&self, query: WorkerListQuery) -> Result<WorkerList> {
+        self.get_data(SAPI_V1_MINING_WORKER_LIST, query).await
+    }
+
+    /// Hashrate history of a worker
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, mining::*, config::*};
+    /// let mining: Mining = Binance::new_with_env(&Config::testnet());
+    /// let details = tokio_test::block_on(mining.worker_detail("sha256", "miner", "rig1"));
+    /// assert!(details.is_ok(), "{:?}", details);
+    /// ```
+    pub async fn worker_detail<S1, S2, S3>(&self, algo: S1, user_name: S2, worker_name: S3) -> Result<Vec<WorkerDetail>>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<String>,
+    {
+        let mut params = HashMap::new();
+        params.insert("algo", algo.into());
+        params.insert("userName", user_name.into());
+        params.insert("workerName", worker_name.into());
+        self.get_data(SAPI_V1_MINING_WORKER_DETAIL, params).await
+    }
+
+    /// Mining earnings of an account
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, mining::*, config::*};
+    /// let mining: Mining = Binance::new_with_env(&Config::testnet());
+    /// let query = EarningsQuery {
+    ///     algo: "sha256".to_string(),
+    ///     user_name: "miner".to_string(),
+    ///     ..EarningsQuery::default()
+    /// };
+    /// let earnings = tokio_test::block_on(mining.earnings(query));
+    /// assert!(earnings.is_ok(), "{:?}", earnings);
+    /// ```
+    pub async fn earnings(&self, query: EarningsQuery) -> Result<EarningsList> {
+        self.get_data(SAPI_V1_MINING_PAYMENT_LIST, query).await
+    }
+
+    /// Extra bonuses of an account, e.g. merged mining or activity bonuses
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, mining::*, config::*};
+    /// let mining: Mining = Binance::new_with_env(&Config::testnet());
+    /// let query = EarningsQuery {
+    ///     algo: "sha256".to_string(),
+    ///     user_name: "miner".to_string(),
+    ///     ..EarningsQuery::default()
+    /// };
+    /// let bonuses = tokio_test::block_on(mining.extra_bonus(query));
+    /// assert!(bonuses.is_ok(), "{:?}", bonuses);
+    /// ```
+    pub async fn extra_bonus(&self, query: EarningsQuery) -> Result<ExtraBonusList> {
+        self.get_data(SAPI_V1_MINING_PAYMENT_OTHER, query).await
+    }
+
+    /// Hashrate resale configurations
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, mining::*, config::*};
+    /// let mining: Mining = Binance::new_with_env(&Config::testnet());
+    /// let resales = tokio_test::block_on(mining.hashrate_resale_list(None, None));
+    /// assert!(resales.is_ok(), "{:?}", resales);
+    /// ```
+    pub async fn hashrate_resale_list(
+        &self,
+        page_index: Option<u64>,
+        page_size: Option<u64>,
+    ) -> Result<HashrateResaleList> {
+        let mut params = HashMap::new();
+        if let Some(page_index) = page_index {
+            params.insert("pageIndex", page_index);
+        }
+        if let Some(page_size) = page_size {
+            params.insert("pageSize", page_size);
+        }
+        self.get_data(SAPI_V1_MINING_HASH_TRANSFER_CONFIG_LIST, params).await
+    }
+
+    /// Daily details of a hashrate resale configuration
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, mining::*, config::*};
+    /// let mining: Mining = Binance::new_with_env(&Config::testnet());
+    /// let details = tokio_test::block_on(mining.hashrate_resale_detail(168, "miner", None, None));
+    /// assert!(details.is_ok(), "{:?}", details);
+    /// ```
+    pub async fn hashrate_resale_detail<S>(
+        &self,
+        config_id: u64,
+        user_name: S,
+        page_index: Option<u64>,
+        page_size: Option<u64>,
+    ) -> Result<HashrateResaleDetailList>
+    where
+        S: Into<String>,
+    {
+        let mut params = HashMap::new();
+        params.insert("configId", config_id.to_string());
+        params.insert("userName", user_name.into());
+        if let Some(page_index) = page_index {
+            params.insert("pageIndex", page_index.to_string());
+        }
+        if let Some(page_size) = page_size {
+            params.insert("pageSize", page_size.to_string());
+        }
+        self.get_data(SAPI_V1_MINING_HASH_TRANSFER_PROFIT_DETAILS, params).await
+    }
+
+    /// Resell hashrate to another mining account, returns the id of the configuration
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, mining::*, config::*};
+    /// let mining: Mining = Binance::new_with_env(&Config::testnet());
+    /// let query = HashrateResaleQuery {
+    ///     user_name: "miner".to_string(),
+    ///     algo: "sha256".to_string(),
+    ///     start_date: 1607659086000,
+    ///     end_date: 1617659086000,
+    ///     to_pool_user: "buyer".to_string(),
+    ///     hash_rate: 100000000,
+    /// };
+    /// let config_id = tokio_test::block_on(mining.hashrate_resale_request(query));
+    /// assert!(config_id.is_ok(), "{:?}", config_id);
+    /// ```
+    pub async fn hashrate_resale_request(&self, query: HashrateResaleQuery) -> Result<u64> {
+        self.post_data(SAPI_V1_MINING_HASH_TRANSFER_CONFIG, query).await
+    }
+
+    /// Cancel a hashrate resale configuration
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, mining::*, config::*};
+    /// let mining: Mining = Binance::new_with_env(&Config::testnet());
+    /// let cancelled = tokio_test::block_on(mining.cancel_hashrate_resale(168, "miner"));
+    /// assert!(cancelled.is_ok(), "{:?}", cancelled);
+    /// ```
+    pub async fn cancel_hashrate_resale<S>(&self, config_id: u64, user_name: S) -> Result<bool>
+    where
+        S: Into<String>,
+    {
+        let mut params = HashMap::new();
+        params.insert("configId", config_id.to_string());
+        params.insert("userName", user_name.into());
+        self.post_data(SAPI_V1_MINING_HASH_TRANSFER_CONFIG_CANCEL, params).await
+    }
+
+    /// Hashrate and earnings statistics of a mining account
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, mining::*, config::*};
+    /// let mining: Mining = Binance::new_with_env(&Config::testnet());
+    /// let statistics = tokio_test::block_on(mining.statistics("sha256", "miner"));
+    /// assert!(statistics.is_ok(), "{:?}", statistics);
+    /// ```
+    pub async fn statistics<S1, S2>(&self, algo: S1, user_name: S2) -> Result<MiningStatistics>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+    {
+        let mut params = HashMap::new();
+        params.insert("algo", algo.into());
+        params.insert("userName", user_name.into());
+        self.get_data(SAPI_V1_MINING_STATISTICS_USER_STATUS, params).await
+    }
+
+    /// Hashrate history of a mining account
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, mining::*, config::*};
+    /// let mining: Mining = Binance::new_with_env(&Config::testnet());
+    /// let hashrates = tokio_test::block_on(mining.account_hashrate("sha256", "miner"));
+    /// assert!(hashrates.is_ok(), "{:?}", hashrates);
+    /// ```
+    pub async fn account_hashrate<S1, S2>(&self, algo: S1, user_name: S2) -> Result<Vec<AccountHashrate>>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+    {
+        let mut params = HashMap::new();
+        params.insert("algo", algo.into());
+        params.insert("userName", user_name.into());
+        self.get_data(SAPI_V1_MINING_STATISTICS_USER_LIST, params).await
+    }
+}
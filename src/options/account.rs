@@ -0,0 +1,266 @@
+use std::collections::HashMap;
+
+use serde_json::Value;
+
+use crate::client::*;
+use crate::errors::*;
+use crate::options::rest_model::*;
+use crate::rest_model::{string_or_float, string_or_float_opt};
+
+#[derive(Clone)]
+pub struct OptionsAccount {
+    pub client: Client,
+    pub recv_window: u64,
+}
+
+#[derive(Debug, Serialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderRequest {
+    pub symbol: String,
+    pub side: OrderSide,
+    #[serde(rename = "type")]
+    pub order_type: OrderType,
+    #[serde(with = "string_or_float")]
+    pub quantity: f64,
+    #[serde(skip_serializing_if = "Option::is_none", with = "string_or_float_opt")]
+    pub price: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_in_force: Option<TimeInForce>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reduce_only: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub post_only: Option<bool>,
+    /// ACK or RESULT, defaults to ACK
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_order_resp_type: Option<OrderResponse>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_order_id: Option<String>,
+    /// Whether the order is a market maker protection order
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_mmp: Option<bool>,
+}
+
+/// Either `order_id` or `client_order_id` must be set
+#[derive(Debug, Serialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderCancellation {
+    pub symbol: String,
+    pub order_id: Option<u64>,
+    pub client_order_id: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenOrdersQuery {
+    pub symbol: Option<String>,
+    /// Only return this order
+    pub order_id: Option<u64>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ExerciseRecordQuery {
+    pub symbol: Option<String>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    /// Default: 1000, Max: 1000
+    pub limit: Option<u16>,
+}
+
+/// Batch endpoints answer with an order or an error for each request of the batch
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum BatchOrderResponse {
+    Order(Order),
+    Error(BinanceContentError),
+}
+
+impl From<BatchOrderResponse> for Result<Order> {
+    fn from(response: BatchOrderResponse) -> Self {
+        match response {
+            BatchOrderResponse::Order(order) => Ok(order),
+            BatchOrderResponse::Error(response) => Err(Error::BinanceError { response }),
+        }
+    }
+}
+
+impl OptionsAccount {
+    /// Account assets and greeks
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, options::account::*, config::*};
+    /// let account: OptionsAccount = Binance::new_with_env(&Config::default());
+    /// let info = tokio_test::block_on(account.account_information());
+    /// assert!(info.is_ok(), "{:?}", info);
+    /// ```
+    pub async fn account_information(&self) -> Result<AccountInformation> {
+        self.client
+            .get_signed_p("/eapi/v1/account", Option::<String>::None, self.recv_window)
+            .await
+    }
+
+    /// Open positions, of all symbols if `symbol` is None
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, options::account::*, config::*};
+    /// let account: OptionsAccount = Binance::new_with_env(&Config::default());
+    /// let positions = tokio_test::block_on(account.positions(None));
+    /// assert!(positions.is_ok(), "{:?}", positions);
+    /// ```
+    pub async fn positions(&self, symbol: Option<String>) -> Result<Vec<Position>> {
+        let mut params = HashMap::new();
+        if let Some(symbol) = symbol {
+            params.insert("symbol", symbol);
+        }
+        self.client
+            .get_signed_p("/eapi/v1/position", Some(params), self.recv_window)
+            .await
+    }
+
+    /// Place a limit order
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, options::account::*, options::rest_model::*, config::*};
+    /// let account: OptionsAccount = Binance::new_with_env(&Config::default());
+    /// let order = OrderRequest {
+    ///     symbol: "BTC-240628-60000-C".to_string(),
+    ///     side: OrderSide::Buy,
+    ///     quantity: 0.01,
+    ///     price: Some(500.0),
+    ///     ..OrderRequest::default()
+    /// };
+    /// let placed = tokio_test::block_on(account.place_order(order));
+    /// assert!(placed.is_ok(), "{:?}", placed);
+    /// ```
+    pub async fn place_order(&self, order: OrderRequest) -> Result<Order> {
+        self.client
+            .post_signed_p("/eapi/v1/order", order, self.recv_window)
+            .await
+    }
+
+    /// Place up to 10 orders at once, each order succeeds or fails independently
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, options::account::*, options::rest_model::*, config::*};
+    /// let account: OptionsAccount = Binance::new_with_env(&Config::default());
+    /// let order = OrderRequest {
+    ///     symbol: "BTC-240628-60000-C".to_string(),
+    ///     side: OrderSide::Buy,
+    ///     quantity: 0.01,
+    ///     price: Some(500.0),
+    ///     ..OrderRequest::default()
+    /// };
+    /// let placed = tokio_test::block_on(account.place_batch_orders(vec![order]));
+    /// assert!(placed.is_ok(), "{:?}", placed);
+    /// ```
+    pub async fn place_batch_orders(&self, orders: Vec<OrderRequest>) -> Result<Vec<Result<Order>>> {
+        let mut params = HashMap::new();
+        params.insert("orders", serde_json::to_string(&orders)?);
+        let responses: Vec<BatchOrderResponse> = self
+            .client
+            .post_signed_p("/eapi/v1/batchOrders", params, self.recv_window)
+            .await?;
+        Ok(responses.into_iter().map(Into::into).collect())
+    }
+
+    /// Cancel an order
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, options::account::*, config::*};
+    /// let account: OptionsAccount = Binance::new_with_env(&Config::default());
+    /// let cancellation = OrderCancellation {
+    ///     symbol: "BTC-240628-60000-C".to_string(),
+    ///     order_id: Some(4611875134427365377),
+    ///     client_order_id: None,
+    /// };
+    /// let canceled = tokio_test::block_on(account.cancel_order(cancellation));
+    /// assert!(canceled.is_ok(), "{:?}", canceled);
+    /// ```
+    pub async fn cancel_order(&self, cancellation: OrderCancellation) -> Result<Order> {
+        self.client
+            .delete_signed_p("/eapi/v1/order", cancellation, self.recv_window)
+            .await
+    }
+
+    /// Cancel several orders of a symbol at once, each cancellation succeeds or fails independently
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, options::account::*, config::*};
+    /// let account: OptionsAccount = Binance::new_with_env(&Config::default());
+    /// let canceled = tokio_test::block_on(account.cancel_batch_orders("BTC-240628-60000-C", &[4611875134427365377]));
+    /// assert!(canceled.is_ok(), "{:?}", canceled);
+    /// ```
+    pub async fn cancel_batch_orders<S>(&self, symbol: S, order_ids: &[u64]) -> Result<Vec<Result<Order>>>
+    where
+        S: Into<String>,
+    {
+        let mut params = HashMap::new();
+        params.insert("symbol", symbol.into());
+        params.insert("orderIds", serde_json::to_string(order_ids)?);
+        let responses: Vec<BatchOrderResponse> = self
+            .client
+            .delete_signed_p("/eapi/v1/batchOrders", params, self.recv_window)
+            .await?;
+        Ok(responses.into_iter().map(Into::into).collect())
+    }
+
+    /// Cancel all open orders of a symbol
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, options::account::*, config::*};
+    /// let account: OptionsAccount = Binance::new_with_env(&Config::default());
+    /// let canceled = tokio_test::block_on(account.cancel_all_open_orders("BTC-240628-60000-C"));
+    /// assert!(canceled.is_ok(), "{:?}", canceled);
+    /// ```
+    pub async fn cancel_all_open_orders<S>(&self, symbol: S) -> Result<()>
+    where
+        S: Into<String>,
+    {
+        let mut params = HashMap::new();
+        params.insert("symbol", symbol.into());
+        let _: Value = self
+            .client
+            .delete_signed_p("/eapi/v1/allOpenOrders", params, self.recv_window)
+            .await?;
+        Ok(())
+    }
+
+    /// Open orders
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, options::account::*, config::*};
+    /// let account: OptionsAccount = Binance::new_with_env(&Config::default());
+    /// let orders = tokio_test::block_on(account.open_orders(OpenOrdersQuery::default()));
+    /// assert!(orders.is_ok(), "{:?}", orders);
+    /// ```
+    pub async fn open_orders(&self, query: OpenOrdersQuery) -> Result<Vec<Order>> {
+        self.client
+            .get_signed_p("/eapi/v1/openOrders", Some(query), self.recv_window)
+            .await
+    }
+
+    /// Exercise history of the user positions
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, options::account::*, config::*};
+    /// let account: OptionsAccount = Binance::new_with_env(&Config::default());
+    /// let records = tokio_test::block_on(account.exercise_record(ExerciseRecordQuery::default()));
+    /// assert!(records.is_ok(), "{:?}", records);
+    /// ```
+    pub async fn exercise_record(&self, query: ExerciseRecordQuery) -> Result<Vec<ExerciseRecord>> {
+        self.client
+            .get_signed_p("/eapi/v1/exerciseRecord", Some(query), self.recv_window)
+            .await
+    }
+}
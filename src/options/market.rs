@@ -0,0 +1,124 @@
+use std::collections::HashMap;
+
+use crate::client::*;
+use crate::errors::*;
+use crate::options::rest_model::*;
+
+#[derive(Clone)]
+pub struct OptionsMarket {
+    pub client: Client,
+    pub recv_window: u64,
+}
+
+impl OptionsMarket {
+    /// Order book of an option symbol (Default 100; valid limits: 10, 20, 50, 100, 500, 1000)
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, options::market::*, config::*};
+    /// let market: OptionsMarket = Binance::new(None, None);
+    /// let depth = tokio_test::block_on(market.get_depth("BTC-240628-60000-C", None));
+    /// assert!(depth.is_ok(), "{:?}", depth);
+    /// ```
+    pub async fn get_depth<S>(&self, symbol: S, limit: Option<u16>) -> Result<OrderBook>
+    where
+        S: Into<String>,
+    {
+        let mut params = HashMap::new();
+        params.insert("symbol", symbol.into());
+        if let Some(limit) = limit {
+            params.insert("limit", limit.to_string());
+        }
+        self.client.get_d("/eapi/v1/depth", Some(params)).await
+    }
+
+    /// Klines of an option symbol
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, options::market::*, options::rest_model::*, config::*};
+    /// let market: OptionsMarket = Binance::new(None, None);
+    /// let query = KlineQuery {
+    ///     symbol: "BTC-240628-60000-C".to_string(),
+    ///     interval: "1h".to_string(),
+    ///     ..KlineQuery::default()
+    /// };
+    /// let klines = tokio_test::block_on(market.get_klines(query));
+    /// assert!(klines.is_ok(), "{:?}", klines);
+    /// ```
+    pub async fn get_klines(&self, query: KlineQuery) -> Result<Vec<Kline>> {
+        self.client.get_d("/eapi/v1/klines", Some(query)).await
+    }
+
+    /// Mark price, implied volatilities and greeks, of all symbols if `symbol` is None
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, options::market::*, config::*};
+    /// let market: OptionsMarket = Binance::new(None, None);
+    /// let mark_prices = tokio_test::block_on(market.get_mark_prices(None));
+    /// assert!(mark_prices.is_ok(), "{:?}", mark_prices);
+    /// ```
+    pub async fn get_mark_prices(&self, symbol: Option<String>) -> Result<Vec<MarkPrice>> {
+        let mut params = HashMap::new();
+        if let Some(symbol) = symbol {
+            params.insert("symbol", symbol);
+        }
+        self.client.get_d("/eapi/v1/mark", Some(params)).await
+    }
+
+    /// Open interest of all symbols of an underlying asset expiring on `expiration`, formatted as YYMMDD
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, options::market::*, config::*};
+    /// let market: OptionsMarket = Binance::new(None, None);
+    /// let open_interest = tokio_test::block_on(market.get_open_interest("BTC", "240628"));
+    /// assert!(open_interest.is_ok(), "{:?}", open_interest);
+    /// ```
+    pub async fn get_open_interest<S1, S2>(&self, underlying_asset: S1, expiration: S2) -> Result<Vec<OpenInterest>>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+    {
+        let mut params = HashMap::new();
+        params.insert("underlyingAsset", underlying_asset.into());
+        params.insert("expiration", expiration.into());
+        self.client.get_d("/eapi/v1/openInterest", Some(params)).await
+    }
+
+    /// Exercise results of expired options
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, options::market::*, options::rest_model::*, config::*};
+    /// let market: OptionsMarket = Binance::new(None, None);
+    /// let query = ExerciseHistoryQuery {
+    ///     underlying: Some("BTCUSDT".to_string()),
+    ///     ..ExerciseHistoryQuery::default()
+    /// };
+    /// let history = tokio_test::block_on(market.get_exercise_history(query));
+    /// assert!(history.is_ok(), "{:?}", history);
+    /// ```
+    pub async fn get_exercise_history(&self, query: ExerciseHistoryQuery) -> Result<Vec<ExerciseHistory>> {
+        self.client.get_d("/eapi/v1/exerciseHistory", Some(query)).await
+    }
+
+    /// Spot index price of an underlying, e.g. BTCUSDT
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, options::market::*, config::*};
+    /// let market: OptionsMarket = Binance::new(None, None);
+    /// let index = tokio_test::block_on(market.get_index_price("BTCUSDT"));
+    /// assert!(index.is_ok(), "{:?}", index);
+    /// ```
+    pub async fn get_index_price<S>(&self, underlying: S) -> Result<IndexPrice>
+    where
+        S: Into<String>,
+    {
+        let mut params = HashMap::new();
+        params.insert("underlying", underlying.into());
+        self.client.get_d("/eapi/v1/index", Some(params)).await
+    }
+}
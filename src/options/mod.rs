@@ -0,0 +1,7 @@
+//! European options, served by the eapi host configured with
+//! [`crate::config::Config::set_options_rest_api_endpoint`].
+
+pub mod account;
+pub mod general;
+pub mod market;
+pub mod rest_model;
@@ -0,0 +1,358 @@
+use serde::{Deserialize, Serialize};
+
+use crate::rest_model::{string_or_float, string_or_float_opt, string_or_u64};
+pub use crate::rest_model::{Asks, Bids, Filters, Level, OrderResponse, OrderSide, RateLimit, ServerTime, TimeInForce};
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ExchangeInformation {
+    pub timezone: String,
+    pub server_time: u64,
+    pub option_contracts: Vec<OptionContract>,
+    pub option_assets: Vec<OptionAsset>,
+    pub option_symbols: Vec<OptionSymbol>,
+    pub rate_limits: Vec<RateLimit>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionContract {
+    pub base_asset: String,
+    pub quote_asset: String,
+    pub underlying: String,
+    pub settle_asset: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct OptionAsset {
+    pub name: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum OptionSide {
+    Call,
+    Put,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionSymbol {
+    pub symbol: String,
+    pub side: OptionSide,
+    #[serde(with = "string_or_float")]
+    pub strike_price: f64,
+    pub underlying: String,
+    pub expiry_date: u64,
+    /// Number of underlying assets per contract
+    pub unit: u64,
+    pub filters: Vec<Filters>,
+    #[serde(with = "string_or_float")]
+    pub maker_fee_rate: f64,
+    #[serde(with = "string_or_float")]
+    pub taker_fee_rate: f64,
+    #[serde(with = "string_or_float")]
+    pub min_qty: f64,
+    #[serde(with = "string_or_float")]
+    pub max_qty: f64,
+    #[serde(with = "string_or_float")]
+    pub initial_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub maintenance_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub min_initial_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub min_maintenance_margin: f64,
+    pub price_scale: u8,
+    pub quantity_scale: u8,
+    pub quote_asset: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct OrderBook {
+    /// Transaction time
+    #[serde(rename = "T")]
+    pub transaction_time: u64,
+    #[serde(rename = "u")]
+    pub update_id: u64,
+    pub bids: Vec<Bids>,
+    pub asks: Vec<Asks>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct KlineQuery {
+    pub symbol: String,
+    /// 1m, 3m, 5m, 15m, 30m, 1h, 2h, 4h, 6h, 12h, 1d, 3d, 1w
+    pub interval: String,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    /// Default: 500, Max: 1500
+    pub limit: Option<u16>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Kline {
+    pub open_time: u64,
+    #[serde(with = "string_or_float")]
+    pub open: f64,
+    #[serde(with = "string_or_float")]
+    pub high: f64,
+    #[serde(with = "string_or_float")]
+    pub low: f64,
+    #[serde(with = "string_or_float")]
+    pub close: f64,
+    /// Traded contracts
+    #[serde(with = "string_or_float")]
+    pub volume: f64,
+    /// Traded quote amount
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    pub interval: String,
+    pub trade_count: u64,
+    #[serde(with = "string_or_float")]
+    pub taker_volume: f64,
+    #[serde(with = "string_or_float")]
+    pub taker_amount: f64,
+    pub close_time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MarkPrice {
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub mark_price: f64,
+    #[serde(rename = "bidIV", with = "string_or_float")]
+    pub bid_iv: f64,
+    #[serde(rename = "askIV", with = "string_or_float")]
+    pub ask_iv: f64,
+    #[serde(rename = "markIV", with = "string_or_float")]
+    pub mark_iv: f64,
+    #[serde(with = "string_or_float")]
+    pub delta: f64,
+    #[serde(with = "string_or_float")]
+    pub theta: f64,
+    #[serde(with = "string_or_float")]
+    pub gamma: f64,
+    #[serde(with = "string_or_float")]
+    pub vega: f64,
+    #[serde(with = "string_or_float")]
+    pub high_price_limit: f64,
+    #[serde(with = "string_or_float")]
+    pub low_price_limit: f64,
+    #[serde(with = "string_or_float")]
+    pub risk_free_interest: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountInformation {
+    pub asset: Vec<AccountAsset>,
+    pub greek: Vec<AccountGreek>,
+    pub time: u64,
+    /// NORMAL, MEDIUM, HIGH
+    pub risk_level: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountAsset {
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub margin_balance: f64,
+    #[serde(with = "string_or_float")]
+    pub equity: f64,
+    #[serde(with = "string_or_float")]
+    pub available: f64,
+    #[serde(with = "string_or_float")]
+    pub locked: f64,
+    #[serde(rename = "unrealizedPNL", with = "string_or_float")]
+    pub unrealized_pnl: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountGreek {
+    pub underlying: String,
+    #[serde(with = "string_or_float")]
+    pub delta: f64,
+    #[serde(with = "string_or_float")]
+    pub gamma: f64,
+    #[serde(with = "string_or_float")]
+    pub theta: f64,
+    #[serde(with = "string_or_float")]
+    pub vega: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PositionSide {
+    Long,
+    Short,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Position {
+    pub symbol: String,
+    pub side: PositionSide,
+    /// Negative for short positions
+    #[serde(with = "string_or_float")]
+    pub quantity: f64,
+    #[serde(with = "string_or_float")]
+    pub reducible_qty: f64,
+    #[serde(with = "string_or_float")]
+    pub entry_price: f64,
+    #[serde(with = "string_or_float")]
+    pub mark_price: f64,
+    #[serde(with = "string_or_float")]
+    pub mark_value: f64,
+    /// Rate of return
+    #[serde(with = "string_or_float")]
+    pub ror: f64,
+    #[serde(rename = "unrealizedPNL", with = "string_or_float")]
+    pub unrealized_pnl: f64,
+    #[serde(with = "string_or_float")]
+    pub strike_price: f64,
+    #[serde(with = "string_or_float")]
+    pub position_cost: f64,
+    pub expiry_date: u64,
+    pub price_scale: u8,
+    pub quantity_scale: u8,
+    pub option_side: OptionSide,
+    pub quote_asset: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Default)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum OrderType {
+    #[default]
+    Limit,
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum OrderStatus {
+    Accepted,
+    Rejected,
+    PartiallyFilled,
+    Filled,
+    Cancelled,
+    #[serde(other)]
+    Other,
+}
+
+/// An option order, fields missing from ACK responses are left to their default
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Order {
+    pub order_id: u64,
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub price: f64,
+    #[serde(with = "string_or_float")]
+    pub quantity: f64,
+    #[serde(default, with = "string_or_float_opt")]
+    pub executed_qty: Option<f64>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub fee: Option<f64>,
+    pub side: OrderSide,
+    #[serde(rename = "type")]
+    pub order_type: OrderType,
+    pub time_in_force: Option<TimeInForce>,
+    pub reduce_only: bool,
+    pub post_only: bool,
+    pub create_time: Option<u64>,
+    pub update_time: Option<u64>,
+    pub status: Option<OrderStatus>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub avg_price: Option<f64>,
+    pub client_order_id: Option<String>,
+    pub price_scale: Option<u8>,
+    pub quantity_scale: Option<u8>,
+    pub option_side: Option<OptionSide>,
+    pub quote_asset: Option<String>,
+    #[serde(default)]
+    pub mmp: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ExerciseRecord {
+    pub id: String,
+    pub currency: String,
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub exercise_price: f64,
+    #[serde(with = "string_or_float")]
+    pub mark_price: f64,
+    #[serde(with = "string_or_float")]
+    pub quantity: f64,
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    #[serde(with = "string_or_float")]
+    pub fee: f64,
+    pub create_date: u64,
+    pub price_scale: u8,
+    pub quantity_scale: u8,
+    pub option_side: OptionSide,
+    pub position_side: PositionSide,
+    pub quote_asset: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenInterest {
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub sum_open_interest: f64,
+    #[serde(with = "string_or_float")]
+    pub sum_open_interest_usd: f64,
+    #[serde(with = "string_or_u64")]
+    pub timestamp: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ExerciseHistoryQuery {
+    /// Underlying index, e.g. BTCUSDT
+    pub underlying: Option<String>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    /// Default: 100, Max: 100
+    pub limit: Option<u16>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum StrikeResult {
+    RealisticValueStricken,
+    ExtrinsicValueExpired,
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ExerciseHistory {
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub strike_price: f64,
+    /// Settlement price of the underlying at expiry
+    #[serde(with = "string_or_float")]
+    pub real_strike_price: f64,
+    pub expiry_date: u64,
+    pub strike_result: StrikeResult,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexPrice {
+    pub time: u64,
+    #[serde(with = "string_or_float")]
+    pub index_price: f64,
+}
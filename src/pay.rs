@@ -0,0 +1,172 @@
+//! Binance Pay transaction history.
+
+use std::collections::HashMap;
+
+use crate::client::*;
+use crate::errors::*;
+use crate::rest_model::{string_or_float, string_or_float_opt};
+
+static SAPI_V1_PAY_TRANSACTIONS: &str = "/sapi/v1/pay/transactions";
+
+/// Pay endpoints wrap their data in an envelope with a success flag
+#[derive(Deserialize)]
+struct PayResponse<T> {
+    code: String,
+    message: String,
+    data: Option<T>,
+    success: bool,
+}
+
+impl<T> PayResponse<T> {
+    fn into_result(self) -> Result<T> {
+        match self.data {
+            Some(data) if self.success => Ok(data),
+            _ => Err(Error::Msg(format!("code: {}, msg: {}", self.code, self.message))),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct PayTransactionQuery {
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    /// Default: 100, Max: 100
+    pub limit: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PayOrderType {
+    Pay,
+    PayRefund,
+    C2c,
+    CryptoBox,
+    /// Refund of an unclaimed crypto box
+    CryptoBoxRf,
+    C2cHolding,
+    /// Refund of a C2C transfer held for a receiver without an account
+    C2cHoldingRf,
+    Payout,
+    Remittance,
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PayTransaction {
+    pub order_type: PayOrderType,
+    pub transaction_id: String,
+    pub transaction_time: u64,
+    /// Negative when paying, positive when receiving
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    pub currency: String,
+    /// 1: funding wallet, 2: spot wallet
+    pub wallet_type: Option<u8>,
+    /// Wallets the funds were taken from or credited to
+    #[serde(default)]
+    pub wallet_types: Vec<u8>,
+    #[serde(default)]
+    pub funds_detail: Vec<PayFundsDetail>,
+    pub payer_info: Option<PayParty>,
+    pub receiver_info: Option<PayParty>,
+    /// Fees paid for the transaction, in `currency`
+    #[serde(default, with = "string_or_float_opt")]
+    pub total_payment_fee: Option<f64>,
+}
+
+impl PayTransaction {
+    pub fn is_refund(&self) -> bool {
+        matches!(
+            self.order_type,
+            PayOrderType::PayRefund | PayOrderType::CryptoBoxRf | PayOrderType::C2cHoldingRf
+        )
+    }
+
+    /// Bank card or digital wallet the funds were paid out to
+    pub fn payout_details(&self) -> Option<&HashMap<String, String>> {
+        if self.order_type != PayOrderType::Payout {
+            return None;
+        }
+        self.receiver_info.as_ref()?.extend.as_ref()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PayFundsDetail {
+    pub currency: String,
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    /// Amount taken from each wallet, keyed by wallet type
+    #[serde(default)]
+    pub wallet_asset_cost: Vec<HashMap<String, String>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PayParty {
+    /// Nickname or merchant name
+    pub name: String,
+    /// USER or MERCHANT
+    #[serde(rename = "type")]
+    pub party_type: Option<String>,
+    pub binance_id: Option<String>,
+    pub account_id: Option<String>,
+    pub email: Option<String>,
+    pub country_code: Option<String>,
+    pub phone_number: Option<String>,
+    pub mobile_code: Option<String>,
+    /// Bank card or digital wallet details, only for payouts
+    pub extend: Option<HashMap<String, String>>,
+}
+
+/// This struct acts as a gateway for all Binance Pay endpoints.
+/// Preferably use the trait [`crate::api::Binance`] to get an instance.
+#[derive(Clone)]
+pub struct Pay {
+    pub client: Client,
+    pub recv_window: u64,
+}
+
+impl Pay {
+    /// Pay transactions, most recent first
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, pay::*, config::*};
+    /// let pay: Pay = Binance::new_with_env(&Config::testnet());
+    /// let transactions = tokio_test::block_on(pay.transactions(PayTransactionQuery::default()));
+    /// assert!(transactions.is_ok(), "{:?}", transactions);
+    /// ```
+    pub async fn transactions(&self, query: PayTransactionQuery) -> Result<Vec<PayTransaction>> {
+        let response: PayResponse<Vec<PayTransaction>> = self
+            .client
+            .get_signed_p(SAPI_V1_PAY_TRANSACTIONS, Some(query), self.recv_window)
+            .await?;
+        response.into_result()
+    }
+
+    /// Pay transactions of the given order types, e.g. refunds and payouts to match settlements
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, pay::*, config::*};
+    /// let pay: Pay = Binance::new_with_env(&Config::testnet());
+    /// let refunds = tokio_test::block_on(pay.transactions_of_types(PayTransactionQuery::default(), &[PayOrderType::PayRefund]));
+    /// assert!(refunds.is_ok(), "{:?}", refunds);
+    /// ```
+    pub async fn transactions_of_types(
+        &self,
+        query: PayTransactionQuery,
+        order_types: &[PayOrderType],
+    ) -> Result<Vec<PayTransaction>> {
+        let transactions = self.transactions(query).await?;
+        Ok(transactions
+            .into_iter()
+            .filter(|t| order_types.contains(&t.order_type))
+            .collect())
+    }
+}
@@ -0,0 +1,186 @@
+//! Permissions of the API key required by the restricted endpoints.
+//!
+//! With [`crate::config::Config::with_permission_preflight`], clients fetch the permissions of their key once,
+//! then fail locally with [`crate::errors::Error::MissingPermission`] before calling an endpoint the key cannot use,
+//! instead of receiving an opaque `-2015` from the exchange. Binance.US does not serve the permissions endpoint.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, RwLock};
+
+use chrono::Utc;
+use reqwest::Method;
+
+use crate::config::Config;
+use crate::errors::*;
+use crate::rest_model::ApiKeyPermissions;
+
+pub(crate) static SAPI_V1_ACCOUNT_APIRESTRICTIONS: &str = "/sapi/v1/account/apiRestrictions";
+
+/// A permission of the API key
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Permission {
+    Reading,
+    SpotAndMarginTrading,
+    Margin,
+    Futures,
+    Withdrawals,
+    InternalTransfer,
+    UniversalTransfer,
+    VanillaOptions,
+}
+
+impl fmt::Display for Permission {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Permission::Reading => "reading",
+            Permission::SpotAndMarginTrading => "spot and margin trading",
+            Permission::Margin => "margin",
+            Permission::Futures => "futures",
+            Permission::Withdrawals => "withdrawals",
+            Permission::InternalTransfer => "internal transfers",
+            Permission::UniversalTransfer => "universal transfers",
+            Permission::VanillaOptions => "vanilla options",
+        })
+    }
+}
+
+/// Path prefixes which change the account, and the permissions they require
+static WRITE_PREFIXES: &[(&str, &[Permission])] = &[
+    ("/api/v3/order", &[Permission::SpotAndMarginTrading]),
+    ("/api/v3/openOrders", &[Permission::SpotAndMarginTrading]),
+    ("/api/v3/orderList", &[Permission::SpotAndMarginTrading]),
+    ("/api/v3/sor/order", &[Permission::SpotAndMarginTrading]),
+    ("/sapi/v1/margin/order", &[
+        Permission::SpotAndMarginTrading,
+        Permission::Margin,
+    ]),
+    ("/sapi/v1/margin/openOrders", &[
+        Permission::SpotAndMarginTrading,
+        Permission::Margin,
+    ]),
+    ("/sapi/v1/margin/", &[Permission::Margin]),
+    ("/sapi/v1/capital/withdraw/apply", &[Permission::Withdrawals]),
+    ("/sapi/v1/asset/transfer", &[Permission::UniversalTransfer]),
+    ("/sapi/v1/sub-account/universalTransfer", &[
+        Permission::UniversalTransfer,
+    ]),
+    ("/sapi/v1/sub-account/transfer", &[Permission::InternalTransfer]),
+    ("/fapi/", &[Permission::Futures]),
+    ("/dapi/", &[Permission::Futures]),
+    ("/eapi/", &[Permission::VanillaOptions]),
+];
+
+/// Permissions required to call the signed `endpoint` with `method`
+///
+/// # Examples
+/// ```rust
+/// use binance::permissions::*;
+/// use reqwest::Method;
+/// assert_eq!(required_permissions(&Method::POST, "/sapi/v1/capital/withdraw/apply"), &[Permission::Withdrawals]);
+/// assert_eq!(required_permissions(&Method::GET, "/api/v3/account"), &[Permission::Reading]);
+/// ```
+pub fn required_permissions(method: &Method, endpoint: &str) -> &'static [Permission] {
+    if endpoint == SAPI_V1_ACCOUNT_APIRESTRICTIONS {
+        return &[];
+    }
+    if method == Method::GET {
+        return &[Permission::Reading];
+    }
+    WRITE_PREFIXES
+        .iter()
+        .find(|(prefix, _)| endpoint.starts_with(prefix))
+        .map(|(_, permissions)| *permissions)
+        .unwrap_or(&[])
+}
+
+impl ApiKeyPermissions {
+    /// Whether the key has `permission`, spot and margin trading also requires the authority not to be expired
+    pub fn allows(&self, permission: Permission) -> bool {
+        match permission {
+            Permission::Reading => self.enable_reading,
+            Permission::SpotAndMarginTrading => {
+                let expired = matches!(
+                    self.trading_authority_expiration_time,
+                    Some(expiration) if expiration as i64 <= Utc::now().timestamp_millis()
+                );
+                self.enable_spot_and_margin_trading && !expired
+            }
+            Permission::Margin => self.enable_margin,
+            Permission::Futures => self.enable_futures,
+            Permission::Withdrawals => self.enable_withdrawals,
+            Permission::InternalTransfer => self.enable_internal_transfer,
+            Permission::UniversalTransfer => self.permits_universal_transfer,
+            Permission::VanillaOptions => self.enable_vanilla_options,
+        }
+    }
+
+    /// Fails with [`Error::MissingPermission`] if the key does not have `permission`
+    pub fn check(&self, permission: Permission) -> Result<()> {
+        if self.allows(permission) {
+            Ok(())
+        } else {
+            Err(Error::MissingPermission(permission))
+        }
+    }
+}
+
+/// Permissions of the keys used by the clients, fetched once per API key and shared between the gateways
+///
+/// # Examples
+/// ```rust,no_run
+/// use binance::{account::*, api::*, config::*, errors::*};
+/// let config = Config::testnet().with_permission_preflight();
+/// let account: Account = Binance::new_with_env(&config);
+/// let order = tokio_test::block_on(account.place_order(OrderRequest::default()));
+/// assert!(matches!(order, Err(Error::MissingPermission(_))), "{:?}", order);
+/// ```
+#[derive(Clone)]
+pub struct PermissionPreflight {
+    pub(crate) host: String,
+    pub(crate) recv_window: u64,
+    /// Permissions per API key, e.g. of the accounts of an [`crate::account_pool::AccountPool`]
+    cache: Arc<RwLock<HashMap<String, ApiKeyPermissions>>>,
+}
+
+impl PermissionPreflight {
+    /// Preflight fetching the permissions from the wallet endpoint of `config`
+    pub fn new(config: &Config) -> Self {
+        Self {
+            host: config.sapi_endpoint().to_string(),
+            recv_window: config.recv_window,
+            cache: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+
+    /// Cached permissions of `api_key`
+    pub fn cached(&self, api_key: &str) -> Option<ApiKeyPermissions> {
+        self.cache
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(api_key)
+            .cloned()
+    }
+
+    pub(crate) fn store(&self, api_key: String, permissions: ApiKeyPermissions) {
+        self.cache
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(api_key, permissions);
+    }
+
+    /// Forget the cached permissions, e.g. after they were changed, they are fetched again by the next request
+    pub fn clear(&self) { self.cache.write().unwrap_or_else(|e| e.into_inner()).clear(); }
+}
+
+impl fmt::Debug for PermissionPreflight {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PermissionPreflight").field("host", &self.host).finish()
+    }
+}
+
+impl PartialEq for PermissionPreflight {
+    fn eq(&self, other: &Self) -> bool { Arc::ptr_eq(&self.cache, &other.cache) }
+}
+
+impl Eq for PermissionPreflight {}
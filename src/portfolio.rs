@@ -0,0 +1,467 @@
+//! Consolidated valuation of the balances held across the Binance products.
+
+use std::collections::{BTreeMap, HashMap};
+
+use futures::future::try_join_all;
+
+use crate::account::Account;
+use crate::errors::*;
+use crate::futures::account::FuturesAccount;
+use crate::futures::rest_model::CommissionRate;
+use crate::margin::Margin;
+use crate::market::Market;
+use crate::rest_model::{Prices, TradeFee, TransactionId};
+use crate::simple_earn::SimpleEarn;
+use crate::util::round_down;
+use crate::wallet::Wallet;
+
+/// Assets used to value assets which are not directly quoted in the target quote asset
+static BRIDGE_ASSETS: [&str; 3] = ["BTC", "USDT", "BNB"];
+
+/// Transfer amounts are rounded down to the 8 decimals supported by the wallet
+static COLLATERAL_STEP: f64 = 0.000_000_01;
+
+/// Product holding a balance
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum BalanceSource {
+    Spot,
+    Funding,
+    CrossMargin,
+    IsolatedMargin,
+    UsdFutures,
+    /// Simple Earn flexible and locked positions, the `LD` receipts of the flexible positions held on spot
+    /// are then skipped
+    Earn,
+}
+
+impl BalanceSource {
+    pub const ALL: [BalanceSource; 6] = [
+        BalanceSource::Spot,
+        BalanceSource::Funding,
+        BalanceSource::CrossMargin,
+        BalanceSource::IsolatedMargin,
+        BalanceSource::UsdFutures,
+        BalanceSource::Earn,
+    ];
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssetValuation {
+    pub asset: String,
+    /// Total quantity held
+    pub quantity: f64,
+    /// Quantity held in each product
+    pub sources: BTreeMap<BalanceSource, f64>,
+    /// Price in the quote asset, None if no market allows to value the asset
+    pub price: Option<f64>,
+    /// Value in the quote asset, None if no market allows to value the asset
+    pub value: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PortfolioValuation {
+    pub quote_asset: String,
+    /// Valuation of each asset held, largest value first
+    pub assets: Vec<AssetValuation>,
+    /// Sum of the values of the assets which could be valued
+    pub total: f64,
+}
+
+/// Settings and tiers which determine the fees paid by an account
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FeeProfile {
+    pub vip_level: u8,
+    /// Whether spot fees are paid in BNB
+    pub spot_bnb_burn: Option<bool>,
+    /// Whether margin interests are paid in BNB
+    pub margin_interest_bnb_burn: Option<bool>,
+    /// Spot fees of the requested symbol
+    pub spot_fees: Option<TradeFee>,
+    /// None if futures are not enabled for the account
+    pub futures: Option<FuturesFeeProfile>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FuturesFeeProfile {
+    pub fee_tier: u64,
+    /// Whether futures fees are paid in BNB
+    pub bnb_burn: bool,
+    /// Commission rates of the requested symbol
+    pub commission_rate: CommissionRate,
+}
+
+/// Side of a transfer between the spot and the USDⓈ-M futures accounts
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CollateralDirection {
+    SpotToFutures,
+    FuturesToSpot,
+}
+
+/// Amount of collateral to move between the spot and the USDⓈ-M futures accounts
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum CollateralTarget {
+    /// Move this fraction, in `(0, 1]`, of the transferable balance of the source account
+    Ratio(CollateralDirection, f64),
+    /// Move whatever brings the futures wallet balance to this amount, in either direction
+    FuturesBalance(f64),
+}
+
+/// Transfer computed from the balances read before moving the collateral
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CollateralTransfer {
+    pub asset: String,
+    pub direction: CollateralDirection,
+    pub amount: f64,
+    /// Free spot balance before the transfer
+    pub spot_free: f64,
+    /// Futures wallet balance before the transfer
+    pub futures_balance: f64,
+    /// Amount which could be withdrawn from the futures account before the transfer
+    pub futures_max_withdraw: f64,
+    /// None until the transfer is submitted
+    pub tran_id: Option<u64>,
+}
+
+/// Pulls the balances of every product of an account and values them in a single quote asset.
+///
+/// Prices are taken from a single request of all the latest prices.
+///
+/// # Examples
+/// ```rust,no_run
+/// use binance::{api::*, config::*, portfolio::*};
+/// let portfolio: Portfolio = Binance::new_with_env(&Config::default());
+/// let valuation = tokio_test::block_on(portfolio.valuation("USDT"));
+/// assert!(valuation.is_ok(), "{:?}", valuation);
+/// ```
+#[derive(Clone)]
+pub struct Portfolio {
+    pub account: Account,
+    pub wallet: Wallet,
+    pub margin: Margin,
+    pub futures: FuturesAccount,
+    pub simple_earn: SimpleEarn,
+    pub market: Market,
+    /// Products to pull balances from, all by default
+    pub sources: Vec<BalanceSource>,
+}
+
+impl Portfolio {
+    /// Only pull the balances of `sources`, e.g. to skip products which are not enabled for the account
+    pub fn with_sources(mut self, sources: &[BalanceSource]) -> Self {
+        self.sources = sources.to_vec();
+        self
+    }
+
+    /// Quantity of every asset held, per product
+    pub async fn balances(&self) -> Result<BTreeMap<String, BTreeMap<BalanceSource, f64>>> {
+        let source_balances = try_join_all(self.sources.iter().map(|source| self.source_balances(*source))).await?;
+        let mut balances: BTreeMap<String, BTreeMap<BalanceSource, f64>> = BTreeMap::new();
+        for (source, assets) in self.sources.iter().zip(source_balances) {
+            for (asset, quantity) in assets {
+                if quantity != 0.0 {
+                    *balances.entry(asset).or_default().entry(*source).or_default() += quantity;
+                }
+            }
+        }
+        if self.sources.contains(&BalanceSource::Earn) {
+            let receipts: Vec<String> = balances
+                .keys()
+                .filter(|asset| {
+                    asset.strip_prefix("LD").is_some_and(|underlying| {
+                        balances
+                            .get(underlying)
+                            .is_some_and(|sources| sources.contains_key(&BalanceSource::Earn))
+                    })
+                })
+                .cloned()
+                .collect();
+            for receipt in receipts {
+                if let Some(sources) = balances.get_mut(&receipt) {
+                    sources.remove(&BalanceSource::Spot);
+                    if sources.is_empty() {
+                        balances.remove(&receipt);
+                    }
+                }
+            }
+        }
+        Ok(balances)
+    }
+
+    /// Value every asset held in `quote_asset`
+    pub async fn valuation<S>(&self, quote_asset: S) -> Result<PortfolioValuation>
+    where
+        S: Into<String>,
+    {
+        let quote_asset = quote_asset.into();
+        let (balances, prices) = futures::try_join!(self.balances(), self.market.get_all_prices())?;
+        let Prices::AllPrices(prices) = prices;
+        let prices: HashMap<String, f64> = prices.into_iter().map(|p| (p.symbol, p.price)).collect();
+        let mut assets: Vec<AssetValuation> = balances
+            .into_iter()
+            .map(|(asset, sources)| {
+                let quantity = sources.values().sum();
+                let price = price_in(&prices, &asset, &quote_asset);
+                AssetValuation {
+                    value: price.map(|price| price * quantity),
+                    asset,
+                    quantity,
+                    sources,
+                    price,
+                }
+            })
+            .collect();
+        assets.sort_by(|a, b| b.value.unwrap_or(0.0).total_cmp(&a.value.unwrap_or(0.0)));
+        Ok(PortfolioValuation {
+            total: assets.iter().filter_map(|a| a.value).sum(),
+            quote_asset,
+            assets,
+        })
+    }
+
+    /// VIP level, BNB burn settings and fees of `symbol` on spot and USD-M futures
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, config::*, portfolio::*};
+    /// let portfolio: Portfolio = Binance::new_with_env(&Config::default());
+    /// let profile = tokio_test::block_on(portfolio.fee_profile("BTCUSDT"));
+    /// assert!(profile.is_ok(), "{:?}", profile);
+    /// ```
+    pub async fn fee_profile<S>(&self, symbol: S) -> Result<FeeProfile>
+    where
+        S: Into<String>,
+    {
+        let symbol = symbol.into();
+        let account_info = self.wallet.account_info().await?;
+        let futures = async {
+            if !account_info.is_future_enabled {
+                return Ok(None);
+            }
+            let (account, bnb_burn, commission_rate) = futures::try_join!(
+                self.futures.account_information(),
+                self.futures.fee_burn_status(),
+                self.futures.commission_rate(symbol.clone())
+            )?;
+            Ok(Some(FuturesFeeProfile {
+                fee_tier: account.fee_tier,
+                bnb_burn: bnb_burn.fee_burn,
+                commission_rate,
+            }))
+        };
+        let (bnb_burn, spot_fees, futures) = futures::try_join!(
+            self.margin.bnb_burn_status(),
+            self.wallet.trade_fees(Some(symbol.clone())),
+            futures
+        )?;
+        Ok(FeeProfile {
+            vip_level: account_info.vip_level,
+            spot_bnb_burn: bnb_burn.spot_bnb_burn,
+            margin_interest_bnb_burn: bnb_burn.interest_bnb_burn,
+            spot_fees: spot_fees.into_iter().find(|f| f.symbol == symbol),
+            futures,
+        })
+    }
+
+    /// Transfer needed to reach `target` for `asset`, without moving anything.
+    ///
+    /// `safety_margin`, in `[0, 1)`, is the fraction of the source balance which is never moved,
+    /// e.g. `0.01` leaves 1% of the free spot balance or of the futures max withdraw amount in place.
+    /// Returns None if there is nothing to move.
+    pub async fn plan_collateral_transfer<S>(
+        &self,
+        asset: S,
+        target: CollateralTarget,
+        safety_margin: f64,
+    ) -> Result<Option<CollateralTransfer>>
+    where
+        S: Into<String>,
+    {
+        let asset = asset.into();
+        if !(0.0..1.0).contains(&safety_margin) {
+            return Err(Error::InvalidTransfer(format!(
+                "safety margin {safety_margin} must be in [0, 1)"
+            )));
+        }
+        if let CollateralTarget::Ratio(_, ratio) = target {
+            if !(ratio > 0.0 && ratio <= 1.0) {
+                return Err(Error::InvalidTransfer(format!("ratio {ratio} must be in (0, 1]")));
+            }
+        }
+        if let CollateralTarget::FuturesBalance(balance) = target {
+            if balance.is_nan() || balance < 0.0 {
+                return Err(Error::InvalidTransfer(format!(
+                    "target futures balance {balance} is negative"
+                )));
+            }
+        }
+        let (spot, futures_balances) = futures::try_join!(self.account.get_account(), self.futures.account_balance())?;
+        let spot_free = spot.balances.iter().find(|b| b.asset == asset).map_or(0.0, |b| b.free);
+        let (futures_balance, futures_max_withdraw) = futures_balances
+            .iter()
+            .find(|b| b.asset == asset)
+            .map_or((0.0, 0.0), |b| (b.balance, b.max_withdraw_amount));
+        let spot_transferable = spot_free * (1.0 - safety_margin);
+        let futures_transferable = futures_max_withdraw * (1.0 - safety_margin);
+        let (direction, amount) = match target {
+            CollateralTarget::Ratio(direction @ CollateralDirection::SpotToFutures, ratio) => {
+                (direction, spot_transferable * ratio)
+            }
+            CollateralTarget::Ratio(direction @ CollateralDirection::FuturesToSpot, ratio) => {
+                (direction, futures_transferable * ratio)
+            }
+            CollateralTarget::FuturesBalance(balance) if balance > futures_balance => {
+                let missing = balance - futures_balance;
+                if missing > spot_transferable {
+                    return Err(Error::InvalidTransfer(format!(
+                        "{missing} {asset} missing in futures but only {spot_transferable} can be moved from spot"
+                    )));
+                }
+                (CollateralDirection::SpotToFutures, missing)
+            }
+            CollateralTarget::FuturesBalance(balance) => {
+                let excess = futures_balance - balance;
+                if excess > futures_transferable {
+                    return Err(Error::InvalidTransfer(format!(
+                        "{excess} {asset} in excess in futures but only {futures_transferable} can be withdrawn"
+                    )));
+                }
+                (CollateralDirection::FuturesToSpot, excess)
+            }
+        };
+        let amount = round_down(amount, COLLATERAL_STEP);
+        if amount <= 0.0 {
+            return Ok(None);
+        }
+        Ok(Some(CollateralTransfer {
+            asset,
+            direction,
+            amount,
+            spot_free,
+            futures_balance,
+            futures_max_withdraw,
+            tran_id: None,
+        }))
+    }
+
+    /// Read the spot and futures balances of `asset` and move collateral to reach `target`,
+    /// see [`Portfolio::plan_collateral_transfer`] for the safety margin.
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, config::*, portfolio::*};
+    /// let portfolio: Portfolio = Binance::new_with_env(&Config::testnet());
+    /// // Move a quarter of the free spot USDT to futures, leaving 1% untouched
+    /// let target = CollateralTarget::Ratio(CollateralDirection::SpotToFutures, 0.25);
+    /// let transfer = tokio_test::block_on(portfolio.transfer_collateral("USDT", target, 0.01));
+    /// assert!(transfer.is_ok(), "{:?}", transfer);
+    /// // Top up the futures wallet to 1000 USDT
+    /// let target = CollateralTarget::FuturesBalance(1000.0);
+    /// let transfer = tokio_test::block_on(portfolio.transfer_collateral("USDT", target, 0.01));
+    /// assert!(transfer.is_ok(), "{:?}", transfer);
+    /// ```
+    pub async fn transfer_collateral<S>(
+        &self,
+        asset: S,
+        target: CollateralTarget,
+        safety_margin: f64,
+    ) -> Result<Option<CollateralTransfer>>
+    where
+        S: Into<String>,
+    {
+        let mut transfer = match self.plan_collateral_transfer(asset, target, safety_margin).await? {
+            Some(transfer) => transfer,
+            None => return Ok(None),
+        };
+        let TransactionId { tran_id } = match transfer.direction {
+            CollateralDirection::SpotToFutures => {
+                self.wallet
+                    .transfer_spot_to_usd_m_futures(transfer.asset.clone(), transfer.amount)
+                    .await?
+            }
+            CollateralDirection::FuturesToSpot => {
+                self.wallet
+                    .transfer_usd_m_futures_to_spot(transfer.asset.clone(), transfer.amount)
+                    .await?
+            }
+        };
+        transfer.tran_id = Some(tran_id);
+        Ok(Some(transfer))
+    }
+
+    async fn source_balances(&self, source: BalanceSource) -> Result<Vec<(String, f64)>> {
+        let balances = match source {
+            BalanceSource::Spot => self
+                .account
+                .get_account()
+                .await?
+                .balances
+                .into_iter()
+                .map(|b| (b.asset, b.free + b.locked))
+                .collect(),
+            BalanceSource::Funding => self
+                .wallet
+                .funding_wallet(None, None)
+                .await?
+                .into_iter()
+                .map(|b| (b.asset, b.free + b.locked + b.freeze + b.withdrawing))
+                .collect(),
+            BalanceSource::CrossMargin => self
+                .margin
+                .details()
+                .await?
+                .user_assets
+                .into_iter()
+                .map(|a| (a.asset, a.net_asset))
+                .collect(),
+            BalanceSource::IsolatedMargin => self
+                .margin
+                .isolated_details(None)
+                .await?
+                .assets
+                .into_iter()
+                .flat_map(|pair| [pair.base_asset, pair.quote_asset])
+                .map(|a| (a.asset, a.net_asset))
+                .collect(),
+            BalanceSource::UsdFutures => self
+                .futures
+                .account_balance()
+                .await?
+                .into_iter()
+                .map(|b| (b.asset, b.balance))
+                .collect(),
+            BalanceSource::Earn => {
+                let (flexible, locked) = futures::try_join!(
+                    self.simple_earn.all_flexible_positions(None),
+                    self.simple_earn.all_locked_positions(None)
+                )?;
+                flexible
+                    .into_iter()
+                    .map(|p| (p.asset, p.total_amount))
+                    .chain(locked.into_iter().map(|p| (p.asset, p.amount)))
+                    .collect()
+            }
+        };
+        Ok(balances)
+    }
+}
+
+/// Price of `asset` in `quote`, directly or through one of the bridge assets
+fn price_in(prices: &HashMap<String, f64>, asset: &str, quote: &str) -> Option<f64> {
+    direct_price(prices, asset, quote).or_else(|| {
+        BRIDGE_ASSETS
+            .iter()
+            .find_map(|bridge| Some(direct_price(prices, asset, bridge)? * direct_price(prices, bridge, quote)?))
+    })
+}
+
+fn direct_price(prices: &HashMap<String, f64>, asset: &str, quote: &str) -> Option<f64> {
+    if asset == quote {
+        return Some(1.0);
+    }
+    prices.get(&format!("{asset}{quote}")).copied().or_else(|| {
+        prices
+            .get(&format!("{quote}{asset}"))
+            .filter(|p| **p != 0.0)
+            .map(|p| 1.0 / p)
+    })
+}
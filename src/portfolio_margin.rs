@@ -0,0 +1,203 @@
+//! Portfolio Margin Pro account information, collateral rates and bankruptcy loans.
+
+use std::collections::HashMap;
+
+use crate::client::*;
+use crate::errors::*;
+use crate::rest_model::string_or_float;
+
+static SAPI_V1_PORTFOLIO_ACCOUNT: &str = "/sapi/v1/portfolio/account";
+static SAPI_V1_PORTFOLIO_COLLATERAL_RATE: &str = "/sapi/v1/portfolio/collateralRate";
+static SAPI_V1_PORTFOLIO_PM_LOAN: &str = "/sapi/v1/portfolio/pmLoan";
+static SAPI_V1_PORTFOLIO_REPAY: &str = "/sapi/v1/portfolio/repay";
+static SAPI_V1_PORTFOLIO_BALANCE: &str = "/sapi/v1/portfolio/balance";
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PortfolioMarginAccountStatus {
+    Normal,
+    MarginCall,
+    SupplyMargin,
+    ReduceOnly,
+    ActiveLiquidation,
+    ForceLiquidation,
+    Bankrupted,
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PortfolioMarginAccount {
+    /// Unified maintenance margin ratio, liquidation starts below 1.05
+    #[serde(rename = "uniMMR", with = "string_or_float")]
+    pub uni_mmr: f64,
+    /// Account equity, in USD
+    #[serde(with = "string_or_float")]
+    pub account_equity: f64,
+    /// Equity without collateral rates, in USD
+    #[serde(with = "string_or_float")]
+    pub actual_equity: f64,
+    /// Maintenance margin, in USD
+    #[serde(with = "string_or_float")]
+    pub account_maint_margin: f64,
+    pub account_status: PortfolioMarginAccountStatus,
+    /// PM_1 for Portfolio Margin Pro
+    pub account_type: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CollateralRate {
+    pub asset: String,
+    /// Share of the asset value counted in the account equity
+    #[serde(with = "string_or_float")]
+    pub collateral_rate: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BankruptcyLoan {
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum RepaySource {
+    Spot,
+    Margin,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BankruptcyLoanRepay {
+    pub tran_id: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PortfolioMarginBalance {
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub total_wallet_balance: f64,
+    #[serde(with = "string_or_float")]
+    pub cross_margin_asset: f64,
+    #[serde(with = "string_or_float")]
+    pub cross_margin_borrowed: f64,
+    #[serde(with = "string_or_float")]
+    pub cross_margin_free: f64,
+    #[serde(with = "string_or_float")]
+    pub cross_margin_interest: f64,
+    #[serde(with = "string_or_float")]
+    pub cross_margin_locked: f64,
+    #[serde(with = "string_or_float")]
+    pub um_wallet_balance: f64,
+    #[serde(rename = "umUnrealizedPNL", with = "string_or_float")]
+    pub um_unrealized_pnl: f64,
+    #[serde(with = "string_or_float")]
+    pub cm_wallet_balance: f64,
+    #[serde(rename = "cmUnrealizedPNL", with = "string_or_float")]
+    pub cm_unrealized_pnl: f64,
+    pub update_time: u64,
+    #[serde(with = "string_or_float")]
+    pub negative_balance: f64,
+}
+
+/// This struct acts as a gateway for all Portfolio Margin Pro account endpoints.
+/// Preferably use the trait [`crate::api::Binance`] to get an instance.
+#[derive(Clone)]
+pub struct PortfolioMargin {
+    pub client: Client,
+    pub recv_window: u64,
+}
+
+impl PortfolioMargin {
+    /// Equity, maintenance margin and status of the account
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, portfolio_margin::*, config::*};
+    /// let portfolio_margin: PortfolioMargin = Binance::new_with_env(&Config::testnet());
+    /// let account = tokio_test::block_on(portfolio_margin.account());
+    /// assert!(account.is_ok(), "{:?}", account);
+    /// ```
+    pub async fn account(&self) -> Result<PortfolioMarginAccount> {
+        self.client
+            .get_signed_p(SAPI_V1_PORTFOLIO_ACCOUNT, Option::<String>::None, self.recv_window)
+            .await
+    }
+
+    /// Collateral rate of every asset
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, portfolio_margin::*, config::*};
+    /// let portfolio_margin: PortfolioMargin = Binance::new_with_env(&Config::testnet());
+    /// let rates = tokio_test::block_on(portfolio_margin.collateral_rates());
+    /// assert!(rates.is_ok(), "{:?}", rates);
+    /// ```
+    pub async fn collateral_rates(&self) -> Result<Vec<CollateralRate>> {
+        self.client
+            .get_signed_p(
+                SAPI_V1_PORTFOLIO_COLLATERAL_RATE,
+                Option::<String>::None,
+                self.recv_window,
+            )
+            .await
+    }
+
+    /// Loan taken after the account went bankrupt, if any
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, portfolio_margin::*, config::*};
+    /// let portfolio_margin: PortfolioMargin = Binance::new_with_env(&Config::testnet());
+    /// let loan = tokio_test::block_on(portfolio_margin.bankruptcy_loan());
+    /// assert!(loan.is_ok(), "{:?}", loan);
+    /// ```
+    pub async fn bankruptcy_loan(&self) -> Result<BankruptcyLoan> {
+        self.client
+            .get_signed_p(SAPI_V1_PORTFOLIO_PM_LOAN, Option::<String>::None, self.recv_window)
+            .await
+    }
+
+    /// Repay the bankruptcy loan, from the spot account by default
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, portfolio_margin::*, config::*};
+    /// let portfolio_margin: PortfolioMargin = Binance::new_with_env(&Config::testnet());
+    /// let repay = tokio_test::block_on(portfolio_margin.repay_bankruptcy_loan(Some(RepaySource::Spot)));
+    /// assert!(repay.is_ok(), "{:?}", repay);
+    /// ```
+    pub async fn repay_bankruptcy_loan(&self, from: Option<RepaySource>) -> Result<BankruptcyLoanRepay> {
+        let mut params = HashMap::new();
+        if let Some(from) = from {
+            params.insert("from", from);
+        }
+        self.client
+            .post_signed_p(SAPI_V1_PORTFOLIO_REPAY, Some(params), self.recv_window)
+            .await
+    }
+
+    /// Balances of the account, of all assets if `asset` is None
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, portfolio_margin::*, config::*};
+    /// let portfolio_margin: PortfolioMargin = Binance::new_with_env(&Config::testnet());
+    /// let balances = tokio_test::block_on(portfolio_margin.balances(None));
+    /// assert!(balances.is_ok(), "{:?}", balances);
+    /// ```
+    pub async fn balances(&self, asset: Option<String>) -> Result<Vec<PortfolioMarginBalance>> {
+        let mut params = HashMap::new();
+        if let Some(asset) = asset {
+            params.insert("asset", asset);
+        }
+        self.client
+            .get_signed_p(SAPI_V1_PORTFOLIO_BALANCE, Some(params), self.recv_window)
+            .await
+    }
+}
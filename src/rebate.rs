@@ -0,0 +1,108 @@
+//! Spot commission rebate and referral kickback history.
+
+use crate::client::*;
+use crate::errors::*;
+use crate::rest_model::string_or_float;
+
+static SAPI_V1_REBATE_TAX_QUERY: &str = "/sapi/v1/rebate/taxQuery";
+
+/// Rebate endpoints wrap their data in an envelope with a status
+#[derive(Deserialize)]
+struct RebateResponse<T> {
+    status: String,
+    code: String,
+    data: Option<T>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct RebateHistoryQuery {
+    /// Default: 7 days before end time, the period cannot exceed 7 days
+    pub start_time: Option<u64>,
+    /// Default: now
+    pub end_time: Option<u64>,
+    /// Page number, starting at 1
+    pub page: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RebateHistory {
+    pub page: u64,
+    pub total_records: u64,
+    pub total_page_num: u64,
+    #[serde(default)]
+    pub data: Vec<Rebate>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(from = "u8", into = "u8")]
+pub enum RebateType {
+    CommissionRebate,
+    ReferralKickback,
+    Other(u8),
+}
+
+impl From<u8> for RebateType {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => RebateType::CommissionRebate,
+            2 => RebateType::ReferralKickback,
+            other => RebateType::Other(other),
+        }
+    }
+}
+
+impl From<RebateType> for u8 {
+    fn from(value: RebateType) -> Self {
+        match value {
+            RebateType::CommissionRebate => 1,
+            RebateType::ReferralKickback => 2,
+            RebateType::Other(other) => other,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Rebate {
+    pub asset: String,
+    #[serde(rename = "type")]
+    pub rebate_type: RebateType,
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    pub update_time: u64,
+}
+
+/// This struct acts as a gateway for all rebate endpoints.
+/// Preferably use the trait [`crate::api::Binance`] to get an instance.
+#[derive(Clone)]
+pub struct Rebates {
+    pub client: Client,
+    pub recv_window: u64,
+}
+
+impl Rebates {
+    /// Spot rebate history, one page of records over at most 7 days
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, rebate::*, config::*};
+    /// let rebates: Rebates = Binance::new_with_env(&Config::testnet());
+    /// let history = tokio_test::block_on(rebates.spot_rebate_history(RebateHistoryQuery::default()));
+    /// assert!(history.is_ok(), "{:?}", history);
+    /// ```
+    pub async fn spot_rebate_history(&self, query: RebateHistoryQuery) -> Result<RebateHistory> {
+        let response: RebateResponse<RebateHistory> = self
+            .client
+            .get_signed_p(SAPI_V1_REBATE_TAX_QUERY, Some(query), self.recv_window)
+            .await?;
+        match response.data {
+            Some(history) if response.status == "OK" => Ok(history),
+            _ => Err(Error::Msg(format!(
+                "code: {}, status: {}",
+                response.code, response.status
+            ))),
+        }
+    }
+}
@@ -0,0 +1,102 @@
+//! Aggregation of klines into larger intervals than the ones provided by Binance (e.g. 1m into 7m or 2h).
+
+use crate::errors::*;
+use crate::rest_model::KlineSummary;
+
+/// How to handle intervals without any source kline
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GapFill {
+    /// Omit intervals without trading
+    #[default]
+    Skip,
+    /// Emit a flat kline at the previous close with no volume
+    CarryForward,
+}
+
+/// Aggregate `klines`, sorted by open time, into klines of `interval_ms` milliseconds.
+///
+/// Resampled klines are aligned on multiples of the interval since the unix epoch, like the Binance ones.
+/// Open is the first open, close the last close, high and low the extremes,
+/// volumes and number of trades are summed.
+///
+/// # Examples
+/// ```rust
+/// use binance::{resample::*, rest_model::KlineSummary};
+/// let minute = |i: i64, close: f64| KlineSummary {
+///     open_time: i * 60_000,
+///     open: 1.0,
+///     high: close,
+///     low: 1.0,
+///     close,
+///     volume: 1.0,
+///     close_time: (i + 1) * 60_000 - 1,
+///     quote_asset_volume: close,
+///     number_of_trades: 1,
+///     taker_buy_base_asset_volume: 0.0,
+///     taker_buy_quote_asset_volume: 0.0,
+/// };
+/// let klines: Vec<KlineSummary> = (0..14).map(|i| minute(i, i as f64 + 1.0)).collect();
+/// let resampled = resample(&klines, 7 * 60_000, GapFill::Skip).unwrap();
+/// assert_eq!(resampled.len(), 2);
+/// assert_eq!(resampled[1].close, 14.0);
+/// assert_eq!(resampled[1].volume, 7.0);
+/// ```
+pub fn resample(klines: &[KlineSummary], interval_ms: i64, gaps: GapFill) -> Result<Vec<KlineSummary>> {
+    if interval_ms <= 0 {
+        return Err(Error::InvalidPeriod(format!("{interval_ms}ms")));
+    }
+    let mut resampled: Vec<KlineSummary> = vec![];
+    for kline in klines {
+        let open_time = kline.open_time - kline.open_time.rem_euclid(interval_ms);
+        match resampled.last_mut() {
+            Some(last) if last.open_time == open_time => {
+                last.high = last.high.max(kline.high);
+                last.low = last.low.min(kline.low);
+                last.close = kline.close;
+                last.volume += kline.volume;
+                last.quote_asset_volume += kline.quote_asset_volume;
+                last.number_of_trades += kline.number_of_trades;
+                last.taker_buy_base_asset_volume += kline.taker_buy_base_asset_volume;
+                last.taker_buy_quote_asset_volume += kline.taker_buy_quote_asset_volume;
+            }
+            Some(last) if last.open_time > open_time => {
+                return Err(Error::Msg(format!(
+                    "klines are not sorted by open time, {} comes after {}",
+                    kline.open_time, last.open_time
+                )));
+            }
+            last => {
+                if let (Some(last), GapFill::CarryForward) = (last, gaps) {
+                    let close = last.close;
+                    let mut gap_open_time = last.open_time + interval_ms;
+                    while gap_open_time < open_time {
+                        resampled.push(flat_kline(gap_open_time, interval_ms, close));
+                        gap_open_time += interval_ms;
+                    }
+                }
+                resampled.push(KlineSummary {
+                    open_time,
+                    close_time: open_time + interval_ms - 1,
+                    ..kline.clone()
+                });
+            }
+        }
+    }
+    Ok(resampled)
+}
+
+fn flat_kline(open_time: i64, interval_ms: i64, price: f64) -> KlineSummary {
+    KlineSummary {
+        open_time,
+        open: price,
+        high: price,
+        low: price,
+        close: price,
+        volume: 0.0,
+        close_time: open_time + interval_ms - 1,
+        quote_asset_volume: 0.0,
+        number_of_trades: 0,
+        taker_buy_base_asset_volume: 0.0,
+        taker_buy_quote_asset_volume: 0.0,
+    }
+}
@@ -1,6 +1,11 @@
 use chrono::{DateTime, Utc};
 use serde_repr::{Deserialize_repr, Serialize_repr};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+
+pub use crate::util::serde_helpers::{
+    string_or_bool, string_or_float, string_or_float_opt, string_or_u64, string_or_u64_opt,
+};
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
@@ -38,6 +43,9 @@ pub struct Symbol {
     pub is_margin_trading_allowed: bool,
     pub filters: Vec<Filters>,
     pub permissions: Vec<SymbolPermission>,
+    /// Only returned when permission sets are requested, see [`ExchangeInfoQuery`]
+    #[serde(default)]
+    pub permission_sets: Vec<Vec<SymbolPermission>>,
 }
 
 impl Symbol {
@@ -106,6 +114,17 @@ pub enum Filters {
         apply_to_market: bool,
         avg_price_mins: u64,
     },
+    #[serde(rename = "NOTIONAL")]
+    #[serde(rename_all = "camelCase")]
+    Notional {
+        #[serde(with = "string_or_float")]
+        min_notional: f64,
+        apply_min_to_market: bool,
+        #[serde(with = "string_or_float")]
+        max_notional: f64,
+        apply_max_to_market: bool,
+        avg_price_mins: u64,
+    },
     #[serde(rename = "ICEBERG_PARTS")]
     #[serde(rename_all = "camelCase")]
     IcebergParts { limit: u16 },
@@ -134,6 +153,18 @@ pub enum Filters {
     Others,
 }
 
+/// Restricts the exchange information to a subset of symbols
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ExchangeInfoQuery {
+    /// Only return these symbols, cannot be combined with permissions
+    pub symbols: Vec<String>,
+    /// Only return symbols that have any of these permissions
+    pub permissions: Vec<SymbolPermission>,
+    /// Whether to return the permission sets of each symbol, Binance defaults to true
+    pub show_permission_sets: Option<bool>,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct AccountInformation {
@@ -292,22 +323,51 @@ pub struct OrderBook {
     pub asks: Vec<Asks>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
-pub struct Bids {
-    #[serde(with = "string_or_float")]
-    pub price: f64,
-    #[serde(with = "string_or_float")]
-    pub qty: f64,
+impl OrderBook {
+    /// Highest bid
+    pub fn best_bid(&self) -> Option<&Level> { self.bids.first() }
+
+    /// Lowest ask
+    pub fn best_ask(&self) -> Option<&Level> { self.asks.first() }
+
+    /// Price halfway between the best bid and the best ask
+    pub fn mid_price(&self) -> Option<f64> { mid_price(&self.bids, &self.asks) }
+
+    /// Volume weighted average price of the `depth` first levels of both sides of the book
+    pub fn vwap(&self, depth: usize) -> Option<f64> {
+        vwap(self.bids.iter().take(depth).chain(self.asks.iter().take(depth)))
+    }
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
-pub struct Asks {
+/// A price level of an order book, deserialized from the `[price, qty]` arrays sent by Binance
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Level {
     #[serde(with = "string_or_float")]
     pub price: f64,
     #[serde(with = "string_or_float")]
     pub qty: f64,
 }
 
+pub type Bids = Level;
+
+pub type Asks = Level;
+
+/// Price halfway between the first bid and the first ask
+pub fn mid_price(bids: &[Level], asks: &[Level]) -> Option<f64> {
+    match (bids.first(), asks.first()) {
+        (Some(bid), Some(ask)) => Some((bid.price + ask.price) / 2.0),
+        _ => None,
+    }
+}
+
+/// Volume weighted average price of levels, None if there is no volume
+pub fn vwap<'a>(levels: impl IntoIterator<Item = &'a Level>) -> Option<f64> {
+    let (notional, qty) = levels.into_iter().fold((0.0, 0.0), |(notional, qty), level| {
+        (notional + level.price * level.qty, qty + level.qty)
+    });
+    (qty > 0.0).then(|| notional / qty)
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct UserDataStream {
@@ -350,6 +410,83 @@ pub enum KlineSummaries {
     AllKlineSummaries(Vec<KlineSummary>),
 }
 
+/// Interval of klines, shared by the REST endpoints and the kline streams
+///
+/// # Examples
+/// ```rust
+/// use binance::rest_model::Interval;
+/// assert_eq!(Interval::FifteenMinutes.as_str(), "15m");
+/// assert_eq!(Interval::OneMonth.to_string(), "1M");
+/// assert_eq!(serde_json::to_string(&Interval::OneHour).unwrap(), r#""1h""#);
+/// ```
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Interval {
+    #[serde(rename = "1s")]
+    OneSecond,
+    #[serde(rename = "1m")]
+    OneMinute,
+    #[serde(rename = "3m")]
+    ThreeMinutes,
+    #[serde(rename = "5m")]
+    FiveMinutes,
+    #[serde(rename = "15m")]
+    FifteenMinutes,
+    #[serde(rename = "30m")]
+    ThirtyMinutes,
+    #[serde(rename = "1h")]
+    OneHour,
+    #[serde(rename = "2h")]
+    TwoHours,
+    #[serde(rename = "4h")]
+    FourHours,
+    #[serde(rename = "6h")]
+    SixHours,
+    #[serde(rename = "8h")]
+    EightHours,
+    #[serde(rename = "12h")]
+    TwelveHours,
+    #[serde(rename = "1d")]
+    OneDay,
+    #[serde(rename = "3d")]
+    ThreeDays,
+    #[serde(rename = "1w")]
+    OneWeek,
+    #[serde(rename = "1M")]
+    OneMonth,
+}
+
+impl Interval {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Interval::OneSecond => "1s",
+            Interval::OneMinute => "1m",
+            Interval::ThreeMinutes => "3m",
+            Interval::FiveMinutes => "5m",
+            Interval::FifteenMinutes => "15m",
+            Interval::ThirtyMinutes => "30m",
+            Interval::OneHour => "1h",
+            Interval::TwoHours => "2h",
+            Interval::FourHours => "4h",
+            Interval::SixHours => "6h",
+            Interval::EightHours => "8h",
+            Interval::TwelveHours => "12h",
+            Interval::OneDay => "1d",
+            Interval::ThreeDays => "3d",
+            Interval::OneWeek => "1w",
+            Interval::OneMonth => "1M",
+        }
+    }
+}
+
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.write_str(self.as_str()) }
+}
+
+/// Lets an interval be passed to the kline endpoints, e.g. [`crate::market::Market::get_klines`]
+impl From<Interval> for String {
+    fn from(interval: Interval) -> Self { interval.as_str().to_string() }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct Tickers {
@@ -372,7 +509,10 @@ pub struct TradeHistory {
     pub price: f64,
     #[serde(with = "string_or_float")]
     pub qty: f64,
-    pub commission: String,
+    #[serde(with = "string_or_float")]
+    pub quote_qty: f64,
+    #[serde(with = "string_or_float")]
+    pub commission: f64,
     pub commission_asset: String,
     pub time: u64,
     pub is_buyer: bool,
@@ -383,9 +523,12 @@ pub struct TradeHistory {
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct PriceStats {
-    pub price_change: String,
-    pub price_change_percent: String,
-    pub weighted_avg_price: String,
+    #[serde(with = "string_or_float")]
+    pub price_change: f64,
+    #[serde(with = "string_or_float")]
+    pub price_change_percent: f64,
+    #[serde(with = "string_or_float")]
+    pub weighted_avg_price: f64,
     #[serde(with = "string_or_float")]
     pub prev_close_price: f64,
     #[serde(with = "string_or_float")]
@@ -429,6 +572,21 @@ pub struct AggTrade {
     pub qty: f64,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MarketTrade {
+    pub id: u64,
+    #[serde(with = "string_or_float")]
+    pub price: f64,
+    #[serde(with = "string_or_float")]
+    pub qty: f64,
+    #[serde(with = "string_or_float")]
+    pub quote_qty: f64,
+    pub time: u64,
+    pub is_buyer_maker: bool,
+    pub is_best_match: bool,
+}
+
 #[derive(Debug, Serialize_repr, Deserialize_repr, Clone, PartialEq, Eq)]
 #[repr(u8)]
 pub enum MarginTransferType {
@@ -981,6 +1139,37 @@ pub struct IsolatedMarginAccountAssetDetails {
     pub trade_enabled: bool,
 }
 
+impl IsolatedMarginAccountAsset {
+    /// Amount owed, borrowed plus interest
+    pub fn liability(&self) -> f64 { self.borrowed + self.interest }
+}
+
+impl IsolatedMarginAccountAssetDetails {
+    /// The base or the quote asset of the pair
+    pub fn asset(&self, asset: &str) -> Option<&IsolatedMarginAccountAsset> {
+        [&self.base_asset, &self.quote_asset]
+            .into_iter()
+            .find(|details| details.asset == asset)
+    }
+
+    /// Whether the margin level has reached a margin call or a liquidation
+    pub fn is_at_risk(&self) -> bool {
+        matches!(
+            self.margin_level_status,
+            MarginLevelStatus::MarginCall | MarginLevelStatus::PreLiquidation | MarginLevelStatus::ForceLiquidation
+        )
+    }
+
+    /// Relative distance between the index price and the liquidation price, e.g. `0.2` if the price can move by 20%,
+    /// None without a liquidation price, i.e. without liability
+    pub fn liquidation_distance(&self) -> Option<f64> {
+        if self.liquidate_price <= 0.0 || self.index_price <= 0.0 {
+            return None;
+        }
+        Some((self.index_price - self.liquidate_price).abs() / self.index_price)
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum MarginLevelStatus {
@@ -1005,6 +1194,18 @@ pub struct IsolatedMarginAccountDetails {
     pub total_net_asset_of_btc: Option<f64>,
 }
 
+impl IsolatedMarginAccountDetails {
+    /// Details of the isolated account of `symbol`
+    pub fn symbol(&self, symbol: &str) -> Option<&IsolatedMarginAccountAssetDetails> {
+        self.assets.iter().find(|details| details.symbol == symbol)
+    }
+
+    /// Isolated accounts whose margin level has reached a margin call or a liquidation
+    pub fn at_risk(&self) -> impl Iterator<Item = &IsolatedMarginAccountAssetDetails> {
+        self.assets.iter().filter(|details| details.is_at_risk())
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct AssetQuery {
@@ -1227,6 +1428,7 @@ pub enum SymbolStatus {
 pub enum SymbolPermission {
     Spot,
     Margin,
+    Leveraged,
     #[serde(other)]
     Other,
 }
@@ -1253,22 +1455,34 @@ pub enum OrderStatus {
     Trade,
 }
 
+/// `listStatusType` of an order list
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum OCOStatus {
+    /// The list status is a response to a failed action, e.g. a rejected placement or cancellation
     Response,
+    /// The list was placed or its status changed
     ExecStarted,
+    /// The list finished executing and is no longer active
     AllDone,
 }
 
+/// `listOrderStatus` of an order list
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum OCOOrderStatus {
+    /// Either the list was just placed or is being executed
     Executing,
+    /// The list finished executing and is no longer active
     AllDone,
+    /// The list was rejected, either on placement or on cancellation
     Reject,
 }
 
+pub type ListStatusType = OCOStatus;
+
+pub type ListOrderStatus = OCOOrderStatus;
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct MarginOCOOrderCancellation {
@@ -1291,7 +1505,10 @@ pub struct MarginOCOOrderQuery {
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum ContingencyType {
+    #[serde(rename = "OCO")]
     OCO,
+    #[serde(rename = "OTO")]
+    OTO,
     #[serde(other)]
     Other,
 }
@@ -1377,19 +1594,99 @@ pub type InterestRateHistory = Vec<InterestRateAssetHistory>;
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
 #[serde(rename_all = "camelCase")]
 pub struct KlineSummary {
+    /// Kline open time, in milliseconds
     pub open_time: i64,
     pub open: f64,
     pub high: f64,
     pub low: f64,
     pub close: f64,
+    /// Volume in base asset
     pub volume: f64,
+    /// Kline close time, in milliseconds
     pub close_time: i64,
+    /// Volume in quote asset
     pub quote_asset_volume: f64,
     pub number_of_trades: i64,
     pub taker_buy_base_asset_volume: f64,
     pub taker_buy_quote_asset_volume: f64,
 }
 
+/// Kline as returned by the REST api, an array of positional values.
+/// Only the first 11 values are read, the trailing ones are ignored.
+#[derive(Debug, Clone)]
+pub(crate) struct KlineSummaryRow(KlineSummary);
+
+impl<'de> serde::Deserialize<'de> for KlineSummaryRow {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(KlineSummaryRowVisitor)
+    }
+}
+
+struct KlineSummaryRowVisitor;
+
+#[derive(Deserialize)]
+struct StringOrFloat(#[serde(with = "string_or_float")] f64);
+
+impl KlineSummaryRowVisitor {
+    fn next<'de, A, T>(seq: &mut A, index: usize) -> Result<T, A::Error>
+    where
+        A: serde::de::SeqAccess<'de>,
+        T: serde::Deserialize<'de>,
+    {
+        seq.next_element()?
+            .ok_or_else(|| serde::de::Error::invalid_length(index, &KlineSummaryRowVisitor))
+    }
+
+    fn next_float<'de, A>(seq: &mut A, index: usize) -> Result<f64, A::Error>
+    where
+        A: serde::de::SeqAccess<'de>,
+    {
+        Self::next::<A, StringOrFloat>(seq, index).map(|value| value.0)
+    }
+}
+
+impl<'de> serde::de::Visitor<'de> for KlineSummaryRowVisitor {
+    type Value = KlineSummaryRow;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("an array of at least 11 kline values")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::SeqAccess<'de>,
+    {
+        let kline = KlineSummary {
+            open_time: Self::next(&mut seq, 0)?,
+            open: Self::next_float(&mut seq, 1)?,
+            high: Self::next_float(&mut seq, 2)?,
+            low: Self::next_float(&mut seq, 3)?,
+            close: Self::next_float(&mut seq, 4)?,
+            volume: Self::next_float(&mut seq, 5)?,
+            close_time: Self::next(&mut seq, 6)?,
+            quote_asset_volume: Self::next_float(&mut seq, 7)?,
+            number_of_trades: Self::next(&mut seq, 8)?,
+            taker_buy_base_asset_volume: Self::next_float(&mut seq, 9)?,
+            taker_buy_quote_asset_volume: Self::next_float(&mut seq, 10)?,
+        };
+        while seq.next_element::<serde::de::IgnoredAny>()?.is_some() {}
+        Ok(KlineSummaryRow(kline))
+    }
+}
+
+impl From<KlineSummaryRow> for KlineSummary {
+    fn from(row: KlineSummaryRow) -> Self { row.0 }
+}
+
+impl KlineSummaries {
+    pub(crate) fn from_rows(rows: Vec<KlineSummaryRow>) -> Self {
+        KlineSummaries::AllKlineSummaries(rows.into_iter().map(KlineSummary::from).collect())
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct PropertyCmd {
     pub id: i32,
@@ -1404,6 +1701,49 @@ pub struct IsolatedAccountLimit {
     pub max_account: u64,
 }
 
+/// Loan-to-value of an institutional loan group
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LoanGroupLtv {
+    pub group_id: String,
+    #[serde(rename = "currentLTV", with = "string_or_float")]
+    pub current_ltv: f64,
+    /// A margin call is issued above this LTV
+    #[serde(rename = "marginCallLTV", default, with = "string_or_float_opt")]
+    pub margin_call_ltv: Option<f64>,
+    /// The group is liquidated above this LTV
+    #[serde(rename = "liquidationLTV", default, with = "string_or_float_opt")]
+    pub liquidation_ltv: Option<f64>,
+    pub update_time: Option<u64>,
+}
+
+/// Borrowed and collateral assets of an institutional loan group
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LoanGroupDetails {
+    pub group_id: String,
+    #[serde(rename = "currentLTV", with = "string_or_float")]
+    pub current_ltv: f64,
+    #[serde(default)]
+    pub loan_assets: Vec<LoanGroupAsset>,
+    #[serde(default)]
+    pub collateral_assets: Vec<LoanGroupAsset>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LoanGroupAsset {
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    /// Value in USDT
+    #[serde(default, with = "string_or_float_opt")]
+    pub value: Option<f64>,
+    /// Accrued interest, only for loan assets
+    #[serde(default, with = "string_or_float_opt")]
+    pub interest: Option<f64>,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct IsolatedSymbol {
@@ -1499,6 +1839,118 @@ pub struct SnapshotVosData {
     pub total_asset_of_btc: f64,
 }
 
+impl AccountSnapshot {
+    /// The most recent snapshot
+    pub fn latest(&self) -> Option<&SnapshotVos> { self.snapshot_vos.iter().max_by_key(|vos| vos.update_time) }
+
+    /// Changes from the latest spot snapshot of `self` to the latest spot snapshot of `later`,
+    /// None if one of them has no snapshot
+    ///
+    /// # Examples
+    /// ```rust
+    /// use binance::rest_model::*;
+    /// let snapshot = |update_time, btc, usdt, total_asset_of_btc| AccountSnapshot {
+    ///     code: 200,
+    ///     msg: String::new(),
+    ///     snapshot_vos: vec![SnapshotVos {
+    ///         data: SnapshotVosData {
+    ///             balances: vec![
+    ///                 Balance { asset: "BTC".into(), free: btc, locked: 0.0 },
+    ///                 Balance { asset: "USDT".into(), free: usdt, locked: 0.0 },
+    ///             ],
+    ///             total_asset_of_btc,
+    ///         },
+    ///         snapshot_type: "spot".into(),
+    ///         update_time,
+    ///     }],
+    /// };
+    /// let yesterday = snapshot(1_000, 1.0, 0.0, 1.0);
+    /// let today = snapshot(2_000, 0.5, 15_000.0, 1.0);
+    /// let diff = yesterday.diff(&today).unwrap();
+    /// assert_eq!(diff.asset("BTC").unwrap().change(), -0.5);
+    /// assert_eq!(diff.asset("USDT").unwrap().change(), 15_000.0);
+    /// let prices = [("BTC".to_string(), 30_000.0), ("USDT".to_string(), 1.0)].into_iter().collect();
+    /// assert_eq!(diff.value_change(&prices), 0.0);
+    /// ```
+    pub fn diff(&self, later: &AccountSnapshot) -> Option<SnapshotDiff> { Some(self.latest()?.diff(later.latest()?)) }
+}
+
+impl SnapshotVos {
+    /// Changes from this snapshot to `later`
+    pub fn diff(&self, later: &SnapshotVos) -> SnapshotDiff {
+        let mut assets: BTreeMap<&str, AssetDelta> = BTreeMap::new();
+        let delta = |asset: &str| AssetDelta {
+            asset: asset.to_string(),
+            ..AssetDelta::default()
+        };
+        for balance in &self.data.balances {
+            let delta = assets.entry(&balance.asset).or_insert_with(|| delta(&balance.asset));
+            delta.free_before += balance.free;
+            delta.locked_before += balance.locked;
+        }
+        for balance in &later.data.balances {
+            let delta = assets.entry(&balance.asset).or_insert_with(|| delta(&balance.asset));
+            delta.free_after += balance.free;
+            delta.locked_after += balance.locked;
+        }
+        SnapshotDiff {
+            from_time: self.update_time,
+            to_time: later.update_time,
+            total_asset_of_btc_before: self.data.total_asset_of_btc,
+            total_asset_of_btc_after: later.data.total_asset_of_btc,
+            assets: assets.into_values().collect(),
+        }
+    }
+}
+
+/// Balance of an asset in two snapshots
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct AssetDelta {
+    pub asset: String,
+    pub free_before: f64,
+    pub free_after: f64,
+    pub locked_before: f64,
+    pub locked_after: f64,
+}
+
+impl AssetDelta {
+    pub fn total_before(&self) -> f64 { self.free_before + self.locked_before }
+
+    pub fn total_after(&self) -> f64 { self.free_after + self.locked_after }
+
+    pub fn change(&self) -> f64 { self.total_after() - self.total_before() }
+}
+
+/// Changes between two account snapshots, with the assets sorted by name
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct SnapshotDiff {
+    pub from_time: u64,
+    pub to_time: u64,
+    pub total_asset_of_btc_before: f64,
+    pub total_asset_of_btc_after: f64,
+    /// Every asset of either snapshot
+    pub assets: Vec<AssetDelta>,
+}
+
+impl SnapshotDiff {
+    pub fn total_asset_of_btc_change(&self) -> f64 { self.total_asset_of_btc_after - self.total_asset_of_btc_before }
+
+    pub fn asset(&self, asset: &str) -> Option<&AssetDelta> { self.assets.iter().find(|delta| delta.asset == asset) }
+
+    /// Assets whose balance changed
+    pub fn changed(&self) -> impl Iterator<Item = &AssetDelta> {
+        self.assets.iter().filter(|delta| delta.change() != 0.0)
+    }
+
+    /// Change of the value of the assets at `prices`, keyed by asset, assets without a price are not counted
+    pub fn value_change(&self, prices: &HashMap<String, f64>) -> f64 {
+        self.assets
+            .iter()
+            .filter_map(|delta| prices.get(&delta.asset).map(|price| delta.change() * price))
+            .sum()
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum AccountSnapshotType {
@@ -1545,14 +1997,14 @@ pub struct LoanResponse {
 pub struct LoanInfo {
     #[serde(rename = "loanCoin")]
     pub loan_coin: String,
-    #[serde(rename = "totalDebt")]
-    pub total_debt: String,
+    #[serde(rename = "totalDebt", with = "string_or_float")]
+    pub total_debt: f64,
     #[serde(rename = "collateralCoin")]
     pub collateral_coin: String,
-    #[serde(rename = "collateralAmount")]
-    pub collateral_amount: String,
-    #[serde(rename = "currentLTV")]
-    pub current_ltv: String,
+    #[serde(rename = "collateralAmount", with = "string_or_float")]
+    pub collateral_amount: f64,
+    #[serde(rename = "currentLTV", with = "string_or_float")]
+    pub current_ltv: f64,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -1600,8 +2052,10 @@ pub struct DepositHistoryQuery {
     pub end_time: Option<u64>,
     /// Default:1000, Max:1000
     pub limit: Option<u64>,
-    /// Default: present timestamp
+    /// Default: 0
     pub offset: Option<u64>,
+    /// Only return the deposit with this transaction id
+    pub tx_id: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
@@ -1671,7 +2125,6 @@ pub struct WithdrawalRecord {
     pub tx_id: Option<String>,
 }
 
-#[cfg(feature = "wallet_api")]
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct DepositAddressQuery {
@@ -1679,17 +2132,39 @@ pub struct DepositAddressQuery {
     /// If network is not send, return with default network of the coin.
     /// You can get network and isDefault in networkList in the response [`crate::wallet::Wallet::all_coin_info`]
     pub network: Option<String>,
+    /// Amount to deposit, mandatory for the networks issuing an invoice per deposit, e.g. LIGHTNING
+    pub amount: Option<f64>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct DepositAddress {
     pub coin: String,
+    /// The invoice of the requested amount on the Lightning network
     pub address: String,
     pub tag: Option<String>,
     pub url: Option<String>,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct DepositAddressListQuery {
+    pub coin: String,
+    /// Only return the addresses of this network
+    pub network: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct NetworkDepositAddress {
+    pub coin: String,
+    pub address: String,
+    pub tag: Option<String>,
+    /// Whether this is the address of the default network of the coin
+    #[serde(with = "string_or_bool")]
+    pub is_default: bool,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, Default)]
 #[serde(rename_all = "camelCase")]
 pub struct FeeInformation {
@@ -1764,6 +2239,24 @@ pub enum UniversalTransferType {
     CmfutureFunding,
 }
 
+impl UniversalTransferType {
+    /// Whether the isolated margin symbol to transfer from must be sent
+    pub fn requires_from_symbol(&self) -> bool {
+        matches!(
+            self,
+            UniversalTransferType::IsolatedmarginMargin | UniversalTransferType::IsolatedmarginIsolatedmargin
+        )
+    }
+
+    /// Whether the isolated margin symbol to transfer to must be sent
+    pub fn requires_to_symbol(&self) -> bool {
+        matches!(
+            self,
+            UniversalTransferType::MarginIsolatedmargin | UniversalTransferType::IsolatedmarginIsolatedmargin
+        )
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct UniversalTransfer {
@@ -1797,8 +2290,10 @@ pub struct FlexibleLoanAdjustLTVResponse {
     pub loan_coin: String,
     pub collateral_coin: String,
     pub direction: String,
-    pub amount: String,
-    pub current_ltv: String,
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    #[serde(with = "string_or_float")]
+    pub current_ltv: f64,
     pub status: String,
 }
 
@@ -1863,6 +2358,18 @@ pub struct AccountStatus {
     pub data: String,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountInfo {
+    pub vip_level: u8,
+    pub is_margin_enabled: bool,
+    pub is_future_enabled: bool,
+    #[serde(default)]
+    pub is_options_enabled: bool,
+    #[serde(default)]
+    pub is_portfolio_margin_retail_enabled: bool,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct ApiTradingStatus {
@@ -1971,6 +2478,19 @@ pub struct DustTransfer {
     pub transfer_result: Vec<DustTransferResult>,
 }
 
+/// Outcome of converting all the dust of an account, see `Wallet::dust_transfer_all`
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct DustTransferReport {
+    #[serde(with = "string_or_float")]
+    pub total_service_charge: f64,
+    #[serde(with = "string_or_float")]
+    pub total_transferred: f64,
+    pub transfer_result: Vec<DustTransferResult>,
+    /// Convertible assets worth less than the minimum value, which were not converted
+    pub skipped: Vec<String>,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct DustTransferResult {
@@ -2059,157 +2579,241 @@ pub struct WalletFunding {
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
-pub struct ApiKeyPermissions {
-    ip_restrict: bool,
-    create_time: u64,
-    /// This option allows you to withdraw via API. You must apply the IP Access Restriction filter in order to enable withdrawals
-    enable_withdrawals: bool,
-    /// This option authorizes this key to transfer funds between your master account and your sub account instantly
-    enable_internal_transfer: bool,
-    /// Authorizes this key to be used for a dedicated universal transfer API to transfer multiple supported currencies. Each business's own transfer API rights are not affected by this authorization
-    permits_universal_transfer: bool,
-    ///  Authorizes this key to Vanilla options trading
-    enable_vanilla_options: bool,
-    enable_reading: bool,
-    ///  API Key created before your futures account opened does not support futures API service
-    enable_futures: bool,
-    ///  This option can be adjusted after the Cross Margin account transfer is completed
-    enable_margin: bool,
-    /// Spot and margin trading
-    enable_spot_and_margin_trading: bool,
-    /// Expiration time for spot and margin trading permission
-    trading_authority_expiration_time: Option<u64>,
+pub struct WalletUserAsset {
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub free: f64,
+    #[serde(with = "string_or_float")]
+    pub locked: f64,
+    #[serde(with = "string_or_float")]
+    pub freeze: f64,
+    #[serde(with = "string_or_float")]
+    pub withdrawing: f64,
+    #[serde(with = "string_or_float")]
+    pub ipoable: f64,
+    /// Zero unless the btc valuation is requested
+    #[serde(with = "string_or_float")]
+    pub btc_valuation: f64,
 }
 
-pub mod string_or_float {
-    use std::fmt;
-
-    use serde::{de, Deserialize, Deserializer, Serializer};
-
-    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        T: fmt::Display,
-        S: Serializer,
-    {
-        serializer.collect_str(value)
-    }
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct WalletBalance {
+    /// Whether the wallet is activated
+    pub activate: bool,
+    /// Valuation of the wallet in the requested quote asset, BTC by default
+    #[serde(with = "string_or_float")]
+    pub balance: f64,
+    /// Spot, Funding, Cross Margin, Isolated Margin, USDⓈ-M Futures, COIN-M Futures, Earn...
+    pub wallet_name: String,
+}
 
-    pub fn deserialize<'de, D>(deserializer: D) -> Result<f64, D::Error>
-    where
-        D: Deserializer<'de>,
-    {
-        #[derive(Deserialize)]
-        #[serde(untagged)]
-        enum StringOrFloat {
-            String(String),
-            Float(f64),
-        }
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ConvertibleCoins {
+    /// Whether the automatic conversion of deposits is enabled
+    pub convert_enabled: bool,
+    pub coins: Vec<String>,
+    #[serde(default)]
+    pub exchange_rates: HashMap<String, String>,
+}
 
-        match StringOrFloat::deserialize(deserializer)? {
-            StringOrFloat::String(s) => s.parse().map_err(de::Error::custom),
-            StringOrFloat::Float(i) => Ok(i),
-        }
-    }
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ConvertTransferQuery {
+    /// User defined id, must be unique
+    pub client_tran_id: String,
+    pub asset: String,
+    pub amount: f64,
+    pub target_asset: String,
+    /// MAIN (default) or CARD
+    pub account_type: Option<String>,
 }
 
-pub(crate) mod string_or_float_opt {
-    use std::fmt;
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ConvertTransferResponse {
+    pub tran_id: u64,
+    /// S for success
+    pub status: String,
+}
 
-    use serde::{Deserialize, Deserializer, Serializer};
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ConvertTransferHistoryQuery {
+    pub tran_id: Option<u64>,
+    pub client_tran_id: Option<String>,
+    pub asset: Option<String>,
+    pub start_time: u64,
+    pub end_time: u64,
+    /// MAIN or CARD
+    pub account_type: Option<String>,
+    /// Page, starting at 1
+    pub current: Option<u64>,
+    /// Default 10, max 100
+    pub size: Option<u64>,
+}
 
-    pub fn serialize<T, S>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        T: fmt::Display,
-        S: Serializer,
-    {
-        match value {
-            Some(v) => crate::rest_model::string_or_float::serialize(v, serializer),
-            None => serializer.serialize_none(),
-        }
-    }
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ConvertTransferRecord {
+    pub tran_id: u64,
+    #[serde(rename = "type")]
+    pub transfer_type: u64,
+    pub time: u64,
+    pub account_type: String,
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    pub target_asset: String,
+    #[serde(with = "string_or_float")]
+    pub target_amount: f64,
+    pub status: String,
+}
 
-    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<f64>, D::Error>
-    where
-        D: Deserializer<'de>,
-    {
-        #[derive(Deserialize)]
-        #[serde(untagged)]
-        enum StringOrFloat {
-            String(String),
-            Float(f64),
-        }
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct CloudMiningHistoryQuery {
+    pub tran_id: Option<u64>,
+    pub client_tran_id: Option<String>,
+    pub asset: Option<String>,
+    pub start_time: u64,
+    pub end_time: u64,
+    /// Page, starting at 1
+    pub current: Option<u64>,
+    /// Default 10, max 100
+    pub size: Option<u64>,
+}
 
-        Ok(Some(crate::rest_model::string_or_float::deserialize(deserializer)?))
-    }
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CloudMiningRecord {
+    pub create_time: u64,
+    pub tran_id: u64,
+    /// 248 for a payment, 249 for a refund
+    #[serde(rename = "type")]
+    pub record_type: u64,
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    /// S for success
+    pub status: String,
 }
 
-pub mod string_or_u64 {
-    use std::fmt;
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub enum DelegationType {
+    Delegate,
+    Undelegate,
+}
 
-    use serde::{de, Deserialize, Deserializer, Serializer};
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct DelegationHistoryQuery {
+    pub email: String,
+    pub start_time: u64,
+    pub end_time: u64,
+    #[serde(rename = "type")]
+    pub delegation_type: Option<DelegationType>,
+    pub asset: Option<String>,
+    /// Page, starting at 1
+    pub current: Option<u64>,
+    /// Default 10, max 100
+    pub size: Option<u64>,
+}
 
-    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        T: fmt::Display,
-        S: Serializer,
-    {
-        serializer.collect_str(value)
-    }
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DelegationRecord {
+    pub client_tran_id: String,
+    pub transfer_type: DelegationType,
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    pub time: u64,
+}
 
-    pub fn deserialize<'de, D>(deserializer: D) -> Result<u64, D::Error>
-    where
-        D: Deserializer<'de>,
-    {
-        #[derive(Deserialize)]
-        #[serde(untagged)]
-        enum StringOrU64 {
-            String(String),
-            U64(u64),
-        }
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DelistSchedule {
+    pub delist_time: u64,
+    pub symbols: Vec<String>,
+}
 
-        match StringOrU64::deserialize(deserializer)? {
-            StringOrU64::String(s) => s.parse().map_err(de::Error::custom),
-            StringOrU64::U64(i) => Ok(i),
-        }
-    }
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SymbolOpenSchedule {
+    pub open_time: u64,
+    pub symbols: Vec<String>,
 }
 
-pub(crate) mod string_or_bool {
-    use std::fmt;
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct WithdrawQuota {
+    /// Withdrawal limit over 24h, in USD
+    #[serde(with = "string_or_float")]
+    pub wd_quota: f64,
+    /// Amount withdrawn over the last 24h, in USD
+    #[serde(with = "string_or_float")]
+    pub used_wd_quota: f64,
+}
 
-    use serde::{de, Deserialize, Deserializer, Serializer};
+impl WithdrawQuota {
+    /// Amount that can still be withdrawn, in USD
+    pub fn remaining(&self) -> f64 { (self.wd_quota - self.used_wd_quota).max(0.0) }
+}
 
-    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        T: fmt::Display,
-        S: Serializer,
-    {
-        serializer.collect_str(value)
-    }
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct DepositCreditApplyQuery {
+    /// Deposit record id, takes priority over `tx_id`
+    pub deposit_id: Option<u64>,
+    pub tx_id: Option<String>,
+    pub sub_account_id: Option<u64>,
+    pub sub_user_id: Option<u64>,
+}
 
-    pub fn deserialize<'de, D>(deserializer: D) -> Result<bool, D::Error>
-    where
-        D: Deserializer<'de>,
-    {
-        #[derive(Deserialize)]
-        #[serde(untagged)]
-        enum StringOrFloat {
-            String(String),
-            Bool(bool),
-        }
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DepositCreditApplyResponse {
+    pub code: String,
+    pub message: String,
+    /// Whether the deposit was credited
+    pub data: bool,
+    pub success: bool,
+}
 
-        match StringOrFloat::deserialize(deserializer)? {
-            StringOrFloat::String(s) => s.parse().map_err(de::Error::custom),
-            StringOrFloat::Bool(i) => Ok(i),
-        }
-    }
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiKeyPermissions {
+    pub ip_restrict: bool,
+    pub create_time: u64,
+    /// This option allows you to withdraw via API. You must apply the IP Access Restriction filter in order to enable withdrawals
+    pub enable_withdrawals: bool,
+    /// This option authorizes this key to transfer funds between your master account and your sub account instantly
+    pub enable_internal_transfer: bool,
+    /// Authorizes this key to be used for a dedicated universal transfer API to transfer multiple supported currencies. Each business's own transfer API rights are not affected by this authorization
+    pub permits_universal_transfer: bool,
+    ///  Authorizes this key to Vanilla options trading
+    pub enable_vanilla_options: bool,
+    pub enable_reading: bool,
+    ///  API Key created before your futures account opened does not support futures API service
+    pub enable_futures: bool,
+    ///  This option can be adjusted after the Cross Margin account transfer is completed
+    pub enable_margin: bool,
+    /// Spot and margin trading
+    pub enable_spot_and_margin_trading: bool,
+    /// Expiration time for spot and margin trading permission
+    pub trading_authority_expiration_time: Option<u64>,
 }
 
 #[cfg(test)]
 mod test {
     use std::path::PathBuf;
 
-    use crate::rest_model::ExchangeInformation;
+    use crate::rest_model::{ContingencyType, ExchangeInformation, IsolatedMarginAccountDetails, KlineSummaries,
+                            KlineSummaryRow, Level, ListOrderStatus, ListStatusType, MarginLevelStatus, OrderBook,
+                            TradeHistory};
+    use crate::ws_model::WebsocketEvent;
 
     #[test]
     fn exchange_info_serde() {
@@ -2219,4 +2823,98 @@ mod test {
         let result = serde_json::from_str::<ExchangeInformation>(&fc);
         assert!(result.is_ok(), "{result:?}");
     }
+
+    #[test]
+    fn kline_rows_serde() {
+        let rows = r#"[[1499040000000, "0.01634790", "0.80000000", "0.01575800", "0.01577100", "148976.11427815",
+            1499644799999, "2434.19055334", 308, "1756.87402397", "28.46694368", "0"]]"#;
+        let rows = serde_json::from_str::<Vec<KlineSummaryRow>>(rows).unwrap();
+        let KlineSummaries::AllKlineSummaries(klines) = KlineSummaries::from_rows(rows);
+        assert_eq!(klines[0].open_time, 1499040000000);
+        assert_eq!(klines[0].close, 0.015771);
+        assert_eq!(klines[0].close_time, 1499644799999);
+        assert_eq!(klines[0].number_of_trades, 308);
+        assert_eq!(klines[0].taker_buy_quote_asset_volume, 28.46694368);
+
+        let rows = r#"[[1499040000000, "0.01634790", "0.80000000", "0.01575800", "0.01577100", "148976.11427815",
+            1499644799999, "2434.19055334", 308, "1756.87402397", "28.46694368"],
+            [1499644800000, "0.01577100", "0.01600000", "0.01560000", "0.01590000", "1000.0", 1500249599999,
+            "15.9", 12, "500.0", "7.95", "0", "extra"]]"#;
+        let rows = serde_json::from_str::<Vec<KlineSummaryRow>>(rows).unwrap();
+        let KlineSummaries::AllKlineSummaries(klines) = KlineSummaries::from_rows(rows);
+        assert_eq!(klines.len(), 2);
+        assert_eq!(klines[1].number_of_trades, 12);
+
+        let short = r#"[[1499040000000, "0.01634790", "0.80000000"]]"#;
+        assert!(serde_json::from_str::<Vec<KlineSummaryRow>>(short).is_err());
+    }
+
+    #[test]
+    fn order_book_levels_serde() {
+        let book = r#"{"lastUpdateId": 1027024, "bids": [["4.00000000", "431.00000000"], ["3.00000000", "100.00000000"]],
+            "asks": [["5.00000000", "12.00000000"], ["6.00000000", "88.00000000"]]}"#;
+        let book = serde_json::from_str::<OrderBook>(book).unwrap();
+        assert_eq!(book.best_bid(), Some(&Level { price: 4.0, qty: 431.0 }));
+        assert_eq!(book.best_ask(), Some(&Level { price: 5.0, qty: 12.0 }));
+        assert_eq!(book.mid_price(), Some(4.5));
+        assert_eq!(book.vwap(1), Some((4.0 * 431.0 + 5.0 * 12.0) / 443.0));
+    }
+
+    #[test]
+    fn trade_history_serde() {
+        let trade = r#"{"symbol": "BNBBTC", "id": 28457, "orderId": 100234, "price": "4.00000100", "qty": "12.00000000",
+            "quoteQty": "48.000012", "commission": "10.10000000", "commissionAsset": "BNB", "time": 1499865549590,
+            "isBuyer": true, "isMaker": false, "isBestMatch": true}"#;
+        let trade = serde_json::from_str::<TradeHistory>(trade).unwrap();
+        assert_eq!(trade.price, 4.000001);
+        assert_eq!(trade.quote_qty, 48.000012);
+        assert_eq!(trade.commission, 10.1);
+        let json = serde_json::to_value(&trade).unwrap();
+        assert_eq!(json["commission"], "10.1");
+    }
+
+    #[test]
+    fn isolated_margin_account_serde() {
+        let asset = |asset: &str, borrowed: &str| {
+            format!(
+                r#"{{"asset": "{asset}", "borrowEnabled": true, "borrowed": "{borrowed}", "free": "1.0",
+                "interest": "0.01", "locked": "0", "netAsset": "0.5", "netAssetOfBtc": "0.5", "repayEnabled": true,
+                "totalAsset": "1.0"}}"#
+            )
+        };
+        let account = format!(
+            r#"{{"assets": [{{"baseAsset": {}, "quoteAsset": {}, "symbol": "BTCUSDT", "isolatedCreated": true,
+            "enabled": true, "marginLevel": "1.2", "marginLevelStatus": "MARGIN_CALL", "marginRatio": "5",
+            "indexPrice": "10000", "liquidatePrice": "9000", "liquidateRate": "1.1", "tradeEnabled": true}}],
+            "totalAssetOfBtc": "1", "totalLiabilityOfBtc": "0.5", "totalNetAssetOfBtc": "0.5"}}"#,
+            asset("BTC", "0.49"),
+            asset("USDT", "0")
+        );
+        let account = serde_json::from_str::<IsolatedMarginAccountDetails>(&account).unwrap();
+        let btcusdt = account.symbol("BTCUSDT").unwrap();
+        assert_eq!(btcusdt.margin_level_status, MarginLevelStatus::MarginCall);
+        assert_eq!(btcusdt.asset("BTC").map(|btc| btc.liability()), Some(0.5));
+        assert_eq!(btcusdt.liquidation_distance(), Some(0.1));
+        assert_eq!(account.at_risk().count(), 1);
+        assert!(account.symbol("ETHUSDT").is_none());
+    }
+
+    #[test]
+    fn order_list_status_serde() {
+        let event = r#"{"e": "listStatus", "E": 1564035303637, "s": "ETHBTC", "g": 2, "c": "OCO", "l": "EXEC_STARTED",
+            "L": "EXECUTING", "r": "NONE", "C": "F4QN4G8DlFATFlIUQ0cjdD", "T": 1564035303625,
+            "O": [{"s": "ETHBTC", "i": 17, "c": "AJYsMjErWJesZvqlJCTUgL"},
+                  {"s": "ETHBTC", "i": 18, "c": "bfYPSQdLoqAJeNrOr9adzq"}]}"#;
+        let update = match serde_json::from_str::<WebsocketEvent>(event).unwrap() {
+            WebsocketEvent::ListOrderUpdate(update) => update,
+            event => panic!("unexpected event {event:?}"),
+        };
+        assert_eq!(update.contingency_type, ContingencyType::OCO);
+        assert_eq!(update.list_status_type, ListStatusType::ExecStarted);
+        assert_eq!(update.list_order_status, ListOrderStatus::Executing);
+        assert!(!update.is_done() && !update.is_rejected());
+        assert_eq!(update.reject_reason(), None);
+        let order = update.order(18).unwrap();
+        assert_eq!(order.client_order_id, "bfYPSQdLoqAJeNrOr9adzq");
+    }
 }
@@ -1,3 +1,8 @@
+//! Coin configuration, asset details and deposit addresses.
+//!
+//! The flexible and locked savings products of the former lending endpoints are served by
+//! Simple Earn, see `simple_earn::SimpleEarn` of the `simple_earn_api` feature.
+
 use crate::client::*;
 use crate::errors::*;
 use crate::rest_model::*;
@@ -0,0 +1,160 @@
+//! Scheduling of recurring market data requests under the request weight limit.
+
+use std::collections::VecDeque;
+use std::future::Future;
+use std::time::Duration;
+
+use futures::future::{BoxFuture, FutureExt};
+use futures::Stream;
+use tokio::time::{sleep_until, Instant};
+
+use crate::errors::*;
+
+/// Default request weight allowed by Binance per minute and per IP
+pub const DEFAULT_WEIGHT_PER_MINUTE: u32 = 6000;
+
+/// Share of the weight budget above which jobs start slowing down
+pub const DEFAULT_SOFT_LIMIT: f64 = 0.7;
+
+/// Polling periods are stretched by up to this factor when the weight budget is almost spent
+const MAX_SLOWDOWN: f64 = 8.0;
+
+const WEIGHT_WINDOW: Duration = Duration::from_secs(60);
+
+type Fetch<T> = Box<dyn Fn() -> BoxFuture<'static, Result<T>> + Send>;
+
+struct Job<T> {
+    name: String,
+    weight: u32,
+    period: Duration,
+    next_run: Instant,
+    fetch: Fetch<T>,
+}
+
+/// Runs recurring queries at their target period while keeping the weight spent over the last minute under the budget.
+///
+/// Once the weight spent over the last minute exceeds the soft limit, periods are stretched proportionally,
+/// and a query is delayed when running it would exceed the budget.
+/// Weights of the endpoints are documented by Binance, e.g. 2 for `ticker/bookTicker` with a symbol.
+///
+/// # Examples
+/// ```rust,no_run
+/// use std::time::Duration;
+/// use binance::{api::*, config::*, market::*, scheduler::*};
+/// use futures::StreamExt;
+/// let market: Market = Binance::new_with_env(&Config::default());
+/// let mut scheduler = WeightScheduler::new(DEFAULT_WEIGHT_PER_MINUTE);
+/// let m = market.clone();
+/// scheduler.add_job("btc ticker", 2, Duration::from_secs(1), move || {
+///     let m = m.clone();
+///     async move { m.get_price("BTCUSDT").await.map(|p| p.price) }
+/// });
+/// scheduler.add_job("eth ticker", 2, Duration::from_secs(5), move || {
+///     let m = market.clone();
+///     async move { m.get_price("ETHUSDT").await.map(|p| p.price) }
+/// });
+/// tokio_test::block_on(scheduler.run().take(10).for_each(|(name, price)| async move {
+///     println!("{name}: {price:?}");
+/// }));
+/// ```
+pub struct WeightScheduler<T> {
+    jobs: Vec<Job<T>>,
+    weight_per_minute: u32,
+    soft_limit: f64,
+    spent: VecDeque<(Instant, u32)>,
+}
+
+impl<T: Send + 'static> WeightScheduler<T> {
+    /// Scheduler spending at most `weight_per_minute`
+    pub fn new(weight_per_minute: u32) -> Self {
+        Self {
+            jobs: vec![],
+            weight_per_minute,
+            soft_limit: DEFAULT_SOFT_LIMIT,
+            spent: VecDeque::new(),
+        }
+    }
+
+    /// Share of the budget, between 0 and 1, above which jobs start slowing down
+    pub fn with_soft_limit(mut self, soft_limit: f64) -> Self {
+        self.soft_limit = soft_limit.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Run `fetch`, which costs `weight`, every `period`
+    pub fn add_job<S, F, Fut>(&mut self, name: S, weight: u32, period: Duration, fetch: F)
+    where
+        S: Into<String>,
+        F: Fn() -> Fut + Send + 'static,
+        Fut: Future<Output = Result<T>> + Send + 'static,
+    {
+        self.jobs.push(Job {
+            name: name.into(),
+            weight,
+            period,
+            next_run: Instant::now(),
+            fetch: Box::new(move || fetch().boxed()),
+        });
+    }
+
+    /// Stream of the job names and their results, ends immediately if there are no jobs
+    pub fn run(self) -> impl Stream<Item = (String, Result<T>)> {
+        futures::stream::unfold(self, |mut scheduler| async move {
+            let item = scheduler.next().await?;
+            Some((item, scheduler))
+        })
+    }
+
+    async fn next(&mut self) -> Option<(String, Result<T>)> {
+        let index = (0..self.jobs.len()).min_by_key(|&i| self.jobs[i].next_run)?;
+        let weight = self.jobs[index].weight;
+        sleep_until(self.jobs[index].next_run).await;
+        while let Some(available_at) = self.available_at(weight) {
+            sleep_until(available_at).await;
+        }
+        let slowdown = self.slowdown();
+        let now = Instant::now();
+        self.spent.push_back((now, weight));
+        let job = &mut self.jobs[index];
+        job.next_run = now + job.period.mul_f64(slowdown);
+        let result = (job.fetch)().await;
+        Some((job.name.clone(), result))
+    }
+
+    fn spent_weight(&mut self) -> u32 {
+        let now = Instant::now();
+        while let Some((at, _)) = self.spent.front() {
+            if now.duration_since(*at) < WEIGHT_WINDOW {
+                break;
+            }
+            self.spent.pop_front();
+        }
+        self.spent.iter().map(|(_, weight)| weight).sum()
+    }
+
+    /// When enough weight will be available to spend `weight`, None if it is available now
+    fn available_at(&mut self, weight: u32) -> Option<Instant> {
+        let mut excess = (self.spent_weight() + weight).checked_sub(self.weight_per_minute)?;
+        if excess == 0 {
+            return None;
+        }
+        for (at, spent) in &self.spent {
+            excess = excess.saturating_sub(*spent);
+            if excess == 0 {
+                return Some(*at + WEIGHT_WINDOW);
+            }
+        }
+        // The job alone exceeds the budget, run it once the window is empty
+        self.spent.back().map(|(at, _)| *at + WEIGHT_WINDOW)
+    }
+
+    /// Factor applied to the periods, grows linearly from 1 at the soft limit to `MAX_SLOWDOWN` at the budget
+    fn slowdown(&mut self) -> f64 {
+        let usage = self.spent_weight() as f64 / self.weight_per_minute.max(1) as f64;
+        if usage <= self.soft_limit || self.soft_limit >= 1.0 {
+            return 1.0;
+        }
+        let overload = ((usage - self.soft_limit) / (1.0 - self.soft_limit)).min(1.0);
+        1.0 + overload * (MAX_SLOWDOWN - 1.0)
+    }
+}
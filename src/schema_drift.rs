@@ -0,0 +1,70 @@
+//! Detection of the fields of REST responses unknown to the models, enabled with the `schema_drift` feature.
+//!
+//! Models ignore the fields they do not declare. With a [`SchemaDriftReporter`] set in the
+//! [`crate::config::Config`], each of those fields is reported with the endpoint it came from, which warns early when
+//! Binance adds or renames fields. Websocket events are not checked.
+
+use std::fmt;
+use std::sync::Arc;
+
+use serde::de::DeserializeOwned;
+
+use crate::errors::*;
+
+/// A field of a response which the model does not declare
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownField {
+    pub endpoint: String,
+    /// Path of the field in the response, e.g. `balances.?.newField`
+    pub path: String,
+}
+
+impl fmt::Display for UnknownField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown field {} in the response of {}", self.path, self.endpoint)
+    }
+}
+
+/// Callback receiving the unknown fields, two configurations are equal if they share the same reporter
+///
+/// # Examples
+/// ```
+/// use std::sync::{Arc, Mutex};
+/// use binance::schema_drift::*;
+/// use binance::rest_model::ServerTime;
+/// let fields = Arc::new(Mutex::new(vec![]));
+/// let reported = fields.clone();
+/// let reporter = SchemaDriftReporter::new(move |field: &UnknownField| reported.lock().unwrap().push(field.path.clone()));
+/// let time: ServerTime = reporter.deserialize("/api/v3/time", r#"{"serverTime": 1, "zone": "UTC"}"#).unwrap();
+/// assert_eq!(time.server_time, 1);
+/// assert_eq!(*fields.lock().unwrap(), vec!["zone".to_string()]);
+/// ```
+#[derive(Clone)]
+pub struct SchemaDriftReporter(Arc<dyn Fn(&UnknownField) + Send + Sync>);
+
+impl SchemaDriftReporter {
+    pub fn new<F: Fn(&UnknownField) + Send + Sync + 'static>(callback: F) -> Self { Self(Arc::new(callback)) }
+
+    /// Deserialize the body of a response of `endpoint`, reporting the fields ignored by `T`
+    pub fn deserialize<T: DeserializeOwned>(&self, endpoint: &str, body: &str) -> Result<T> {
+        let mut deserializer = serde_json::Deserializer::from_str(body);
+        let value = serde_ignored::deserialize(&mut deserializer, |path| {
+            (self.0)(&UnknownField {
+                endpoint: endpoint.to_string(),
+                path: path.to_string(),
+            })
+        })?;
+        deserializer.end()?;
+        Ok(value)
+    }
+}
+
+impl fmt::Debug for SchemaDriftReporter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.write_str("SchemaDriftReporter") }
+}
+
+impl PartialEq for SchemaDriftReporter {
+    fn eq(&self, other: &Self) -> bool { Arc::ptr_eq(&self.0, &other.0) }
+}
+
+impl Eq for SchemaDriftReporter {}
@@ -0,0 +1,87 @@
+//! Signature of the `SIGNED` endpoints.
+//!
+//! By default requests are signed with HMAC SHA256 from the secret key given to the gateways.
+//! A [`RequestSigner`] lets the secret live elsewhere, e.g. in an HSM, a cloud KMS or a signing service,
+//! the gateways then only need the API key.
+
+use std::fmt;
+use std::sync::Arc;
+
+use futures::future::BoxFuture;
+use hex::encode as hex_encode;
+use ring::hmac;
+
+use crate::errors::*;
+
+/// Signs the query string of a request, returning the value of its `signature` parameter
+///
+/// # Examples
+/// ```
+/// use binance::{api::*, account::*, config::*, errors::*, signer::*};
+/// use futures::future::BoxFuture;
+///
+/// /// Forwards the payloads to a signing service
+/// struct RemoteSigner {
+///     service: HmacSigner,
+/// }
+///
+/// impl RequestSigner for RemoteSigner {
+///     fn sign<'a>(&'a self, payload: &'a str) -> BoxFuture<'a, Result<String>> {
+///         Box::pin(async move {
+///             // e.g. an HTTP call to the signing service
+///             self.service.sign(payload).await
+///         })
+///     }
+/// }
+///
+/// let signer = RemoteSigner { service: HmacSigner::new("secret") };
+/// let config = Config::default().set_signer(signer);
+/// let account: Account = Binance::new_with_config(Some("api key".into()), None, &config);
+/// ```
+pub trait RequestSigner: Send + Sync {
+    fn sign<'a>(&'a self, payload: &'a str) -> BoxFuture<'a, Result<String>>;
+}
+
+/// HMAC SHA256 signature from a secret key held in memory, the default signer
+#[derive(Clone)]
+pub struct HmacSigner {
+    key: hmac::Key,
+}
+
+impl HmacSigner {
+    pub fn new<S: AsRef<[u8]>>(secret_key: S) -> Self {
+        Self {
+            key: hmac::Key::new(hmac::HMAC_SHA256, secret_key.as_ref()),
+        }
+    }
+
+    pub fn sign_sync(&self, payload: &str) -> String { hex_encode(hmac::sign(&self.key, payload.as_bytes()).as_ref()) }
+}
+
+impl fmt::Debug for HmacSigner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.write_str("HmacSigner") }
+}
+
+impl RequestSigner for HmacSigner {
+    fn sign<'a>(&'a self, payload: &'a str) -> BoxFuture<'a, Result<String>> {
+        Box::pin(futures::future::ready(Ok(self.sign_sync(payload))))
+    }
+}
+
+/// Signer shared by the gateways, two configurations are equal if they share the same signer
+#[derive(Clone)]
+pub struct SharedSigner(pub Arc<dyn RequestSigner>);
+
+impl SharedSigner {
+    pub fn new<S: RequestSigner + 'static>(signer: S) -> Self { Self(Arc::new(signer)) }
+}
+
+impl fmt::Debug for SharedSigner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.write_str("SharedSigner") }
+}
+
+impl PartialEq for SharedSigner {
+    fn eq(&self, other: &Self) -> bool { Arc::ptr_eq(&self.0, &other.0) }
+}
+
+impl Eq for SharedSigner {}
@@ -0,0 +1,717 @@
+//! Simple Earn flexible and locked products, which replace the deprecated savings products.
+
+use std::collections::{BTreeMap, HashMap};
+use std::time::Duration;
+
+use crate::client::*;
+use crate::errors::*;
+use crate::rest_model::{string_or_float, string_or_float_opt, string_or_u64, RecordsQueryResult};
+use crate::util::time_windows;
+
+static SAPI_V1_SIMPLE_EARN_ACCOUNT: &str = "/sapi/v1/simple-earn/account";
+static SAPI_V1_SIMPLE_EARN_FLEXIBLE_LIST: &str = "/sapi/v1/simple-earn/flexible/list";
+static SAPI_V1_SIMPLE_EARN_LOCKED_LIST: &str = "/sapi/v1/simple-earn/locked/list";
+static SAPI_V1_SIMPLE_EARN_FLEXIBLE_SUBSCRIBE: &str = "/sapi/v1/simple-earn/flexible/subscribe";
+static SAPI_V1_SIMPLE_EARN_LOCKED_SUBSCRIBE: &str = "/sapi/v1/simple-earn/locked/subscribe";
+static SAPI_V1_SIMPLE_EARN_FLEXIBLE_REDEEM: &str = "/sapi/v1/simple-earn/flexible/redeem";
+static SAPI_V1_SIMPLE_EARN_LOCKED_REDEEM: &str = "/sapi/v1/simple-earn/locked/redeem";
+static SAPI_V1_SIMPLE_EARN_FLEXIBLE_POSITION: &str = "/sapi/v1/simple-earn/flexible/position";
+static SAPI_V1_SIMPLE_EARN_LOCKED_POSITION: &str = "/sapi/v1/simple-earn/locked/position";
+static SAPI_V1_SIMPLE_EARN_FLEXIBLE_REWARDS_RECORD: &str = "/sapi/v1/simple-earn/flexible/history/rewardsRecord";
+static SAPI_V1_SIMPLE_EARN_LOCKED_REWARDS_RECORD: &str = "/sapi/v1/simple-earn/locked/history/rewardsRecord";
+static SAPI_V1_SIMPLE_EARN_FLEXIBLE_RATE_HISTORY: &str = "/sapi/v1/simple-earn/flexible/history/rateHistory";
+/// Maximum page size of the positions and rewards history
+const MAX_PAGE_SIZE: u64 = 100;
+/// Longest time range accepted by the rewards history
+const REWARDS_WINDOW_MS: u64 = 90 * 24 * 60 * 60 * 1000;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SimpleEarnAccount {
+    #[serde(rename = "totalAmountInBTC", with = "string_or_float")]
+    pub total_amount_in_btc: f64,
+    #[serde(rename = "totalAmountInUSDT", with = "string_or_float")]
+    pub total_amount_in_usdt: f64,
+    #[serde(rename = "totalFlexibleAmountInBTC", with = "string_or_float")]
+    pub total_flexible_amount_in_btc: f64,
+    #[serde(rename = "totalFlexibleAmountInUSDT", with = "string_or_float")]
+    pub total_flexible_amount_in_usdt: f64,
+    #[serde(rename = "totalLockedInBTC", with = "string_or_float")]
+    pub total_locked_in_btc: f64,
+    #[serde(rename = "totalLockedInUSDT", with = "string_or_float")]
+    pub total_locked_in_usdt: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct SimpleEarnProductQuery {
+    pub asset: Option<String>,
+    /// Page number, starting at 1
+    pub current: Option<u64>,
+    /// Default: 10, Max: 100
+    pub size: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FlexibleProduct {
+    pub asset: String,
+    pub product_id: String,
+    #[serde(with = "string_or_float")]
+    pub latest_annual_percentage_rate: f64,
+    /// Annual percentage rate of each balance tier
+    #[serde(default)]
+    pub tier_annual_percentage_rate: HashMap<String, f64>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub air_drop_percentage_rate: Option<f64>,
+    pub can_purchase: bool,
+    pub can_redeem: bool,
+    pub is_sold_out: bool,
+    pub hot: bool,
+    #[serde(with = "string_or_float")]
+    pub min_purchase_amount: f64,
+    #[serde(with = "string_or_u64")]
+    pub subscription_start_time: u64,
+    pub status: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LockedProduct {
+    pub project_id: String,
+    pub detail: LockedProductDetail,
+    pub quota: LockedProductQuota,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LockedProductDetail {
+    pub asset: String,
+    pub reward_asset: String,
+    /// Lock period in days
+    pub duration: u64,
+    pub renewable: bool,
+    pub is_sold_out: bool,
+    #[serde(with = "string_or_float")]
+    pub apr: f64,
+    pub status: String,
+    #[serde(with = "string_or_u64")]
+    pub subscription_start_time: u64,
+    pub extra_reward_asset: Option<String>,
+    #[serde(rename = "extraRewardAPR", default, with = "string_or_float_opt")]
+    pub extra_reward_apr: Option<f64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LockedProductQuota {
+    #[serde(with = "string_or_float")]
+    pub total_personal_quota: f64,
+    #[serde(with = "string_or_float")]
+    pub minimum: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum EarnAccount {
+    Spot,
+    Fund,
+    /// Spot first, then funding, only to subscribe
+    All,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct FlexibleSubscribeQuery {
+    pub product_id: String,
+    pub amount: f64,
+    /// Default: true
+    pub auto_subscribe: Option<bool>,
+    /// Default: Spot
+    pub source_account: Option<EarnAccount>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct LockedSubscribeQuery {
+    pub project_id: String,
+    pub amount: f64,
+    /// Default: true
+    pub auto_subscribe: Option<bool>,
+    /// Default: Spot
+    pub source_account: Option<EarnAccount>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubscribeResponse {
+    pub purchase_id: u64,
+    /// Only returned for locked products
+    pub position_id: Option<String>,
+    pub success: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct FlexibleRedeemQuery {
+    pub product_id: String,
+    /// Redeem the whole position, `amount` is ignored if true
+    pub redeem_all: Option<bool>,
+    pub amount: Option<f64>,
+    /// Default: Spot
+    pub dest_account: Option<EarnAccount>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RedeemResponse {
+    pub redeem_id: u64,
+    pub success: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct FlexiblePositionQuery {
+    pub asset: Option<String>,
+    pub product_id: Option<String>,
+    /// Page number, starting at 1
+    pub current: Option<u64>,
+    /// Default: 10, Max: 100
+    pub size: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FlexiblePosition {
+    pub asset: String,
+    pub product_id: String,
+    #[serde(with = "string_or_float")]
+    pub total_amount: f64,
+    #[serde(with = "string_or_float")]
+    pub latest_annual_percentage_rate: f64,
+    #[serde(default)]
+    pub tier_annual_percentage_rate: HashMap<String, f64>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub yesterday_airdrop_percentage_rate: Option<f64>,
+    pub air_drop_asset: Option<String>,
+    pub can_redeem: bool,
+    /// Amount used as collateral for loans
+    #[serde(with = "string_or_float")]
+    pub collateral_amount: f64,
+    #[serde(with = "string_or_float")]
+    pub yesterday_real_time_rewards: f64,
+    #[serde(with = "string_or_float")]
+    pub cumulative_bonus_rewards: f64,
+    #[serde(with = "string_or_float")]
+    pub cumulative_real_time_rewards: f64,
+    #[serde(with = "string_or_float")]
+    pub cumulative_total_rewards: f64,
+    pub auto_subscribe: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct LockedPositionQuery {
+    pub asset: Option<String>,
+    pub position_id: Option<u64>,
+    pub project_id: Option<String>,
+    /// Page number, starting at 1
+    pub current: Option<u64>,
+    /// Default: 10, Max: 100
+    pub size: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LockedPosition {
+    #[serde(with = "string_or_u64")]
+    pub position_id: u64,
+    pub project_id: String,
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    #[serde(with = "string_or_u64")]
+    pub purchase_time: u64,
+    /// Lock period in days
+    #[serde(with = "string_or_u64")]
+    pub duration: u64,
+    #[serde(with = "string_or_u64")]
+    pub accrual_days: u64,
+    pub reward_asset: String,
+    #[serde(rename = "APY", with = "string_or_float")]
+    pub apy: f64,
+    pub is_renewable: bool,
+    pub is_auto_renew: bool,
+    #[serde(with = "string_or_u64")]
+    pub redeem_date: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum FlexibleRewardType {
+    Bonus,
+    Realtime,
+    /// Bonus and real time rewards
+    Rewards,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FlexibleRewardsQuery {
+    #[serde(rename = "type")]
+    pub reward_type: FlexibleRewardType,
+    pub product_id: Option<String>,
+    pub asset: Option<String>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    /// Page number, starting at 1
+    pub current: Option<u64>,
+    /// Default: 10, Max: 100
+    pub size: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FlexibleReward {
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub rewards: f64,
+    pub project_id: String,
+    #[serde(rename = "type")]
+    pub reward_type: String,
+    pub time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct LockedRewardsQuery {
+    pub position_id: Option<u64>,
+    pub asset: Option<String>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    /// Page number, starting at 1
+    pub current: Option<u64>,
+    /// Default: 10, Max: 100
+    pub size: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LockedReward {
+    #[serde(with = "string_or_u64")]
+    pub position_id: u64,
+    pub time: u64,
+    pub asset: String,
+    /// Lock period in days
+    #[serde(with = "string_or_u64")]
+    pub lock_period: u64,
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+}
+
+/// Rewards of an asset over a period, by reward type
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct PeriodRewards {
+    pub asset: String,
+    /// Start of the period, in milliseconds
+    pub period_start: u64,
+    pub flexible_realtime: f64,
+    pub flexible_bonus: f64,
+    pub locked: f64,
+}
+
+impl PeriodRewards {
+    pub fn total(&self) -> f64 { self.flexible_realtime + self.flexible_bonus + self.locked }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AprPeriod {
+    Day,
+    Year,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct RateHistoryQuery {
+    pub product_id: String,
+    /// Default: Day
+    pub apr_period: Option<AprPeriod>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    /// Page number, starting at 1
+    pub current: Option<u64>,
+    /// Default: 10, Max: 100
+    pub size: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FlexibleRate {
+    pub product_id: String,
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub annual_percentage_rate: f64,
+    pub time: u64,
+}
+
+/// This struct acts as a gateway for all Simple Earn endpoints.
+/// Preferably use the trait [`crate::api::Binance`] to get an instance.
+#[derive(Clone)]
+pub struct SimpleEarn {
+    pub client: Client,
+    pub recv_window: u64,
+}
+
+impl SimpleEarn {
+    /// Total amounts held in flexible and locked products, in BTC and USDT
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, simple_earn::*, config::*};
+    /// let earn: SimpleEarn = Binance::new_with_env(&Config::testnet());
+    /// let account = tokio_test::block_on(earn.account());
+    /// assert!(account.is_ok(), "{:?}", account);
+    /// ```
+    pub async fn account(&self) -> Result<SimpleEarnAccount> {
+        self.client
+            .get_signed_p(SAPI_V1_SIMPLE_EARN_ACCOUNT, Option::<String>::None, self.recv_window)
+            .await
+    }
+
+    /// Flexible products available for subscription
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, simple_earn::*, config::*};
+    /// let earn: SimpleEarn = Binance::new_with_env(&Config::testnet());
+    /// let products = tokio_test::block_on(earn.flexible_products(SimpleEarnProductQuery::default()));
+    /// assert!(products.is_ok(), "{:?}", products);
+    /// ```
+    pub async fn flexible_products(
+        &self,
+        query: SimpleEarnProductQuery,
+    ) -> Result<RecordsQueryResult<FlexibleProduct>> {
+        self.client
+            .get_signed_p(SAPI_V1_SIMPLE_EARN_FLEXIBLE_LIST, Some(query), self.recv_window)
+            .await
+    }
+
+    /// Locked products available for subscription
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, simple_earn::*, config::*};
+    /// let earn: SimpleEarn = Binance::new_with_env(&Config::testnet());
+    /// let products = tokio_test::block_on(earn.locked_products(SimpleEarnProductQuery::default()));
+    /// assert!(products.is_ok(), "{:?}", products);
+    /// ```
+    pub async fn locked_products(&self, query: SimpleEarnProductQuery) -> Result<RecordsQueryResult<LockedProduct>> {
+        self.client
+            .get_signed_p(SAPI_V1_SIMPLE_EARN_LOCKED_LIST, Some(query), self.recv_window)
+            .await
+    }
+
+    /// Subscribe to a flexible product
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, simple_earn::*, config::*};
+    /// let earn: SimpleEarn = Binance::new_with_env(&Config::testnet());
+    /// let query = FlexibleSubscribeQuery {
+    ///     product_id: "USDT001".to_string(),
+    ///     amount: 100.0,
+    ///     ..Default::default()
+    /// };
+    /// let subscription = tokio_test::block_on(earn.subscribe_flexible(query));
+    /// assert!(subscription.is_ok(), "{:?}", subscription);
+    /// ```
+    pub async fn subscribe_flexible(&self, query: FlexibleSubscribeQuery) -> Result<SubscribeResponse> {
+        self.client
+            .post_signed_p(SAPI_V1_SIMPLE_EARN_FLEXIBLE_SUBSCRIBE, Some(query), self.recv_window)
+            .await
+    }
+
+    /// Subscribe to a locked product
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, simple_earn::*, config::*};
+    /// let earn: SimpleEarn = Binance::new_with_env(&Config::testnet());
+    /// let query = LockedSubscribeQuery {
+    ///     project_id: "Axs*90".to_string(),
+    ///     amount: 10.0,
+    ///     ..Default::default()
+    /// };
+    /// let subscription = tokio_test::block_on(earn.subscribe_locked(query));
+    /// assert!(subscription.is_ok(), "{:?}", subscription);
+    /// ```
+    pub async fn subscribe_locked(&self, query: LockedSubscribeQuery) -> Result<SubscribeResponse> {
+        self.client
+            .post_signed_p(SAPI_V1_SIMPLE_EARN_LOCKED_SUBSCRIBE, Some(query), self.recv_window)
+            .await
+    }
+
+    /// Redeem a flexible product, partially or entirely
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, simple_earn::*, config::*};
+    /// let earn: SimpleEarn = Binance::new_with_env(&Config::testnet());
+    /// let query = FlexibleRedeemQuery {
+    ///     product_id: "USDT001".to_string(),
+    ///     redeem_all: Some(true),
+    ///     ..Default::default()
+    /// };
+    /// let redemption = tokio_test::block_on(earn.redeem_flexible(query));
+    /// assert!(redemption.is_ok(), "{:?}", redemption);
+    /// ```
+    pub async fn redeem_flexible(&self, query: FlexibleRedeemQuery) -> Result<RedeemResponse> {
+        self.client
+            .post_signed_p(SAPI_V1_SIMPLE_EARN_FLEXIBLE_REDEEM, Some(query), self.recv_window)
+            .await
+    }
+
+    /// Redeem a locked position before the end of its lock period, accrued rewards are forfeited
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, simple_earn::*, config::*};
+    /// let earn: SimpleEarn = Binance::new_with_env(&Config::testnet());
+    /// let redemption = tokio_test::block_on(earn.redeem_locked(1234));
+    /// assert!(redemption.is_ok(), "{:?}", redemption);
+    /// ```
+    pub async fn redeem_locked(&self, position_id: u64) -> Result<RedeemResponse> {
+        let mut params = HashMap::new();
+        params.insert("positionId", position_id);
+        self.client
+            .post_signed_p(SAPI_V1_SIMPLE_EARN_LOCKED_REDEEM, Some(params), self.recv_window)
+            .await
+    }
+
+    /// Flexible product positions
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, simple_earn::*, config::*};
+    /// let earn: SimpleEarn = Binance::new_with_env(&Config::testnet());
+    /// let positions = tokio_test::block_on(earn.flexible_positions(FlexiblePositionQuery::default()));
+    /// assert!(positions.is_ok(), "{:?}", positions);
+    /// ```
+    pub async fn flexible_positions(
+        &self,
+        query: FlexiblePositionQuery,
+    ) -> Result<RecordsQueryResult<FlexiblePosition>> {
+        self.client
+            .get_signed_p(SAPI_V1_SIMPLE_EARN_FLEXIBLE_POSITION, Some(query), self.recv_window)
+            .await
+    }
+
+    /// Locked product positions
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, simple_earn::*, config::*};
+    /// let earn: SimpleEarn = Binance::new_with_env(&Config::testnet());
+    /// let positions = tokio_test::block_on(earn.locked_positions(LockedPositionQuery::default()));
+    /// assert!(positions.is_ok(), "{:?}", positions);
+    /// ```
+    pub async fn locked_positions(&self, query: LockedPositionQuery) -> Result<RecordsQueryResult<LockedPosition>> {
+        self.client
+            .get_signed_p(SAPI_V1_SIMPLE_EARN_LOCKED_POSITION, Some(query), self.recv_window)
+            .await
+    }
+
+    /// Every flexible position, of `asset` or of all assets, read page by page
+    pub async fn all_flexible_positions(&self, asset: Option<String>) -> Result<Vec<FlexiblePosition>> {
+        let mut query = FlexiblePositionQuery {
+            asset,
+            current: Some(1),
+            size: Some(MAX_PAGE_SIZE),
+            ..FlexiblePositionQuery::default()
+        };
+        let mut positions = vec![];
+        loop {
+            let rows = self.flexible_positions(query.clone()).await?.rows.unwrap_or_default();
+            let count = rows.len() as u64;
+            positions.extend(rows);
+            if count < MAX_PAGE_SIZE {
+                return Ok(positions);
+            }
+            query.current = query.current.map(|current| current + 1);
+        }
+    }
+
+    /// Every locked position, of `asset` or of all assets, read page by page
+    pub async fn all_locked_positions(&self, asset: Option<String>) -> Result<Vec<LockedPosition>> {
+        let mut query = LockedPositionQuery {
+            asset,
+            current: Some(1),
+            size: Some(MAX_PAGE_SIZE),
+            ..LockedPositionQuery::default()
+        };
+        let mut positions = vec![];
+        loop {
+            let rows = self.locked_positions(query.clone()).await?.rows.unwrap_or_default();
+            let count = rows.len() as u64;
+            positions.extend(rows);
+            if count < MAX_PAGE_SIZE {
+                return Ok(positions);
+            }
+            query.current = query.current.map(|current| current + 1);
+        }
+    }
+
+    /// Rewards of flexible products
+    /// If startTime and endTime not sent, return records of the last 7 days by default
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, simple_earn::*, config::*};
+    /// let earn: SimpleEarn = Binance::new_with_env(&Config::testnet());
+    /// let query = FlexibleRewardsQuery {
+    ///     reward_type: FlexibleRewardType::Rewards,
+    ///     product_id: None,
+    ///     asset: Some("USDT".to_string()),
+    ///     start_time: None,
+    ///     end_time: None,
+    ///     current: None,
+    ///     size: None,
+    /// };
+    /// let rewards = tokio_test::block_on(earn.flexible_rewards(query));
+    /// assert!(rewards.is_ok(), "{:?}", rewards);
+    /// ```
+    pub async fn flexible_rewards(&self, query: FlexibleRewardsQuery) -> Result<RecordsQueryResult<FlexibleReward>> {
+        self.client
+            .get_signed_p(
+                SAPI_V1_SIMPLE_EARN_FLEXIBLE_REWARDS_RECORD,
+                Some(query),
+                self.recv_window,
+            )
+            .await
+    }
+
+    /// Rewards of locked products
+    /// If startTime and endTime not sent, return records of the last 7 days by default
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, simple_earn::*, config::*};
+    /// let earn: SimpleEarn = Binance::new_with_env(&Config::testnet());
+    /// let rewards = tokio_test::block_on(earn.locked_rewards(LockedRewardsQuery::default()));
+    /// assert!(rewards.is_ok(), "{:?}", rewards);
+    /// ```
+    pub async fn locked_rewards(&self, query: LockedRewardsQuery) -> Result<RecordsQueryResult<LockedReward>> {
+        self.client
+            .get_signed_p(SAPI_V1_SIMPLE_EARN_LOCKED_REWARDS_RECORD, Some(query), self.recv_window)
+            .await
+    }
+
+    /// Annual percentage rate history of a flexible product
+    /// If startTime and endTime not sent, return records of the last 30 days by default
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, simple_earn::*, config::*};
+    /// let earn: SimpleEarn = Binance::new_with_env(&Config::testnet());
+    /// let query = RateHistoryQuery {
+    ///     product_id: "USDT001".to_string(),
+    ///     ..Default::default()
+    /// };
+    /// let rates = tokio_test::block_on(earn.flexible_rate_history(query));
+    /// assert!(rates.is_ok(), "{:?}", rates);
+    /// ```
+    pub async fn flexible_rate_history(&self, query: RateHistoryQuery) -> Result<RecordsQueryResult<FlexibleRate>> {
+        self.client
+            .get_signed_p(SAPI_V1_SIMPLE_EARN_FLEXIBLE_RATE_HISTORY, Some(query), self.recv_window)
+            .await
+    }
+
+    /// Rewards of flexible and locked products summed per asset and per `period`, e.g. a day, between `start_time`
+    /// and `end_time`, sorted by asset then period.
+    /// Every page of the flexible real time, flexible bonus and locked rewards is fetched, by windows of 90 days.
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, simple_earn::*, config::*};
+    /// use chrono::{Duration, Utc};
+    /// let earn: SimpleEarn = Binance::new_with_env(&Config::testnet());
+    /// let end_time = Utc::now().timestamp_millis() as u64;
+    /// let start_time = end_time - Duration::days(30).num_milliseconds() as u64;
+    /// let rewards = tokio_test::block_on(earn.rewards_summary(
+    ///     None,
+    ///     start_time,
+    ///     end_time,
+    ///     std::time::Duration::from_secs(24 * 3600),
+    /// ));
+    /// assert!(rewards.is_ok(), "{:?}", rewards);
+    /// for rewards in rewards.unwrap() {
+    ///     println!("{} {}: {}", rewards.period_start, rewards.asset, rewards.total());
+    /// }
+    /// ```
+    pub async fn rewards_summary(
+        &self,
+        asset: Option<String>,
+        start_time: u64,
+        end_time: u64,
+        period: Duration,
+    ) -> Result<Vec<PeriodRewards>> {
+        let period = (period.as_millis() as u64).max(1);
+        let mut summary: BTreeMap<(String, u64), PeriodRewards> = BTreeMap::new();
+        let mut add = |asset: &str, time: u64, amount: f64, field: fn(&mut PeriodRewards) -> &mut f64| {
+            let period_start = time - time % period;
+            let rewards = summary
+                .entry((asset.to_string(), period_start))
+                .or_insert_with(|| PeriodRewards {
+                    asset: asset.to_string(),
+                    period_start,
+                    ..PeriodRewards::default()
+                });
+            *field(rewards) += amount;
+        };
+        for (start_time, end_time) in time_windows(start_time, end_time, REWARDS_WINDOW_MS) {
+            for reward_type in [FlexibleRewardType::Realtime, FlexibleRewardType::Bonus] {
+                let mut query = FlexibleRewardsQuery {
+                    reward_type,
+                    product_id: None,
+                    asset: asset.clone(),
+                    start_time: Some(start_time),
+                    end_time: Some(end_time),
+                    current: Some(1),
+                    size: Some(MAX_PAGE_SIZE),
+                };
+                loop {
+                    let rows = self.flexible_rewards(query.clone()).await?.rows.unwrap_or_default();
+                    for reward in &rows {
+                        add(&reward.asset, reward.time, reward.rewards, match reward_type {
+                            FlexibleRewardType::Bonus => |r| &mut r.flexible_bonus,
+                            _ => |r| &mut r.flexible_realtime,
+                        });
+                    }
+                    if (rows.len() as u64) < MAX_PAGE_SIZE {
+                        break;
+                    }
+                    query.current = query.current.map(|current| current + 1);
+                }
+            }
+            let mut query = LockedRewardsQuery {
+                asset: asset.clone(),
+                start_time: Some(start_time),
+                end_time: Some(end_time),
+                current: Some(1),
+                size: Some(MAX_PAGE_SIZE),
+                ..LockedRewardsQuery::default()
+            };
+            loop {
+                let rows = self.locked_rewards(query.clone()).await?.rows.unwrap_or_default();
+                for reward in &rows {
+                    add(&reward.asset, reward.time, reward.amount, |r| &mut r.locked);
+                }
+                if (rows.len() as u64) < MAX_PAGE_SIZE {
+                    break;
+                }
+                query.current = query.current.map(|current| current + 1);
+            }
+        }
+        Ok(summary.into_values().collect())
+    }
+}
@@ -0,0 +1,180 @@
+//! Protection of market orders against thin books.
+//!
+//! A [`SlippageGuard`] walks the current order book with the quantity of a market order and rejects it locally with
+//! [`crate::errors::Error::SlippageExceeded`] when its average fill price would stray too far from the best price.
+//! The estimate is made from a snapshot of the book, the actual fill can still differ.
+
+use crate::account::OrderRequest;
+use crate::errors::*;
+#[cfg(feature = "futures_api")]
+use crate::futures::{account::OrderRequest as FuturesOrderRequest, market::FuturesMarket, rest_model as futures_model};
+use crate::market::Market;
+use crate::rest_model::{Level, OrderBook, OrderSide, OrderType};
+
+/// Default number of levels fetched to estimate the slippage of spot orders
+pub const DEFAULT_DEPTH: u16 = 100;
+
+/// Expected execution of a market order against a book snapshot
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SlippageEstimate {
+    pub best_price: f64,
+    pub average_price: f64,
+    /// Relative distance between the average and the best price, e.g. `0.01` for 1%
+    pub slippage: f64,
+    pub filled_qty: f64,
+    /// Whether the levels of the snapshot hold the whole quantity
+    pub complete: bool,
+}
+
+/// Estimate the fill of a market order walking `levels`, the asks for a buy or the bids for a sell.
+/// The order size is either a base `quantity` or a `quote_qty` to spend.
+///
+/// # Examples
+/// ```rust
+/// use binance::{rest_model::Level, slippage::*};
+/// let asks = vec![Level { price: 100.0, qty: 1.0 }, Level { price: 102.0, qty: 1.0 }];
+/// let estimate = estimate_slippage(&asks, Some(2.0), None).unwrap();
+/// assert_eq!(estimate.average_price, 101.0);
+/// assert!((estimate.slippage - 0.01).abs() < 1e-9);
+/// assert!(estimate.complete);
+/// ```
+pub fn estimate_slippage(levels: &[Level], quantity: Option<f64>, quote_qty: Option<f64>) -> Option<SlippageEstimate> {
+    let best_price = levels.first()?.price;
+    let (mut filled_qty, mut filled_quote) = (0.0, 0.0);
+    let mut complete = false;
+    for level in levels {
+        let qty = match (quantity, quote_qty) {
+            (Some(quantity), _) => level.qty.min(quantity - filled_qty),
+            (None, Some(quote_qty)) => level.qty.min((quote_qty - filled_quote) / level.price),
+            (None, None) => return None,
+        };
+        filled_qty += qty;
+        filled_quote += qty * level.price;
+        let remaining = match (quantity, quote_qty) {
+            (Some(quantity), _) => quantity - filled_qty,
+            _ => quote_qty.unwrap_or_default() - filled_quote,
+        };
+        if remaining <= f64::EPSILON * best_price.max(1.0) {
+            complete = true;
+            break;
+        }
+    }
+    if filled_qty <= 0.0 {
+        return None;
+    }
+    let average_price = filled_quote / filled_qty;
+    Some(SlippageEstimate {
+        best_price,
+        average_price,
+        slippage: (average_price - best_price).abs() / best_price,
+        filled_qty,
+        complete,
+    })
+}
+
+/// Rejects market orders whose estimated slippage exceeds `max_slippage`, or which the book cannot fill
+///
+/// # Examples
+/// ```rust,no_run
+/// use binance::{api::*, account::*, config::*, market::*, rest_model::*, slippage::*};
+/// let market: Market = Binance::new_with_env(&Config::testnet());
+/// let account: Account = Binance::new_with_env(&Config::testnet());
+/// let guard = SlippageGuard::new(0.005);
+/// let order = OrderRequest {
+///     symbol: "BTCUSDT".into(),
+///     side: OrderSide::Buy,
+///     order_type: OrderType::Market,
+///     quantity: Some(0.5),
+///     ..Default::default()
+/// };
+/// let checked = tokio_test::block_on(guard.check_order(&market, &order));
+/// assert!(checked.is_ok(), "{:?}", checked);
+/// let transaction = tokio_test::block_on(account.place_order(order));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SlippageGuard {
+    /// Maximum relative slippage, e.g. `0.005` for 0.5%
+    pub max_slippage: f64,
+    /// Levels fetched for spot orders, futures orders use the default depth of the endpoint
+    pub depth: u16,
+}
+
+impl SlippageGuard {
+    pub fn new(max_slippage: f64) -> Self {
+        Self {
+            max_slippage,
+            depth: DEFAULT_DEPTH,
+        }
+    }
+
+    pub fn with_depth(mut self, depth: u16) -> Self {
+        self.depth = depth;
+        self
+    }
+
+    /// Check a market order of `symbol` against a book snapshot
+    pub fn check(
+        &self,
+        symbol: &str,
+        book: &OrderBook,
+        side: OrderSide,
+        quantity: Option<f64>,
+        quote_qty: Option<f64>,
+    ) -> Result<SlippageEstimate> {
+        let levels = match side {
+            OrderSide::Buy => &book.asks,
+            OrderSide::Sell => &book.bids,
+        };
+        let estimate = estimate_slippage(levels, quantity, quote_qty).ok_or_else(|| Error::InvalidOrderError {
+            msg: format!("cannot estimate the slippage of the {symbol} order, empty book or no quantity"),
+        })?;
+        if !estimate.complete || estimate.slippage > self.max_slippage {
+            return Err(Error::SlippageExceeded {
+                symbol: symbol.to_string(),
+                slippage: if estimate.complete {
+                    estimate.slippage
+                } else {
+                    f64::INFINITY
+                },
+                max_slippage: self.max_slippage,
+            });
+        }
+        Ok(estimate)
+    }
+
+    /// Check a spot order against the current book, orders which are not market orders are not checked
+    pub async fn check_order(&self, market: &Market, order: &OrderRequest) -> Result<Option<SlippageEstimate>> {
+        if order.order_type != OrderType::Market {
+            return Ok(None);
+        }
+        let book = market.get_custom_depth(&order.symbol, self.depth).await?;
+        self.check(
+            &order.symbol,
+            &book,
+            order.side.clone(),
+            order.quantity,
+            order.quote_order_qty,
+        )
+        .map(Some)
+    }
+
+    /// Check a futures order against the current book, orders which are not market orders are not checked
+    #[cfg(feature = "futures_api")]
+    pub async fn check_futures_order(
+        &self,
+        market: &FuturesMarket,
+        order: &FuturesOrderRequest,
+    ) -> Result<Option<SlippageEstimate>> {
+        if !matches!(order.order_type, futures_model::OrderType::Market) {
+            return Ok(None);
+        }
+        let book = market.get_depth(order.symbol.clone()).await?;
+        let book = OrderBook {
+            last_update_id: book.last_update_id,
+            bids: book.bids,
+            asks: book.asks,
+        };
+        self.check(&order.symbol, &book, order.side.clone(), order.quantity, None)
+            .map(Some)
+    }
+}
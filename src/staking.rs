@@ -0,0 +1,988 @@
+//! On-chain staking products, ETH staking through WBETH and SOL staking through BNSOL.
+//!
+//! The legacy locked staking and DeFi staking products are deprecated in favour of
+//! [Simple Earn](crate::simple_earn), their endpoints remain available to manage the positions still held there.
+
+use std::collections::HashMap;
+
+use crate::client::*;
+use crate::errors::*;
+use crate::rest_model::{string_or_float, string_or_float_opt, string_or_u64_opt, RecordsQueryResult};
+
+static SAPI_V2_ETH_STAKING_ACCOUNT: &str = "/sapi/v2/eth-staking/account";
+static SAPI_V2_ETH_STAKING_ETH_STAKE: &str = "/sapi/v2/eth-staking/eth/stake";
+static SAPI_V1_ETH_STAKING_ETH_REDEEM: &str = "/sapi/v1/eth-staking/eth/redeem";
+static SAPI_V1_ETH_STAKING_WBETH_WRAP: &str = "/sapi/v1/eth-staking/wbeth/wrap";
+static SAPI_V1_ETH_STAKING_ETH_QUOTA: &str = "/sapi/v1/eth-staking/eth/quota";
+static SAPI_V1_ETH_STAKING_ETH_STAKING_HISTORY: &str = "/sapi/v1/eth-staking/eth/history/stakingHistory";
+static SAPI_V1_ETH_STAKING_ETH_REDEMPTION_HISTORY: &str = "/sapi/v1/eth-staking/eth/history/redemptionHistory";
+static SAPI_V1_ETH_STAKING_ETH_REWARDS_HISTORY: &str = "/sapi/v1/eth-staking/eth/history/rewardsHistory";
+static SAPI_V1_ETH_STAKING_ETH_RATE_HISTORY: &str = "/sapi/v1/eth-staking/eth/history/rateHistory";
+static SAPI_V1_ETH_STAKING_WBETH_WRAP_HISTORY: &str = "/sapi/v1/eth-staking/wbeth/history/wrapHistory";
+static SAPI_V1_ETH_STAKING_WBETH_UNWRAP_HISTORY: &str = "/sapi/v1/eth-staking/wbeth/history/unwrapHistory";
+static SAPI_V1_SOL_STAKING_ACCOUNT: &str = "/sapi/v1/sol-staking/account";
+static SAPI_V1_SOL_STAKING_SOL_STAKE: &str = "/sapi/v1/sol-staking/sol/stake";
+static SAPI_V1_SOL_STAKING_SOL_REDEEM: &str = "/sapi/v1/sol-staking/sol/redeem";
+static SAPI_V1_SOL_STAKING_SOL_QUOTA: &str = "/sapi/v1/sol-staking/sol/quota";
+static SAPI_V1_SOL_STAKING_SOL_STAKING_HISTORY: &str = "/sapi/v1/sol-staking/sol/history/stakingHistory";
+static SAPI_V1_SOL_STAKING_SOL_REDEMPTION_HISTORY: &str = "/sapi/v1/sol-staking/sol/history/redemptionHistory";
+static SAPI_V1_SOL_STAKING_SOL_BNSOL_REWARDS_HISTORY: &str = "/sapi/v1/sol-staking/sol/history/bnsolRewardsHistory";
+static SAPI_V1_SOL_STAKING_SOL_RATE_HISTORY: &str = "/sapi/v1/sol-staking/sol/history/rateHistory";
+static SAPI_V1_STAKING_PRODUCT_LIST: &str = "/sapi/v1/staking/productList";
+static SAPI_V1_STAKING_PURCHASE: &str = "/sapi/v1/staking/purchase";
+static SAPI_V1_STAKING_REDEEM: &str = "/sapi/v1/staking/redeem";
+static SAPI_V1_STAKING_POSITION: &str = "/sapi/v1/staking/position";
+static SAPI_V1_STAKING_PERSONAL_LEFT_QUOTA: &str = "/sapi/v1/staking/personalLeftQuota";
+static SAPI_V1_STAKING_STAKING_RECORD: &str = "/sapi/v1/staking/stakingRecord";
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct StakingHistoryQuery {
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    /// Page number, starting at 1
+    pub current: Option<u64>,
+    /// Default: 10, Max: 100
+    pub size: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct EthStakingAccount {
+    /// Value of the WBETH and BETH held, in ETH
+    #[serde(rename = "holdingInETH", with = "string_or_float")]
+    pub holding_in_eth: f64,
+    pub holdings: EthStakingHoldings,
+    #[serde(rename = "thirtyDaysProfitInETH", with = "string_or_float")]
+    pub thirty_days_profit_in_eth: f64,
+    pub profit: EthStakingProfit,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct EthStakingHoldings {
+    #[serde(with = "string_or_float")]
+    pub wbeth_amount: f64,
+    #[serde(with = "string_or_float")]
+    pub beth_amount: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct EthStakingProfit {
+    #[serde(rename = "amountFromWBETH", with = "string_or_float")]
+    pub amount_from_wbeth: f64,
+    #[serde(rename = "amountFromBETH", with = "string_or_float")]
+    pub amount_from_beth: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct EthStakeResponse {
+    pub success: bool,
+    #[serde(with = "string_or_float")]
+    pub wbeth_amount: f64,
+    /// ETH per WBETH
+    #[serde(with = "string_or_float")]
+    pub conversion_ratio: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum EthStakingAsset {
+    Wbeth,
+    Beth,
+}
+
+#[derive(Serialize)]
+struct EthRedeemRequest {
+    amount: f64,
+    asset: Option<EthStakingAsset>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct EthRedeemResponse {
+    pub success: bool,
+    #[serde(with = "string_or_float")]
+    pub eth_amount: f64,
+    #[serde(with = "string_or_float")]
+    pub conversion_ratio: f64,
+    pub arrival_time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct WbethWrapResponse {
+    pub success: bool,
+    #[serde(with = "string_or_float")]
+    pub wbeth_amount: f64,
+    #[serde(with = "string_or_float")]
+    pub exchange_rate: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct EthStakingQuota {
+    #[serde(with = "string_or_float")]
+    pub left_staking_personal_quota: f64,
+    #[serde(with = "string_or_float")]
+    pub left_redemption_personal_quota: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct EthStakingRecord {
+    pub time: u64,
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    pub status: String,
+    /// WBETH received
+    #[serde(with = "string_or_float")]
+    pub distribute_amount: f64,
+    #[serde(with = "string_or_float")]
+    pub conversion_ratio: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct EthRedemptionRecord {
+    pub time: u64,
+    pub arrival_time: u64,
+    /// WBETH or BETH
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    pub status: String,
+    pub distribute_asset: String,
+    #[serde(with = "string_or_float")]
+    pub distribute_amount: f64,
+    #[serde(with = "string_or_float")]
+    pub conversion_ratio: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct EthRewardRecord {
+    pub time: u64,
+    pub asset: String,
+    /// BETH held when the reward was distributed
+    #[serde(with = "string_or_float")]
+    pub holding: f64,
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    #[serde(with = "string_or_float")]
+    pub annual_percentage_rate: f64,
+    pub status: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct EthRateRecord {
+    #[serde(with = "string_or_float")]
+    pub annual_percentage_rate: f64,
+    /// ETH per WBETH
+    #[serde(with = "string_or_float")]
+    pub exchange_rate: f64,
+    pub time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct WbethWrapRecord {
+    pub time: u64,
+    pub from_asset: String,
+    #[serde(with = "string_or_float")]
+    pub from_amount: f64,
+    pub to_asset: String,
+    #[serde(with = "string_or_float")]
+    pub to_amount: f64,
+    #[serde(with = "string_or_float")]
+    pub exchange_rate: f64,
+    pub status: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SolStakingAccount {
+    #[serde(with = "string_or_float")]
+    pub bnsol_amount: f64,
+    /// Value of the BNSOL held, in SOL
+    #[serde(rename = "holdingInSOL", with = "string_or_float")]
+    pub holding_in_sol: f64,
+    #[serde(rename = "thirtyDaysProfitInSOL", with = "string_or_float")]
+    pub thirty_days_profit_in_sol: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SolStakeResponse {
+    pub success: bool,
+    #[serde(with = "string_or_float")]
+    pub bnsol_amount: f64,
+    /// SOL per BNSOL
+    #[serde(with = "string_or_float")]
+    pub exchange_rate: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SolRedeemResponse {
+    pub success: bool,
+    #[serde(with = "string_or_float")]
+    pub sol_amount: f64,
+    #[serde(with = "string_or_float")]
+    pub exchange_rate: f64,
+    pub arrival_time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SolStakingQuota {
+    #[serde(with = "string_or_float")]
+    pub left_staking_personal_quota: f64,
+    #[serde(with = "string_or_float")]
+    pub left_redemption_personal_quota: f64,
+    #[serde(with = "string_or_float")]
+    pub min_stake_amount: f64,
+    #[serde(with = "string_or_float")]
+    pub min_redeem_amount: f64,
+    /// Days before redeemed SOL arrives
+    pub redeem_period: u64,
+    pub stakeable: bool,
+    pub redeemable: bool,
+    pub sold_out: bool,
+    #[serde(with = "string_or_float")]
+    pub commission_fee: f64,
+    pub next_epoch_time: u64,
+    /// Rewards of the epoch are being calculated, staking and redemption are unavailable
+    pub calculating: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SolStakingRecord {
+    pub time: u64,
+    /// SOL when staking, BNSOL when redeeming
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    pub distribute_asset: String,
+    #[serde(with = "string_or_float")]
+    pub distribute_amount: f64,
+    #[serde(with = "string_or_float")]
+    pub exchange_rate: f64,
+    pub status: String,
+    /// Only returned for redemptions
+    pub arrival_time: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BnsolRewards {
+    /// Estimated rewards over the queried period, in SOL
+    #[serde(rename = "estRewardsInSOL", with = "string_or_float")]
+    pub est_rewards_in_sol: f64,
+    pub rows: Option<Vec<BnsolRewardRecord>>,
+    pub total: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BnsolRewardRecord {
+    pub time: u64,
+    #[serde(rename = "amountInSOL", with = "string_or_float")]
+    pub amount_in_sol: f64,
+    /// BNSOL held when the rewards were accrued
+    #[serde(with = "string_or_float")]
+    pub holding: f64,
+    #[serde(rename = "holdingInSOL", with = "string_or_float")]
+    pub holding_in_sol: f64,
+    #[serde(with = "string_or_float")]
+    pub annual_percentage_rate: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SolRateRecord {
+    #[serde(with = "string_or_float")]
+    pub annual_percentage_rate: f64,
+    /// SOL per BNSOL
+    #[serde(with = "string_or_float")]
+    pub exchange_rate: f64,
+    pub time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum LegacyStakingProduct {
+    /// Locked staking
+    #[default]
+    Staking,
+    /// Flexible DeFi staking
+    FDefi,
+    /// Locked DeFi staking
+    LDefi,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct LegacyStakingProductQuery {
+    pub product: LegacyStakingProduct,
+    pub asset: Option<String>,
+    /// Page number, starting at 1
+    pub current: Option<u64>,
+    /// Default: 10, Max: 100
+    pub size: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LegacyStakingProductInfo {
+    pub project_id: String,
+    pub detail: LegacyStakingProductDetail,
+    pub quota: LegacyStakingProductQuota,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LegacyStakingProductDetail {
+    pub asset: String,
+    pub reward_asset: String,
+    /// Lock period in days, -1 for flexible DeFi staking
+    pub duration: i64,
+    pub renewable: bool,
+    #[serde(with = "string_or_float")]
+    pub apy: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LegacyStakingProductQuota {
+    #[serde(with = "string_or_float")]
+    pub total_personal_quota: f64,
+    #[serde(with = "string_or_float")]
+    pub minimum: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct LegacyStakingPurchaseQuery {
+    pub product: LegacyStakingProduct,
+    pub product_id: String,
+    pub amount: f64,
+    /// Default: false
+    pub renewable: Option<bool>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LegacyStakingPurchaseResponse {
+    pub position_id: String,
+    pub success: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct LegacyStakingRedeemQuery {
+    pub product: LegacyStakingProduct,
+    pub product_id: String,
+    /// Mandatory for locked staking and locked DeFi staking
+    pub position_id: Option<String>,
+    /// Mandatory for flexible DeFi staking
+    pub amount: Option<f64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LegacyStakingRedeemResponse {
+    pub success: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct LegacyStakingPositionQuery {
+    pub product: LegacyStakingProduct,
+    pub product_id: Option<String>,
+    pub asset: Option<String>,
+    /// Page number, starting at 1
+    pub current: Option<u64>,
+    /// Default: 10, Max: 100
+    pub size: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LegacyStakingPosition {
+    pub position_id: Option<String>,
+    pub project_id: Option<String>,
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    #[serde(default, with = "string_or_u64_opt")]
+    pub purchase_time: Option<u64>,
+    /// Lock period in days
+    #[serde(default, with = "string_or_u64_opt")]
+    pub duration: Option<u64>,
+    #[serde(default, with = "string_or_u64_opt")]
+    pub accrual_days: Option<u64>,
+    pub reward_asset: String,
+    #[serde(rename = "APY", with = "string_or_float")]
+    pub apy: f64,
+    #[serde(default, with = "string_or_float_opt")]
+    pub reward_amt: Option<f64>,
+    pub extra_reward_asset: Option<String>,
+    #[serde(rename = "extraRewardAPY", default, with = "string_or_float_opt")]
+    pub extra_reward_apy: Option<f64>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub est_extra_reward_amt: Option<f64>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub next_interest_pay: Option<f64>,
+    #[serde(default, with = "string_or_u64_opt")]
+    pub next_interest_pay_date: Option<u64>,
+    /// Amount returned when redeeming before the end of the lock period
+    #[serde(default, with = "string_or_float_opt")]
+    pub redeem_amount_early: Option<f64>,
+    #[serde(default, with = "string_or_u64_opt")]
+    pub interest_end_date: Option<u64>,
+    #[serde(default, with = "string_or_u64_opt")]
+    pub deliver_date: Option<u64>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub redeeming_amt: Option<f64>,
+    pub can_redeem_early: Option<bool>,
+    pub renewable: Option<bool>,
+    /// AUTO or NORMAL
+    #[serde(rename = "type")]
+    pub position_type: Option<String>,
+    pub status: Option<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct LegacyStakingQuotaRequest {
+    product: LegacyStakingProduct,
+    product_id: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LegacyStakingQuota {
+    #[serde(with = "string_or_float")]
+    left_personal_quota: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum LegacyStakingTxnType {
+    #[default]
+    Subscription,
+    Redemption,
+    Interest,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct LegacyStakingHistoryQuery {
+    pub product: LegacyStakingProduct,
+    pub txn_type: LegacyStakingTxnType,
+    pub asset: Option<String>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    /// Page number, starting at 1
+    pub current: Option<u64>,
+    /// Default: 10, Max: 100
+    pub size: Option<u64>,
+}
+
+/// Subscription, redemption or interest record, the fields returned depend on the transaction type
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LegacyStakingRecord {
+    pub position_id: Option<String>,
+    pub time: u64,
+    pub asset: String,
+    pub project: Option<String>,
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    #[serde(default, with = "string_or_u64_opt")]
+    pub lock_period: Option<u64>,
+    #[serde(default, with = "string_or_u64_opt")]
+    pub deliver_date: Option<u64>,
+    #[serde(rename = "type")]
+    pub record_type: Option<String>,
+    pub status: Option<String>,
+}
+
+/// This struct acts as a gateway for all staking endpoints.
+/// Preferably use the trait [`crate::api::Binance`] to get an instance.
+#[derive(Clone)]
+pub struct Staking {
+    pub client: Client,
+    pub recv_window: u64,
+}
+
+impl Staking {
+    /// WBETH and BETH holdings and profits
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, staking::*, config::*};
+    /// let staking: Staking = Binance::new_with_env(&Config::testnet());
+    /// let account = tokio_test::block_on(staking.eth_account());
+    /// assert!(account.is_ok(), "{:?}", account);
+    /// ```
+    pub async fn eth_account(&self) -> Result<EthStakingAccount> {
+        self.client
+            .get_signed_p(SAPI_V2_ETH_STAKING_ACCOUNT, Option::<String>::None, self.recv_window)
+            .await
+    }
+
+    /// Stake ETH, which is converted into WBETH
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, staking::*, config::*};
+    /// let staking: Staking = Binance::new_with_env(&Config::testnet());
+    /// let stake = tokio_test::block_on(staking.stake_eth(0.1));
+    /// assert!(stake.is_ok(), "{:?}", stake);
+    /// ```
+    pub async fn stake_eth(&self, amount: f64) -> Result<EthStakeResponse> {
+        let mut params = HashMap::new();
+        params.insert("amount", amount);
+        self.client
+            .post_signed_p(SAPI_V2_ETH_STAKING_ETH_STAKE, Some(params), self.recv_window)
+            .await
+    }
+
+    /// Redeem WBETH or BETH for ETH, also used to unwrap WBETH, WBETH by default
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, staking::*, config::*};
+    /// let staking: Staking = Binance::new_with_env(&Config::testnet());
+    /// let redemption = tokio_test::block_on(staking.redeem_eth(0.1, Some(EthStakingAsset::Wbeth)));
+    /// assert!(redemption.is_ok(), "{:?}", redemption);
+    /// ```
+    pub async fn redeem_eth(&self, amount: f64, asset: Option<EthStakingAsset>) -> Result<EthRedeemResponse> {
+        self.client
+            .post_signed_p(
+                SAPI_V1_ETH_STAKING_ETH_REDEEM,
+                Some(EthRedeemRequest { amount, asset }),
+                self.recv_window,
+            )
+            .await
+    }
+
+    /// Wrap BETH into WBETH
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, staking::*, config::*};
+    /// let staking: Staking = Binance::new_with_env(&Config::testnet());
+    /// let wrap = tokio_test::block_on(staking.wrap_beth(0.1));
+    /// assert!(wrap.is_ok(), "{:?}", wrap);
+    /// ```
+    pub async fn wrap_beth(&self, amount: f64) -> Result<WbethWrapResponse> {
+        let mut params = HashMap::new();
+        params.insert("amount", amount);
+        self.client
+            .post_signed_p(SAPI_V1_ETH_STAKING_WBETH_WRAP, Some(params), self.recv_window)
+            .await
+    }
+
+    /// Remaining personal quota to stake and redeem ETH
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, staking::*, config::*};
+    /// let staking: Staking = Binance::new_with_env(&Config::testnet());
+    /// let quota = tokio_test::block_on(staking.eth_quota());
+    /// assert!(quota.is_ok(), "{:?}", quota);
+    /// ```
+    pub async fn eth_quota(&self) -> Result<EthStakingQuota> {
+        self.client
+            .get_signed_p(SAPI_V1_ETH_STAKING_ETH_QUOTA, Option::<String>::None, self.recv_window)
+            .await
+    }
+
+    /// ETH staking history
+    /// If startTime and endTime not sent, return records of the last 90 days by default
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, staking::*, config::*};
+    /// let staking: Staking = Binance::new_with_env(&Config::testnet());
+    /// let records = tokio_test::block_on(staking.eth_staking_history(StakingHistoryQuery::default()));
+    /// assert!(records.is_ok(), "{:?}", records);
+    /// ```
+    pub async fn eth_staking_history(
+        &self,
+        query: StakingHistoryQuery,
+    ) -> Result<RecordsQueryResult<EthStakingRecord>> {
+        self.client
+            .get_signed_p(SAPI_V1_ETH_STAKING_ETH_STAKING_HISTORY, Some(query), self.recv_window)
+            .await
+    }
+
+    /// ETH redemption history
+    /// If startTime and endTime not sent, return records of the last 90 days by default
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, staking::*, config::*};
+    /// let staking: Staking = Binance::new_with_env(&Config::testnet());
+    /// let records = tokio_test::block_on(staking.eth_redemption_history(StakingHistoryQuery::default()));
+    /// assert!(records.is_ok(), "{:?}", records);
+    /// ```
+    pub async fn eth_redemption_history(
+        &self,
+        query: StakingHistoryQuery,
+    ) -> Result<RecordsQueryResult<EthRedemptionRecord>> {
+        self.client
+            .get_signed_p(
+                SAPI_V1_ETH_STAKING_ETH_REDEMPTION_HISTORY,
+                Some(query),
+                self.recv_window,
+            )
+            .await
+    }
+
+    /// BETH rewards distribution history
+    /// If startTime and endTime not sent, return records of the last 90 days by default
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, staking::*, config::*};
+    /// let staking: Staking = Binance::new_with_env(&Config::testnet());
+    /// let records = tokio_test::block_on(staking.eth_rewards_history(StakingHistoryQuery::default()));
+    /// assert!(records.is_ok(), "{:?}", records);
+    /// ```
+    pub async fn eth_rewards_history(&self, query: StakingHistoryQuery) -> Result<RecordsQueryResult<EthRewardRecord>> {
+        self.client
+            .get_signed_p(SAPI_V1_ETH_STAKING_ETH_REWARDS_HISTORY, Some(query), self.recv_window)
+            .await
+    }
+
+    /// History of the annual percentage rate and WBETH exchange rate
+    /// If startTime and endTime not sent, return records of the last 90 days by default
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, staking::*, config::*};
+    /// let staking: Staking = Binance::new_with_env(&Config::testnet());
+    /// let records = tokio_test::block_on(staking.eth_rate_history(StakingHistoryQuery::default()));
+    /// assert!(records.is_ok(), "{:?}", records);
+    /// ```
+    pub async fn eth_rate_history(&self, query: StakingHistoryQuery) -> Result<RecordsQueryResult<EthRateRecord>> {
+        self.client
+            .get_signed_p(SAPI_V1_ETH_STAKING_ETH_RATE_HISTORY, Some(query), self.recv_window)
+            .await
+    }
+
+    /// BETH to WBETH wrap history
+    /// If startTime and endTime not sent, return records of the last 90 days by default
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, staking::*, config::*};
+    /// let staking: Staking = Binance::new_with_env(&Config::testnet());
+    /// let records = tokio_test::block_on(staking.wbeth_wrap_history(StakingHistoryQuery::default()));
+    /// assert!(records.is_ok(), "{:?}", records);
+    /// ```
+    pub async fn wbeth_wrap_history(&self, query: StakingHistoryQuery) -> Result<RecordsQueryResult<WbethWrapRecord>> {
+        self.client
+            .get_signed_p(SAPI_V1_ETH_STAKING_WBETH_WRAP_HISTORY, Some(query), self.recv_window)
+            .await
+    }
+
+    /// WBETH unwrap history
+    /// If startTime and endTime not sent, return records of the last 90 days by default
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, staking::*, config::*};
+    /// let staking: Staking = Binance::new_with_env(&Config::testnet());
+    /// let records = tokio_test::block_on(staking.wbeth_unwrap_history(StakingHistoryQuery::default()));
+    /// assert!(records.is_ok(), "{:?}", records);
+    /// ```
+    pub async fn wbeth_unwrap_history(
+        &self,
+        query: StakingHistoryQuery,
+    ) -> Result<RecordsQueryResult<WbethWrapRecord>> {
+        self.client
+            .get_signed_p(SAPI_V1_ETH_STAKING_WBETH_UNWRAP_HISTORY, Some(query), self.recv_window)
+            .await
+    }
+
+    /// BNSOL holdings and profits
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, staking::*, config::*};
+    /// let staking: Staking = Binance::new_with_env(&Config::testnet());
+    /// let account = tokio_test::block_on(staking.sol_account());
+    /// assert!(account.is_ok(), "{:?}", account);
+    /// ```
+    pub async fn sol_account(&self) -> Result<SolStakingAccount> {
+        self.client
+            .get_signed_p(SAPI_V1_SOL_STAKING_ACCOUNT, Option::<String>::None, self.recv_window)
+            .await
+    }
+
+    /// Stake SOL, which is converted into BNSOL
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, staking::*, config::*};
+    /// let staking: Staking = Binance::new_with_env(&Config::testnet());
+    /// let stake = tokio_test::block_on(staking.stake_sol(1.0));
+    /// assert!(stake.is_ok(), "{:?}", stake);
+    /// ```
+    pub async fn stake_sol(&self, amount: f64) -> Result<SolStakeResponse> {
+        let mut params = HashMap::new();
+        params.insert("amount", amount);
+        self.client
+            .post_signed_p(SAPI_V1_SOL_STAKING_SOL_STAKE, Some(params), self.recv_window)
+            .await
+    }
+
+    /// Redeem BNSOL for SOL, which arrives after the redeem period of [`SolStakingQuota`]
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, staking::*, config::*};
+    /// let staking: Staking = Binance::new_with_env(&Config::testnet());
+    /// let redemption = tokio_test::block_on(staking.redeem_sol(1.0));
+    /// assert!(redemption.is_ok(), "{:?}", redemption);
+    /// ```
+    pub async fn redeem_sol(&self, amount: f64) -> Result<SolRedeemResponse> {
+        let mut params = HashMap::new();
+        params.insert("amount", amount);
+        self.client
+            .post_signed_p(SAPI_V1_SOL_STAKING_SOL_REDEEM, Some(params), self.recv_window)
+            .await
+    }
+
+    /// Remaining personal quota, limits and availability of SOL staking
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, staking::*, config::*};
+    /// let staking: Staking = Binance::new_with_env(&Config::testnet());
+    /// let quota = tokio_test::block_on(staking.sol_quota());
+    /// assert!(quota.is_ok(), "{:?}", quota);
+    /// ```
+    pub async fn sol_quota(&self) -> Result<SolStakingQuota> {
+        self.client
+            .get_signed_p(SAPI_V1_SOL_STAKING_SOL_QUOTA, Option::<String>::None, self.recv_window)
+            .await
+    }
+
+    /// SOL staking history
+    /// If startTime and endTime not sent, return records of the last 90 days by default
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, staking::*, config::*};
+    /// let staking: Staking = Binance::new_with_env(&Config::testnet());
+    /// let records = tokio_test::block_on(staking.sol_staking_history(StakingHistoryQuery::default()));
+    /// assert!(records.is_ok(), "{:?}", records);
+    /// ```
+    pub async fn sol_staking_history(
+        &self,
+        query: StakingHistoryQuery,
+    ) -> Result<RecordsQueryResult<SolStakingRecord>> {
+        self.client
+            .get_signed_p(SAPI_V1_SOL_STAKING_SOL_STAKING_HISTORY, Some(query), self.recv_window)
+            .await
+    }
+
+    /// BNSOL redemption history
+    /// If startTime and endTime not sent, return records of the last 90 days by default
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, staking::*, config::*};
+    /// let staking: Staking = Binance::new_with_env(&Config::testnet());
+    /// let records = tokio_test::block_on(staking.sol_redemption_history(StakingHistoryQuery::default()));
+    /// assert!(records.is_ok(), "{:?}", records);
+    /// ```
+    pub async fn sol_redemption_history(
+        &self,
+        query: StakingHistoryQuery,
+    ) -> Result<RecordsQueryResult<SolStakingRecord>> {
+        self.client
+            .get_signed_p(
+                SAPI_V1_SOL_STAKING_SOL_REDEMPTION_HISTORY,
+                Some(query),
+                self.recv_window,
+            )
+            .await
+    }
+
+    /// BNSOL rewards history, rewards accrue in the BNSOL exchange rate
+    /// If startTime and endTime not sent, return records of the last 90 days by default
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, staking::*, config::*};
+    /// let staking: Staking = Binance::new_with_env(&Config::testnet());
+    /// let records = tokio_test::block_on(staking.bnsol_rewards_history(StakingHistoryQuery::default()));
+    /// assert!(records.is_ok(), "{:?}", records);
+    /// ```
+    pub async fn bnsol_rewards_history(&self, query: StakingHistoryQuery) -> Result<BnsolRewards> {
+        self.client
+            .get_signed_p(
+                SAPI_V1_SOL_STAKING_SOL_BNSOL_REWARDS_HISTORY,
+                Some(query),
+                self.recv_window,
+            )
+            .await
+    }
+
+    /// History of the annual percentage rate and BNSOL exchange rate
+    /// If startTime and endTime not sent, return records of the last 90 days by default
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, staking::*, config::*};
+    /// let staking: Staking = Binance::new_with_env(&Config::testnet());
+    /// let records = tokio_test::block_on(staking.bnsol_rate_history(StakingHistoryQuery::default()));
+    /// assert!(records.is_ok(), "{:?}", records);
+    /// ```
+    pub async fn bnsol_rate_history(&self, query: StakingHistoryQuery) -> Result<RecordsQueryResult<SolRateRecord>> {
+        self.client
+            .get_signed_p(SAPI_V1_SOL_STAKING_SOL_RATE_HISTORY, Some(query), self.recv_window)
+            .await
+    }
+
+    /// Legacy staking products available for purchase
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, staking::*, config::*};
+    /// let staking: Staking = Binance::new_with_env(&Config::testnet());
+    /// let products = tokio_test::block_on(staking.legacy_staking_products(LegacyStakingProductQuery::default()));
+    /// assert!(products.is_ok(), "{:?}", products);
+    /// ```
+    pub async fn legacy_staking_products(
+        &self,
+        query: LegacyStakingProductQuery,
+    ) -> Result<Vec<LegacyStakingProductInfo>> {
+        self.client
+            .get_signed_p(SAPI_V1_STAKING_PRODUCT_LIST, Some(query), self.recv_window)
+            .await
+    }
+
+    /// Purchase a legacy staking product
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, staking::*, config::*};
+    /// let staking: Staking = Binance::new_with_env(&Config::testnet());
+    /// let query = LegacyStakingPurchaseQuery {
+    ///     product_id: "Axs*90".to_string(),
+    ///     amount: 10.0,
+    ///     ..Default::default()
+    /// };
+    /// let purchase = tokio_test::block_on(staking.legacy_staking_purchase(query));
+    /// assert!(purchase.is_ok(), "{:?}", purchase);
+    /// ```
+    pub async fn legacy_staking_purchase(
+        &self,
+        query: LegacyStakingPurchaseQuery,
+    ) -> Result<LegacyStakingPurchaseResponse> {
+        self.client
+            .post_signed_p(SAPI_V1_STAKING_PURCHASE, Some(query), self.recv_window)
+            .await
+    }
+
+    /// Redeem a legacy staking position
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, staking::*, config::*};
+    /// let staking: Staking = Binance::new_with_env(&Config::testnet());
+    /// let query = LegacyStakingRedeemQuery {
+    ///     product_id: "Axs*90".to_string(),
+    ///     position_id: Some("1234".to_string()),
+    ///     ..Default::default()
+    /// };
+    /// let redemption = tokio_test::block_on(staking.legacy_staking_redeem(query));
+    /// assert!(redemption.is_ok(), "{:?}", redemption);
+    /// ```
+    pub async fn legacy_staking_redeem(&self, query: LegacyStakingRedeemQuery) -> Result<LegacyStakingRedeemResponse> {
+        self.client
+            .post_signed_p(SAPI_V1_STAKING_REDEEM, Some(query), self.recv_window)
+            .await
+    }
+
+    /// Legacy staking positions
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, staking::*, config::*};
+    /// let staking: Staking = Binance::new_with_env(&Config::testnet());
+    /// let positions = tokio_test::block_on(staking.legacy_staking_positions(LegacyStakingPositionQuery::default()));
+    /// assert!(positions.is_ok(), "{:?}", positions);
+    /// ```
+    pub async fn legacy_staking_positions(
+        &self,
+        query: LegacyStakingPositionQuery,
+    ) -> Result<Vec<LegacyStakingPosition>> {
+        self.client
+            .get_signed_p(SAPI_V1_STAKING_POSITION, Some(query), self.recv_window)
+            .await
+    }
+
+    /// Remaining personal quota of a legacy staking product, fails if Binance does not know the product
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, staking::*, config::*};
+    /// let staking: Staking = Binance::new_with_env(&Config::testnet());
+    /// let quota = tokio_test::block_on(staking.legacy_staking_quota(LegacyStakingProduct::Staking, "Axs*90"));
+    /// assert!(quota.is_ok(), "{:?}", quota);
+    /// ```
+    pub async fn legacy_staking_quota<S>(&self, product: LegacyStakingProduct, product_id: S) -> Result<f64>
+    where
+        S: Into<String>,
+    {
+        let product_id = product_id.into();
+        let quotas: Vec<LegacyStakingQuota> = self
+            .client
+            .get_signed_p(
+                SAPI_V1_STAKING_PERSONAL_LEFT_QUOTA,
+                Some(LegacyStakingQuotaRequest {
+                    product,
+                    product_id: product_id.clone(),
+                }),
+                self.recv_window,
+            )
+            .await?;
+        quotas
+            .first()
+            .map(|q| q.left_personal_quota)
+            .ok_or_else(|| Error::Msg(format!("no quota for the legacy staking product {product_id}")))
+    }
+
+    /// Legacy staking subscriptions, redemptions or interests
+    /// If startTime and endTime not sent, return records of the last 30 days by default
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, staking::*, config::*};
+    /// let staking: Staking = Binance::new_with_env(&Config::testnet());
+    /// let query = LegacyStakingHistoryQuery {
+    ///     txn_type: LegacyStakingTxnType::Interest,
+    ///     ..Default::default()
+    /// };
+    /// let records = tokio_test::block_on(staking.legacy_staking_history(query));
+    /// assert!(records.is_ok(), "{:?}", records);
+    /// ```
+    pub async fn legacy_staking_history(&self, query: LegacyStakingHistoryQuery) -> Result<Vec<LegacyStakingRecord>> {
+        self.client
+            .get_signed_p(SAPI_V1_STAKING_STAKING_RECORD, Some(query), self.recv_window)
+            .await
+    }
+}
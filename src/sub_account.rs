@@ -0,0 +1,615 @@
+//! Sub-accounts of a master account.
+
+use std::collections::HashMap;
+
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+use crate::client::*;
+use crate::errors::*;
+use crate::rest_model::{string_or_bool, string_or_float, string_or_float_opt, AccountSnapshot, AccountSnapshotType,
+                        TransactionId, UniversalTransferSubAccount};
+
+static SAPI_V2_SUB_ACCOUNT_FUTURES_ACCOUNT_SUMMARY: &str = "/sapi/v2/sub-account/futures/accountSummary";
+static SAPI_V1_SUB_ACCOUNT_MARGIN_ACCOUNT_SUMMARY: &str = "/sapi/v1/sub-account/margin/accountSummary";
+static SAPI_V2_SUB_ACCOUNT_FUTURES_POSITION_RISK: &str = "/sapi/v2/sub-account/futures/positionRisk";
+static SAPI_V1_SUB_ACCOUNT_API_IP_RESTRICTION: &str = "/sapi/v1/sub-account/subAccountApi/ipRestriction";
+static SAPI_V2_SUB_ACCOUNT_API_IP_RESTRICTION: &str = "/sapi/v2/sub-account/subAccountApi/ipRestriction";
+static SAPI_V1_SUB_ACCOUNT_API_IP_RESTRICTION_IP_LIST: &str = "/sapi/v1/sub-account/subAccountApi/ipRestriction/ipList";
+static SAPI_V1_SUB_ACCOUNT_UNIVERSAL_TRANSFER: &str = "/sapi/v1/sub-account/universalTransfer";
+static SAPI_V1_MANAGED_SUBACCOUNT_DEPOSIT: &str = "/sapi/v1/managed-subaccount/deposit";
+static SAPI_V1_MANAGED_SUBACCOUNT_WITHDRAW: &str = "/sapi/v1/managed-subaccount/withdraw";
+static SAPI_V1_MANAGED_SUBACCOUNT_ASSET: &str = "/sapi/v1/managed-subaccount/asset";
+static SAPI_V1_MANAGED_SUBACCOUNT_ACCOUNTSNAPSHOT: &str = "/sapi/v1/managed-subaccount/accountSnapshot";
+
+#[derive(Debug, Serialize_repr, Deserialize_repr, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum FuturesType {
+    UsdM = 1,
+    CoinM = 2,
+}
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+struct FuturesAccountSummaryQuery {
+    futures_type: FuturesType,
+    page: Option<u32>,
+    limit: Option<u32>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+struct FuturesPositionRiskQuery<'a> {
+    email: &'a str,
+    futures_type: FuturesType,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct UsdMAccountSummaryResponse {
+    future_account_summary_resp: UsdMAccountSummary,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CoinMAccountSummaryResponse {
+    delivery_account_summary_resp: CoinMAccountSummary,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct UsdMPositionRiskResponse {
+    #[serde(default)]
+    future_position_risk_vos: Vec<UsdMPositionRisk>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CoinMPositionRiskResponse {
+    #[serde(default)]
+    delivery_position_risk_vos: Vec<CoinMPositionRisk>,
+}
+
+/// USDⓈ-M futures accounts of the sub-accounts, with their totals
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UsdMAccountSummary {
+    #[serde(with = "string_or_float")]
+    pub total_initial_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub total_maintenance_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub total_margin_balance: f64,
+    #[serde(with = "string_or_float")]
+    pub total_open_order_initial_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub total_position_initial_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub total_unrealized_profit: f64,
+    #[serde(with = "string_or_float")]
+    pub total_wallet_balance: f64,
+    pub asset: String,
+    pub sub_account_list: Vec<UsdMSubAccountSummary>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UsdMSubAccountSummary {
+    pub email: String,
+    #[serde(with = "string_or_float")]
+    pub total_initial_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub total_maintenance_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub total_margin_balance: f64,
+    #[serde(with = "string_or_float")]
+    pub total_open_order_initial_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub total_position_initial_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub total_unrealized_profit: f64,
+    #[serde(with = "string_or_float")]
+    pub total_wallet_balance: f64,
+    pub asset: String,
+}
+
+/// COIN-M futures accounts of the sub-accounts, with their totals in BTC
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CoinMAccountSummary {
+    #[serde(rename = "totalMarginBalanceOfBTC", with = "string_or_float")]
+    pub total_margin_balance_of_btc: f64,
+    #[serde(rename = "totalUnrealizedProfitOfBTC", with = "string_or_float")]
+    pub total_unrealized_profit_of_btc: f64,
+    #[serde(rename = "totalWalletBalanceOfBTC", with = "string_or_float")]
+    pub total_wallet_balance_of_btc: f64,
+    pub asset: String,
+    pub sub_account_list: Vec<CoinMSubAccountSummary>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CoinMSubAccountSummary {
+    pub email: String,
+    #[serde(with = "string_or_float")]
+    pub total_margin_balance: f64,
+    #[serde(with = "string_or_float")]
+    pub total_unrealized_profit: f64,
+    #[serde(with = "string_or_float")]
+    pub total_wallet_balance: f64,
+    pub asset: String,
+}
+
+/// Margin accounts of the sub-accounts, with their totals in BTC
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginAccountSummary {
+    #[serde(with = "string_or_float")]
+    pub total_asset_of_btc: f64,
+    #[serde(with = "string_or_float")]
+    pub total_liability_of_btc: f64,
+    #[serde(with = "string_or_float")]
+    pub total_net_asset_of_btc: f64,
+    pub sub_account_list: Vec<MarginSubAccountSummary>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginSubAccountSummary {
+    pub email: String,
+    #[serde(with = "string_or_float")]
+    pub total_asset_of_btc: f64,
+    #[serde(with = "string_or_float")]
+    pub total_liability_of_btc: f64,
+    #[serde(with = "string_or_float")]
+    pub total_net_asset_of_btc: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UsdMPositionRisk {
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub entry_price: f64,
+    #[serde(with = "string_or_float")]
+    pub mark_price: f64,
+    #[serde(with = "string_or_float")]
+    pub leverage: f64,
+    #[serde(with = "string_or_float")]
+    pub max_notional: f64,
+    #[serde(with = "string_or_float")]
+    pub liquidation_price: f64,
+    #[serde(with = "string_or_float")]
+    pub position_amount: f64,
+    #[serde(with = "string_or_float")]
+    pub unrealized_profit: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CoinMPositionRisk {
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub entry_price: f64,
+    #[serde(with = "string_or_float")]
+    pub mark_price: f64,
+    #[serde(with = "string_or_float")]
+    pub leverage: f64,
+    pub isolated: bool,
+    #[serde(default, with = "string_or_float_opt")]
+    pub isolated_wallet: Option<f64>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub isolated_margin: Option<f64>,
+    #[serde(default)]
+    pub is_auto_add_margin: bool,
+    /// BOTH, LONG or SHORT
+    pub position_side: String,
+    #[serde(with = "string_or_float")]
+    pub position_amount: f64,
+    #[serde(with = "string_or_float")]
+    pub unrealized_profit: f64,
+}
+
+/// IP restriction of an API key of a sub-account, see [`crate::broker::BrokerIpRestriction`] for broker sub-accounts
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountIpRestriction {
+    pub api_key: String,
+    /// Not returned when setting the restriction
+    #[serde(default, with = "string_or_bool")]
+    pub ip_restrict: bool,
+    #[serde(default)]
+    pub ip_list: Vec<String>,
+    pub update_time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountTransfer {
+    pub tran_id: u64,
+    pub client_tran_id: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ManagedSubAccountDepositQuery {
+    /// Email of the managed sub account
+    pub to_email: String,
+    pub asset: String,
+    pub amount: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ManagedSubAccountWithdrawQuery {
+    /// Email of the managed sub account
+    pub from_email: String,
+    pub asset: String,
+    pub amount: f64,
+    /// Withdrawal occurs on this date (UTC0), immediately if None
+    pub transfer_date: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ManagedSubAccountSnapshotQuery {
+    /// Email of the managed sub account
+    pub email: String,
+    #[serde(rename = "type")]
+    pub account_type: AccountSnapshotType,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    /// min 7, max 30, default 7
+    pub limit: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ManagedSubAccountAsset {
+    pub coin: String,
+    pub name: String,
+    #[serde(with = "string_or_float")]
+    pub total_balance: f64,
+    #[serde(with = "string_or_float")]
+    pub available_balance: f64,
+    #[serde(with = "string_or_float")]
+    pub in_order: f64,
+    #[serde(with = "string_or_float")]
+    pub btc_value: f64,
+}
+
+/// This struct acts as a gateway for the sub-account endpoints of a master account.
+/// Preferably use the trait [`crate::api::Binance`] to get an instance.
+#[derive(Clone)]
+pub struct SubAccount {
+    pub client: Client,
+    pub recv_window: u64,
+}
+
+impl SubAccount {
+    /// USDⓈ-M futures account summary of the sub-accounts, `limit` defaults to 10 and is at most 20
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, config::*, sub_account::*};
+    /// let sub_account: SubAccount = Binance::new_with_env(&Config::default());
+    /// let summary = tokio_test::block_on(sub_account.usd_m_futures_account_summary(None, Some(20)));
+    /// assert!(summary.is_ok(), "{:?}", summary);
+    /// ```
+    pub async fn usd_m_futures_account_summary(
+        &self,
+        page: Option<u32>,
+        limit: Option<u32>,
+    ) -> Result<UsdMAccountSummary> {
+        let query = FuturesAccountSummaryQuery {
+            futures_type: FuturesType::UsdM,
+            page,
+            limit,
+        };
+        let response: UsdMAccountSummaryResponse = self
+            .client
+            .get_signed_p(
+                SAPI_V2_SUB_ACCOUNT_FUTURES_ACCOUNT_SUMMARY,
+                Some(query),
+                self.recv_window,
+            )
+            .await?;
+        Ok(response.future_account_summary_resp)
+    }
+
+    /// COIN-M futures account summary of the sub-accounts, `limit` defaults to 10 and is at most 20
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, config::*, sub_account::*};
+    /// let sub_account: SubAccount = Binance::new_with_env(&Config::default());
+    /// let summary = tokio_test::block_on(sub_account.coin_m_futures_account_summary(None, None));
+    /// assert!(summary.is_ok(), "{:?}", summary);
+    /// ```
+    pub async fn coin_m_futures_account_summary(
+        &self,
+        page: Option<u32>,
+        limit: Option<u32>,
+    ) -> Result<CoinMAccountSummary> {
+        let query = FuturesAccountSummaryQuery {
+            futures_type: FuturesType::CoinM,
+            page,
+            limit,
+        };
+        let response: CoinMAccountSummaryResponse = self
+            .client
+            .get_signed_p(
+                SAPI_V2_SUB_ACCOUNT_FUTURES_ACCOUNT_SUMMARY,
+                Some(query),
+                self.recv_window,
+            )
+            .await?;
+        Ok(response.delivery_account_summary_resp)
+    }
+
+    /// Margin account summary of the sub-accounts
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, config::*, sub_account::*};
+    /// let sub_account: SubAccount = Binance::new_with_env(&Config::default());
+    /// let summary = tokio_test::block_on(sub_account.margin_account_summary());
+    /// assert!(summary.is_ok(), "{:?}", summary);
+    /// ```
+    pub async fn margin_account_summary(&self) -> Result<MarginAccountSummary> {
+        self.client
+            .get_signed_p(
+                SAPI_V1_SUB_ACCOUNT_MARGIN_ACCOUNT_SUMMARY,
+                Option::<String>::None,
+                self.recv_window,
+            )
+            .await
+    }
+
+    /// USDⓈ-M futures positions of the sub-account `email`
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, config::*, sub_account::*};
+    /// let sub_account: SubAccount = Binance::new_with_env(&Config::default());
+    /// let positions = tokio_test::block_on(sub_account.usd_m_futures_position_risk("sub@example.com"));
+    /// assert!(positions.is_ok(), "{:?}", positions);
+    /// ```
+    pub async fn usd_m_futures_position_risk(&self, email: &str) -> Result<Vec<UsdMPositionRisk>> {
+        let query = FuturesPositionRiskQuery {
+            email,
+            futures_type: FuturesType::UsdM,
+        };
+        let response: UsdMPositionRiskResponse = self
+            .client
+            .get_signed_p(SAPI_V2_SUB_ACCOUNT_FUTURES_POSITION_RISK, Some(query), self.recv_window)
+            .await?;
+        Ok(response.future_position_risk_vos)
+    }
+
+    /// COIN-M futures positions of the sub-account `email`
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, config::*, sub_account::*};
+    /// let sub_account: SubAccount = Binance::new_with_env(&Config::default());
+    /// let positions = tokio_test::block_on(sub_account.coin_m_futures_position_risk("sub@example.com"));
+    /// assert!(positions.is_ok(), "{:?}", positions);
+    /// ```
+    pub async fn coin_m_futures_position_risk(&self, email: &str) -> Result<Vec<CoinMPositionRisk>> {
+        let query = FuturesPositionRiskQuery {
+            email,
+            futures_type: FuturesType::CoinM,
+        };
+        let response: CoinMPositionRiskResponse = self
+            .client
+            .get_signed_p(SAPI_V2_SUB_ACCOUNT_FUTURES_POSITION_RISK, Some(query), self.recv_window)
+            .await?;
+        Ok(response.delivery_position_risk_vos)
+    }
+
+    /// IP restriction of an API key of the sub-account `email`
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, config::*, sub_account::*};
+    /// let sub_account: SubAccount = Binance::new_with_env(&Config::default());
+    /// let restriction = tokio_test::block_on(sub_account.ip_restriction("sub@example.com", "api_key"));
+    /// assert!(restriction.is_ok(), "{:?}", restriction);
+    /// ```
+    pub async fn ip_restriction<E, K>(&self, email: E, api_key: K) -> Result<SubAccountIpRestriction>
+    where
+        E: Into<String>,
+        K: Into<String>,
+    {
+        let mut params = HashMap::new();
+        params.insert("email", email.into());
+        params.insert("subAccountApiKey", api_key.into());
+        self.client
+            .get_signed_p(SAPI_V1_SUB_ACCOUNT_API_IP_RESTRICTION, Some(params), self.recv_window)
+            .await
+    }
+
+    /// Restrict an API key of the sub-account `email` to `ip_address`, a comma separated list of IPs,
+    /// or lift the restriction if `restrict` is false
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, config::*, sub_account::*};
+    /// let sub_account: SubAccount = Binance::new_with_env(&Config::default());
+    /// let restriction = tokio_test::block_on(sub_account.set_ip_restriction(
+    ///     "sub@example.com",
+    ///     "api_key",
+    ///     true,
+    ///     Some("1.2.3.4,5.6.7.8".to_string()),
+    /// ));
+    /// assert!(restriction.is_ok(), "{:?}", restriction);
+    /// ```
+    pub async fn set_ip_restriction<E, K>(
+        &self,
+        email: E,
+        api_key: K,
+        restrict: bool,
+        ip_address: Option<String>,
+    ) -> Result<SubAccountIpRestriction>
+    where
+        E: Into<String>,
+        K: Into<String>,
+    {
+        let mut params = HashMap::new();
+        params.insert("email", email.into());
+        params.insert("subAccountApiKey", api_key.into());
+        // 1 lifts the IP restriction, 2 restricts access to the whitelisted IPs
+        params.insert("status", if restrict { "2" } else { "1" }.to_string());
+        if let Some(ip_address) = ip_address {
+            params.insert("ipAddress", ip_address);
+        }
+        self.client
+            .post_signed_p(SAPI_V2_SUB_ACCOUNT_API_IP_RESTRICTION, Some(params), self.recv_window)
+            .await
+    }
+
+    /// Remove `ip_address`, a comma separated list of IPs, from the IP whitelist of an API key of the sub-account
+    /// `email`
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, config::*, sub_account::*};
+    /// let sub_account: SubAccount = Binance::new_with_env(&Config::default());
+    /// let restriction =
+    ///     tokio_test::block_on(sub_account.delete_ip_restriction("sub@example.com", "api_key", "1.2.3.4"));
+    /// assert!(restriction.is_ok(), "{:?}", restriction);
+    /// ```
+    pub async fn delete_ip_restriction<E, K, I>(
+        &self,
+        email: E,
+        api_key: K,
+        ip_address: I,
+    ) -> Result<SubAccountIpRestriction>
+    where
+        E: Into<String>,
+        K: Into<String>,
+        I: Into<String>,
+    {
+        let mut params = HashMap::new();
+        params.insert("email", email.into());
+        params.insert("subAccountApiKey", api_key.into());
+        params.insert("ipAddress", ip_address.into());
+        self.client
+            .delete_signed_p(SAPI_V1_SUB_ACCOUNT_API_IP_RESTRICTION_IP_LIST, params, self.recv_window)
+            .await
+    }
+
+    /// Transfer assets between the accounts of the master account and of its sub-accounts, e.g. from the spot
+    /// account of the master account to the USDⓈ-M futures account of a sub-account
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, config::*, rest_model::*, sub_account::*};
+    /// let sub_account: SubAccount = Binance::new_with_env(&Config::testnet());
+    /// let query = UniversalTransferSubAccount {
+    ///     from_email: "".to_string(),
+    ///     to_email: "sub@test.com".to_string(),
+    ///     from_account_type: "SPOT".to_string(),
+    ///     to_account_type: "USDT_FUTURE".to_string(),
+    ///     asset: "USDT".to_string(),
+    ///     amount: 100.0,
+    /// };
+    /// let transfer = tokio_test::block_on(sub_account.universal_transfer(query));
+    /// assert!(transfer.is_ok(), "{:?}", transfer);
+    /// ```
+    pub async fn universal_transfer(&self, query: UniversalTransferSubAccount) -> Result<SubAccountTransfer> {
+        self.client
+            .post_signed_p(SAPI_V1_SUB_ACCOUNT_UNIVERSAL_TRANSFER, Some(query), self.recv_window)
+            .await
+    }
+
+    /// Deposit assets into a managed sub account, from the investor master account
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, config::*, sub_account::*};
+    /// let sub_account: SubAccount = Binance::new_with_env(&Config::testnet());
+    /// let query = ManagedSubAccountDepositQuery {
+    ///     to_email: "managed@test.com".to_string(),
+    ///     asset: "USDT".to_string(),
+    ///     amount: 100.0,
+    /// };
+    /// let records = tokio_test::block_on(sub_account.managed_sub_account_deposit(query));
+    /// assert!(records.is_ok(), "{:?}", records);
+    /// ```
+    pub async fn managed_sub_account_deposit(&self, query: ManagedSubAccountDepositQuery) -> Result<TransactionId> {
+        self.client
+            .post_signed_p(SAPI_V1_MANAGED_SUBACCOUNT_DEPOSIT, Some(query), self.recv_window)
+            .await
+    }
+
+    /// Withdraw assets from a managed sub account, to the investor master account
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, config::*, sub_account::*};
+    /// let sub_account: SubAccount = Binance::new_with_env(&Config::testnet());
+    /// let query = ManagedSubAccountWithdrawQuery {
+    ///     from_email: "managed@test.com".to_string(),
+    ///     asset: "USDT".to_string(),
+    ///     amount: 100.0,
+    ///     ..Default::default()
+    /// };
+    /// let records = tokio_test::block_on(sub_account.managed_sub_account_withdraw(query));
+    /// assert!(records.is_ok(), "{:?}", records);
+    /// ```
+    pub async fn managed_sub_account_withdraw(&self, query: ManagedSubAccountWithdrawQuery) -> Result<TransactionId> {
+        self.client
+            .post_signed_p(SAPI_V1_MANAGED_SUBACCOUNT_WITHDRAW, Some(query), self.recv_window)
+            .await
+    }
+
+    /// Assets held by a managed sub account
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, config::*, sub_account::*};
+    /// let sub_account: SubAccount = Binance::new_with_env(&Config::testnet());
+    /// let records = tokio_test::block_on(sub_account.managed_sub_account_assets("managed@test.com"));
+    /// assert!(records.is_ok(), "{:?}", records);
+    /// ```
+    pub async fn managed_sub_account_assets<S>(&self, email: S) -> Result<Vec<ManagedSubAccountAsset>>
+    where
+        S: Into<String>,
+    {
+        let mut params = HashMap::new();
+        params.insert("email", email.into());
+        self.client
+            .get_signed_p(SAPI_V1_MANAGED_SUBACCOUNT_ASSET, Some(params), self.recv_window)
+            .await
+    }
+
+    /// Daily account snapshot of a managed sub account
+    /// The query time period must be less then 30 days
+    /// Support query within the last one month only
+    /// If startTime and endTime not sent, return records of the last 7 days by default
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, config::*, sub_account::*};
+    /// let sub_account: SubAccount = Binance::new_with_env(&Config::testnet());
+    /// let query = ManagedSubAccountSnapshotQuery {
+    ///     email: "managed@test.com".to_string(),
+    ///     account_type: binance::rest_model::AccountSnapshotType::Spot,
+    ///     start_time: None,
+    ///     end_time: None,
+    ///     limit: None,
+    /// };
+    /// let records = tokio_test::block_on(sub_account.managed_sub_account_snapshot(query));
+    /// assert!(records.is_ok(), "{:?}", records);
+    /// ```
+    pub async fn managed_sub_account_snapshot(&self, query: ManagedSubAccountSnapshotQuery) -> Result<AccountSnapshot> {
+        self.client
+            .get_signed_p(
+                SAPI_V1_MANAGED_SUBACCOUNT_ACCOUNTSNAPSHOT,
+                Some(query),
+                self.recv_window,
+            )
+            .await
+    }
+}
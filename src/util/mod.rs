@@ -6,6 +6,8 @@ use serde_json::Value;
 
 use crate::errors::*;
 
+pub mod serde_helpers;
+
 // pub fn build_request(parameters: &BTreeMap<String, String>) -> String {
 pub fn build_request(parameters: impl IntoIterator<Item = (impl AsRef<str>, impl AsRef<str>)>) -> String {
     parameters
@@ -71,6 +73,19 @@ where
     Ok(request)
 }
 
+/// Binance expects list parameters as url encoded json arrays, empty lists are omitted
+pub fn json_array_param<T>(name: &'static str, values: &[T]) -> Result<Option<(&'static str, String)>>
+where
+    T: serde::Serialize,
+{
+    if values.is_empty() {
+        return Ok(None);
+    }
+    let json = serde_json::to_string(values)?;
+    let encoded = url::form_urlencoded::byte_serialize(json.as_bytes()).collect();
+    Ok(Some((name, encoded)))
+}
+
 pub fn to_i64(v: &Value) -> i64 {
     // TODO: should this return result?
     v.as_i64().unwrap()
@@ -86,6 +101,39 @@ pub fn get_timestamp() -> Result<u64> { Ok(Utc::now().timestamp_millis() as u64)
 /// Returns a duration in milliseconds for the `days`
 pub fn days_millis(days: i64) -> i64 { Duration::days(days).num_milliseconds() }
 
+/// Split `[start, end]` in consecutive ranges spanning at most `span` milliseconds, for the history endpoints
+/// limiting the time range of a query
+///
+/// # Examples
+/// ```rust
+/// use binance::util::time_windows;
+/// assert_eq!(time_windows(0, 24, 10), vec![(0, 9), (10, 19), (20, 24)]);
+/// ```
+pub fn time_windows(start: u64, end: u64, span: u64) -> Vec<(u64, u64)> {
+    let mut windows = vec![];
+    let mut window_start = start;
+    loop {
+        let window_end = window_start.saturating_add(span - 1).min(end);
+        windows.push((window_start, window_end));
+        if window_end >= end {
+            break;
+        }
+        window_start = window_end + 1;
+    }
+    windows
+}
+
+/// Round `value` down to a multiple of `increment`, tolerating the float error of values already on the grid
+pub fn round_down(value: f64, increment: f64) -> f64 {
+    if increment <= 0.0 {
+        return value;
+    }
+    let steps = (value / increment + 1e-9).floor();
+    let decimals = (-increment.log10()).ceil().max(0.0) as i32;
+    let factor = 10f64.powi(decimals);
+    (steps * increment * factor).round() / factor
+}
+
 const TRUE: &str = "TRUE";
 const FALSE: &str = "FALSE";
 
@@ -0,0 +1,268 @@
+//! (De)serializers for the encodings Binance uses interchangeably, to use with `#[serde(with = "...")]`.
+//!
+//! Numbers are often sent as strings (`"0.01634790"`), booleans as strings or integers and timestamps in milliseconds.
+//!
+//! # Examples
+//! ```
+//! use binance::util::serde_helpers::*;
+//! use chrono::{DateTime, Utc};
+//!
+//! #[derive(serde::Deserialize)]
+//! struct Fill {
+//!     #[serde(with = "string_or_float")]
+//!     price: f64,
+//!     #[serde(with = "string_or_bool")]
+//!     is_maker: bool,
+//!     #[serde(with = "millis_datetime")]
+//!     time: DateTime<Utc>,
+//! }
+//!
+//! let fill: Fill = serde_json::from_str(r#"{"price": "0.0163", "is_maker": "TRUE", "time": 1499040000000}"#).unwrap();
+//! assert_eq!(fill.price, 0.0163);
+//! assert!(fill.is_maker);
+//! assert_eq!(fill.time.timestamp_millis(), 1499040000000);
+//! ```
+
+/// Any number parsed from a string or read as is, e.g. f64, u64 or a decimal type, serialized as a string
+pub mod string_or_number {
+    use std::fmt;
+    use std::str::FromStr;
+
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: fmt::Display,
+        S: Serializer,
+    {
+        serializer.collect_str(value)
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: FromStr + Deserialize<'de>,
+        T::Err: fmt::Display,
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum StringOrNumber<T> {
+            String(String),
+            Number(T),
+        }
+
+        match StringOrNumber::deserialize(deserializer)? {
+            StringOrNumber::String(s) => s.parse().map_err(de::Error::custom),
+            StringOrNumber::Number(n) => Ok(n),
+        }
+    }
+}
+
+pub mod string_or_float {
+    use std::fmt;
+
+    use serde::{Deserializer, Serializer};
+
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: fmt::Display,
+        S: Serializer,
+    {
+        super::string_or_number::serialize(value, serializer)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<f64, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        super::string_or_number::deserialize(deserializer)
+    }
+}
+
+pub mod string_or_float_opt {
+    use std::fmt;
+
+    use serde::{Deserializer, Serializer};
+
+    pub fn serialize<T, S>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: fmt::Display,
+        S: Serializer,
+    {
+        match value {
+            Some(v) => super::string_or_float::serialize(v, serializer),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<f64>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(Some(super::string_or_float::deserialize(deserializer)?))
+    }
+}
+
+pub mod string_or_u64 {
+    use std::fmt;
+
+    use serde::{Deserializer, Serializer};
+
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: fmt::Display,
+        S: Serializer,
+    {
+        super::string_or_number::serialize(value, serializer)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<u64, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        super::string_or_number::deserialize(deserializer)
+    }
+}
+
+pub mod string_or_u64_opt {
+    use std::fmt;
+
+    use serde::{Deserializer, Serializer};
+
+    pub fn serialize<T, S>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: fmt::Display,
+        S: Serializer,
+    {
+        match value {
+            Some(v) => super::string_or_u64::serialize(v, serializer),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<u64>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(Some(super::string_or_u64::deserialize(deserializer)?))
+    }
+}
+
+/// Booleans read from `true`, `"true"`, `"TRUE"` or `1`, serialized as `"true"`
+pub mod string_or_bool {
+    use std::fmt;
+
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: fmt::Display,
+        S: Serializer,
+    {
+        serializer.collect_str(value)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<bool, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum StringOrBool {
+            String(String),
+            Bool(bool),
+            Int(u8),
+        }
+
+        match StringOrBool::deserialize(deserializer)? {
+            StringOrBool::String(s) => s.to_lowercase().parse().map_err(de::Error::custom),
+            StringOrBool::Bool(b) => Ok(b),
+            StringOrBool::Int(i) => Ok(i != 0),
+        }
+    }
+}
+
+/// Booleans serialized as `"TRUE"` or `"FALSE"`, as some request parameters expect, see [`crate::util::bool_to_string`]
+pub mod uppercase_bool {
+    use serde::{Deserializer, Serializer};
+
+    pub fn serialize<S>(value: &bool, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&crate::util::bool_to_string(*value))
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<bool, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        super::string_or_bool::deserialize(deserializer)
+    }
+}
+
+pub mod uppercase_bool_opt {
+    use serde::{Deserializer, Serializer};
+
+    pub fn serialize<S>(value: &Option<bool>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match value {
+            Some(v) => super::uppercase_bool::serialize(v, serializer),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<bool>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(Some(super::string_or_bool::deserialize(deserializer)?))
+    }
+}
+
+/// Millisecond timestamps, read from a number or a string, as [`chrono::DateTime<Utc>`]
+pub mod millis_datetime {
+    use chrono::{DateTime, TimeZone, Utc};
+    use serde::{de, Deserializer, Serializer};
+
+    pub fn serialize<S>(value: &DateTime<Utc>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_i64(value.timestamp_millis())
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<DateTime<Utc>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let millis: i64 = super::string_or_number::deserialize(deserializer)?;
+        Utc.timestamp_millis_opt(millis)
+            .single()
+            .ok_or_else(|| de::Error::custom(format!("invalid timestamp {millis}")))
+    }
+}
+
+pub mod millis_datetime_opt {
+    use chrono::{DateTime, Utc};
+    use serde::{Deserializer, Serializer};
+
+    pub fn serialize<S>(value: &Option<DateTime<Utc>>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match value {
+            Some(v) => super::millis_datetime::serialize(v, serializer),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<DateTime<Utc>>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(Some(super::millis_datetime::deserialize(deserializer)?))
+    }
+}
@@ -0,0 +1,321 @@
+//! VIP loans, fixed or flexible rate loans collateralized by the assets of one or several accounts.
+
+use std::collections::HashMap;
+
+use crate::client::*;
+use crate::errors::*;
+use crate::rest_model::{string_or_float, string_or_u64, string_or_u64_opt, RecordsQueryResult};
+
+static SAPI_V1_LOAN_VIP_ONGOING_ORDERS: &str = "/sapi/v1/loan/vip/ongoing/orders";
+static SAPI_V1_LOAN_VIP_BORROW: &str = "/sapi/v1/loan/vip/borrow";
+static SAPI_V1_LOAN_VIP_REPAY: &str = "/sapi/v1/loan/vip/repay";
+static SAPI_V1_LOAN_VIP_RENEW: &str = "/sapi/v1/loan/vip/renew";
+static SAPI_V1_LOAN_VIP_COLLATERAL_ACCOUNT: &str = "/sapi/v1/loan/vip/collateral/account";
+static SAPI_V1_LOAN_VIP_REQUEST_DATA: &str = "/sapi/v1/loan/vip/request/data";
+static SAPI_V1_LOAN_VIP_REQUEST_INTEREST_RATE: &str = "/sapi/v1/loan/vip/request/interestRate";
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct VipLoanOrderQuery {
+    pub order_id: Option<u64>,
+    pub collateral_account_id: Option<u64>,
+    pub loan_coin: Option<String>,
+    pub collateral_coin: Option<String>,
+    /// Page number, starting at 1
+    pub current: Option<u64>,
+    /// Default: 10, Max: 100
+    pub limit: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct VipLoanOrder {
+    pub order_id: u64,
+    pub loan_coin: String,
+    #[serde(with = "string_or_float")]
+    pub total_debt: f64,
+    #[serde(with = "string_or_float")]
+    pub residual_interest: f64,
+    /// Comma separated ids of the collateral accounts
+    pub collateral_account_id: String,
+    /// Comma separated collateral assets
+    pub collateral_coin: String,
+    #[serde(with = "string_or_float")]
+    pub total_collateral_value_after_haircut: f64,
+    #[serde(with = "string_or_float")]
+    pub locked_collateral_value: f64,
+    #[serde(rename = "currentLTV", with = "string_or_float")]
+    pub current_ltv: f64,
+    pub expiration_time: u64,
+    #[serde(with = "string_or_u64")]
+    pub loan_date: u64,
+    /// Loan term in days, None for flexible rate loans
+    #[serde(default, with = "string_or_u64_opt")]
+    pub loan_term: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct VipBorrowQuery {
+    /// Account receiving the loan
+    pub loan_account_id: u64,
+    pub loan_coin: String,
+    pub loan_amount: f64,
+    /// Comma separated ids of the collateral accounts
+    pub collateral_account_id: String,
+    /// Comma separated collateral assets, in the order of the collateral accounts
+    pub collateral_coin: String,
+    pub is_flexible_rate: bool,
+    /// 30 or 60 days, mandatory for fixed rate loans
+    pub loan_term: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct VipBorrowResponse {
+    pub loan_account_id: String,
+    /// Id of the application, see [`VipLoans::applications`]
+    pub request_id: String,
+    pub loan_coin: String,
+    /// Yes or No
+    pub is_flexible_rate: String,
+    #[serde(with = "string_or_float")]
+    pub loan_amount: f64,
+    pub collateral_account_id: String,
+    pub collateral_coin: String,
+    #[serde(default, with = "string_or_u64_opt")]
+    pub loan_term: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct VipRepayResponse {
+    pub loan_coin: String,
+    #[serde(with = "string_or_float")]
+    pub repay_amount: f64,
+    #[serde(with = "string_or_float")]
+    pub remaining_principal: f64,
+    #[serde(with = "string_or_float")]
+    pub remaining_interest: f64,
+    pub collateral_coin: String,
+    #[serde(rename = "currentLTV", with = "string_or_float")]
+    pub current_ltv: f64,
+    /// Repaid, Repaying or Failed
+    pub repay_status: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct VipRenewResponse {
+    pub loan_account_id: String,
+    pub loan_coin: String,
+    #[serde(with = "string_or_float")]
+    pub loan_amount: f64,
+    pub collateral_account_id: String,
+    pub collateral_coin: String,
+    #[serde(with = "string_or_u64")]
+    pub loan_term: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct VipCollateralAccount {
+    pub collateral_account_id: String,
+    /// Comma separated collateral assets
+    pub collateral_coin: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct VipLoanApplication {
+    pub loan_account_id: String,
+    pub order_id: Option<String>,
+    pub request_id: String,
+    pub loan_coin: String,
+    #[serde(with = "string_or_float")]
+    pub loan_amount: f64,
+    pub collateral_account_id: String,
+    pub collateral_coin: String,
+    #[serde(default, with = "string_or_u64_opt")]
+    pub loan_term: Option<u64>,
+    /// Accepted, Rejected, Repaid...
+    pub status: String,
+    #[serde(with = "string_or_u64")]
+    pub loan_date: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct VipLoanInterestRate {
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub flexible_daily_interest_rate: f64,
+    #[serde(with = "string_or_float")]
+    pub flexible_yearly_interest_rate: f64,
+    #[serde(rename = "30dDailyInterestRate", with = "string_or_float")]
+    pub daily_interest_rate_30d: f64,
+    #[serde(rename = "30dYearlyInterestRate", with = "string_or_float")]
+    pub yearly_interest_rate_30d: f64,
+    #[serde(rename = "60dDailyInterestRate", with = "string_or_float")]
+    pub daily_interest_rate_60d: f64,
+    #[serde(rename = "60dYearlyInterestRate", with = "string_or_float")]
+    pub yearly_interest_rate_60d: f64,
+}
+
+/// This struct acts as a gateway for all VIP loan endpoints.
+/// Preferably use the trait [`crate::api::Binance`] to get an instance.
+#[derive(Clone)]
+pub struct VipLoans {
+    pub client: Client,
+    pub recv_window: u64,
+}
+
+impl VipLoans {
+    /// Loans not repaid yet
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, vip_loans::*, config::*};
+    /// let vip_loans: VipLoans = Binance::new_with_env(&Config::testnet());
+    /// let orders = tokio_test::block_on(vip_loans.ongoing_orders(VipLoanOrderQuery::default()));
+    /// assert!(orders.is_ok(), "{:?}", orders);
+    /// ```
+    pub async fn ongoing_orders(&self, query: VipLoanOrderQuery) -> Result<RecordsQueryResult<VipLoanOrder>> {
+        self.client
+            .get_signed_p(SAPI_V1_LOAN_VIP_ONGOING_ORDERS, Some(query), self.recv_window)
+            .await
+    }
+
+    /// Apply for a loan, the application is reviewed before the loan is granted
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, vip_loans::*, config::*};
+    /// let vip_loans: VipLoans = Binance::new_with_env(&Config::testnet());
+    /// let query = VipBorrowQuery {
+    ///     loan_account_id: 12345678,
+    ///     loan_coin: "USDT".to_string(),
+    ///     loan_amount: 100000.0,
+    ///     collateral_account_id: "12345678,12345679".to_string(),
+    ///     collateral_coin: "BTC,ETH".to_string(),
+    ///     is_flexible_rate: false,
+    ///     loan_term: Some(30),
+    /// };
+    /// let borrow = tokio_test::block_on(vip_loans.borrow(query));
+    /// assert!(borrow.is_ok(), "{:?}", borrow);
+    /// ```
+    pub async fn borrow(&self, query: VipBorrowQuery) -> Result<VipBorrowResponse> {
+        self.client
+            .post_signed_p(SAPI_V1_LOAN_VIP_BORROW, Some(query), self.recv_window)
+            .await
+    }
+
+    /// Repay a loan, partially or entirely
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, vip_loans::*, config::*};
+    /// let vip_loans: VipLoans = Binance::new_with_env(&Config::testnet());
+    /// let repay = tokio_test::block_on(vip_loans.repay(12345678, 1000.0));
+    /// assert!(repay.is_ok(), "{:?}", repay);
+    /// ```
+    pub async fn repay(&self, order_id: u64, amount: f64) -> Result<VipRepayResponse> {
+        let mut params = HashMap::new();
+        params.insert("orderId", order_id.to_string());
+        params.insert("amount", amount.to_string());
+        self.client
+            .post_signed_p(SAPI_V1_LOAN_VIP_REPAY, Some(params), self.recv_window)
+            .await
+    }
+
+    /// Renew a fixed rate loan for `loan_term` days, 30 or 60
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, vip_loans::*, config::*};
+    /// let vip_loans: VipLoans = Binance::new_with_env(&Config::testnet());
+    /// let renewal = tokio_test::block_on(vip_loans.renew(12345678, 30));
+    /// assert!(renewal.is_ok(), "{:?}", renewal);
+    /// ```
+    pub async fn renew(&self, order_id: u64, loan_term: u64) -> Result<VipRenewResponse> {
+        let mut params = HashMap::new();
+        params.insert("orderId", order_id);
+        params.insert("loanTerm", loan_term);
+        self.client
+            .post_signed_p(SAPI_V1_LOAN_VIP_RENEW, Some(params), self.recv_window)
+            .await
+    }
+
+    /// Collateral accounts and their collateral assets, of an order or of an account
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, vip_loans::*, config::*};
+    /// let vip_loans: VipLoans = Binance::new_with_env(&Config::testnet());
+    /// let accounts = tokio_test::block_on(vip_loans.collateral_accounts(Some(12345678), None));
+    /// assert!(accounts.is_ok(), "{:?}", accounts);
+    /// ```
+    pub async fn collateral_accounts(
+        &self,
+        order_id: Option<u64>,
+        collateral_account_id: Option<u64>,
+    ) -> Result<RecordsQueryResult<VipCollateralAccount>> {
+        let mut params = HashMap::new();
+        if let Some(order_id) = order_id {
+            params.insert("orderId", order_id);
+        }
+        if let Some(collateral_account_id) = collateral_account_id {
+            params.insert("collateralAccountId", collateral_account_id);
+        }
+        self.client
+            .get_signed_p(SAPI_V1_LOAN_VIP_COLLATERAL_ACCOUNT, Some(params), self.recv_window)
+            .await
+    }
+
+    /// Status of the loan applications
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, vip_loans::*, config::*};
+    /// let vip_loans: VipLoans = Binance::new_with_env(&Config::testnet());
+    /// let applications = tokio_test::block_on(vip_loans.applications(None, None));
+    /// assert!(applications.is_ok(), "{:?}", applications);
+    /// ```
+    pub async fn applications(
+        &self,
+        current: Option<u64>,
+        limit: Option<u64>,
+    ) -> Result<RecordsQueryResult<VipLoanApplication>> {
+        let mut params = HashMap::new();
+        if let Some(current) = current {
+            params.insert("current", current);
+        }
+        if let Some(limit) = limit {
+            params.insert("limit", limit);
+        }
+        self.client
+            .get_signed_p(SAPI_V1_LOAN_VIP_REQUEST_DATA, Some(params), self.recv_window)
+            .await
+    }
+
+    /// Flexible and fixed interest rates of at most 10 loanable assets
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, vip_loans::*, config::*};
+    /// let vip_loans: VipLoans = Binance::new_with_env(&Config::testnet());
+    /// let rates = tokio_test::block_on(vip_loans.interest_rates(&["USDT", "BTC"]));
+    /// assert!(rates.is_ok(), "{:?}", rates);
+    /// ```
+    pub async fn interest_rates<S>(&self, loan_coins: &[S]) -> Result<Vec<VipLoanInterestRate>>
+    where
+        S: AsRef<str>,
+    {
+        let loan_coins: Vec<&str> = loan_coins.iter().map(AsRef::as_ref).collect();
+        let mut params = HashMap::new();
+        params.insert("loanCoin", loan_coins.join(","));
+        self.client
+            .get_signed_p(SAPI_V1_LOAN_VIP_REQUEST_INTEREST_RATE, Some(params), self.recv_window)
+            .await
+    }
+}
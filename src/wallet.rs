@@ -1,9 +1,13 @@
 use crate::client::*;
+use crate::config::SharedCredentials;
 use crate::errors::*;
 use crate::rest_model::*;
+use crate::util::round_down;
 use chrono::DateTime;
 use chrono::{Duration, Utc};
-use std::collections::HashMap;
+use futures::{Stream, TryStreamExt};
+use serde_json::Value;
+use std::collections::{HashMap, HashSet};
 use std::ops::Sub;
 
 static SAPI_V1_UNIVERSAL_TRANSFER: &str = "/sapi/v1/sub-account/universalTransfer";
@@ -13,10 +17,14 @@ static SAPI_V1_ACCOUNTSNAPSHOT: &str = "/sapi/v1/accountSnapshot";
 static SAPI_V1_ACCOUNT_DISABLEFASTWITHDRAWSWITCH: &str = "/sapi/v1/account/disableFastWithdrawSwitch";
 static SAPI_V1_ACCOUNT_ENABLEFASTWITHDRAWSWITCH: &str = "/sapi/v1/account/enableFastWithdrawSwitch";
 static SAPI_V1_CAPITAL_WITHDRAW_APPLY: &str = "/sapi/v1/capital/withdraw/apply";
+static SAPI_V1_CAPITAL_WITHDRAW_QUOTA: &str = "/sapi/v1/capital/withdraw/quota";
+static SAPI_V1_CAPITAL_DEPOSIT_CREDIT_APPLY: &str = "/sapi/v1/capital/deposit/credit-apply";
 static SAPI_V1_CAPITAL_DEPOSIT_HISREC: &str = "/sapi/v1/capital/deposit/hisrec";
 static SAPI_V1_CAPITAL_WITHDRAW_HISTORY: &str = "/sapi/v1/capital/withdraw/history";
 static SAPI_V1_CAPITAL_DEPOSIT_ADDRESS: &str = "/sapi/v1/capital/deposit/address";
+static SAPI_V1_CAPITAL_DEPOSIT_ADDRESS_LIST: &str = "/sapi/v1/capital/deposit/address/list";
 static SAPI_V1_ACCOUNT_STATUS: &str = "/sapi/v1/account/status";
+static SAPI_V1_ACCOUNT_INFO: &str = "/sapi/v1/account/info";
 static SAPI_V1_ACCOUNT_APITRADINGSTATUS: &str = "/sapi/v1/account/apiTradingStatus";
 static SAPI_V1_ASSET_DRIBBLET: &str = "/sapi/v1/asset/dribblet";
 static SAPI_V1_ASSET_DUSTBTC: &str = "/sapi/v1/asset/dust-btc";
@@ -24,15 +32,30 @@ static SAPI_V1_ASSET_DUST: &str = "/sapi/v1/asset/dust";
 static SAPI_V1_ASSET_ASSETDIVIDEND: &str = "/sapi/v1/asset/assetDividend";
 static SAPI_V1_ASSET_ASSETDETAIL: &str = "/sapi/v1/asset/assetDetail";
 static SAPI_V1_ASSET_TRADEFEE: &str = "/sapi/v1/asset/tradeFee";
-static SAPI_V1_ASSET_TRADEFEE_US: &str = "/sapi/v1/asset/query/trading-fee";
 static SAPI_V1_ASSET_TRANSFER: &str = "/sapi/v1/asset/transfer";
 static SAPI_V1_ASSET_GETFUNDINGASSET: &str = "/sapi/v1/asset/get-funding-asset";
+static SAPI_V3_ASSET_GETUSERASSET: &str = "/sapi/v3/asset/getUserAsset";
+static SAPI_V1_ASSET_WALLET_BALANCE: &str = "/sapi/v1/asset/wallet/balance";
+static SAPI_V1_CAPITAL_CONTRACT_CONVERTIBLECOINS: &str = "/sapi/v1/capital/contract/convertible-coins";
+static SAPI_V1_ASSET_CONVERT_TRANSFER: &str = "/sapi/v1/asset/convert-transfer";
+static SAPI_V1_ASSET_CONVERT_TRANSFER_QUERYBYPAGE: &str = "/sapi/v1/asset/convert-transfer/queryByPage";
+static SAPI_V1_ASSET_LEDGER_TRANSFER_CLOUD_MINING_QUERYBYPAGE: &str =
+    "/sapi/v1/asset/ledger-transfer/cloud-mining/queryByPage";
+static SAPI_V1_ASSET_CUSTODY_TRANSFER_HISTORY: &str = "/sapi/v1/asset/custody/transfer-history";
+static SAPI_V1_SPOT_DELIST_SCHEDULE: &str = "/sapi/v1/spot/delist-schedule";
+static SAPI_V1_SPOT_OPEN_SYMBOL_LIST: &str = "/sapi/v1/spot/open-symbol-list";
 static SAPI_V1_ASSET_APIRESTRICTIONS: &str = "/sapi/v1/account/apiRestrictions";
 static SAPI_V1_ASSET_ONGOING_ORDERS: &str = "/sapi/v2/loan/flexible/ongoing/orders";
 static SAPI_V1_VIP_LOAN_ONGOING_ORDERS: &str = "/sapi/v1/loan/vip/ongoing/orders";
 static SAPI_V2_LOAN_FLEXIBLE_ADJUST_LTV: &str = "/sapi/v2/loan/flexible/adjust/ltv";
 
 static DEFAULT_WALLET_HISTORY_QUERY_INTERVAL_DAYS: i64 = 90;
+static DEPOSIT_HISTORY_MAX_LIMIT: u64 = 1000;
+static ASSET_DIVIDEND_MAX_LIMIT: u64 = 500;
+/// Max number of assets converted by a single dust transfer
+static DUST_TRANSFER_MAX_ASSETS: usize = 50;
+/// Longest time range accepted by the asset dividend record
+static ASSET_DIVIDEND_MAX_WINDOW_MS: u64 = 180 * 24 * 60 * 60 * 1000;
 
 /// This struct acts as a gateway for all wallet endpoints.
 /// Preferably use the trait [`crate::api::Binance`] to get an instance.
@@ -40,6 +63,7 @@ static DEFAULT_WALLET_HISTORY_QUERY_INTERVAL_DAYS: i64 = 90;
 pub struct Wallet {
     pub client: Client,
     pub recv_window: u64,
+    /// Binance.US paths are routed by the client, see [`Client::with_binance_us`]
     pub binance_us_api: bool,
 }
 
@@ -146,12 +170,40 @@ impl Wallet {
             .await
     }
 
+    /// Apply for a withdrawal once validated against the configuration of its coin, see [`check_withdrawal`]
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, wallet::*, config::*, rest_model::*};
+    /// let wallet: Wallet = Binance::new_with_env(&Config::testnet());
+    /// let query = CoinWithdrawalQuery {
+    ///     coin: "USDT".into(),
+    ///     network: Some("TRX".into()),
+    ///     address: "TMuA6YqfCeX8EhbfYEg5y7S4DqzSJireY9".into(),
+    ///     amount: 100.0,
+    ///     ..Default::default()
+    /// };
+    /// let withdrawal = tokio_test::block_on(wallet.withdraw_checked(query, true));
+    /// assert!(withdrawal.is_ok(), "{:?}", withdrawal);
+    /// ```
+    pub async fn withdraw_checked(&self, query: CoinWithdrawalQuery, deduct_fee: bool) -> Result<WithdrawId> {
+        let coins = self.all_coin_info().await?;
+        let coin = coins
+            .iter()
+            .find(|coin| coin.coin.eq_ignore_ascii_case(&query.coin))
+            .ok_or_else(|| Error::InvalidWithdrawal(format!("unknown coin {}", query.coin)))?;
+        let query = check_withdrawal(coin, query, deduct_fee)?;
+        self.withdraw(query).await
+    }
+
+    #[deprecated(note = "use `loans::Loans::ongoing_orders`, which returns typed amounts")]
     pub async fn get_loans(&self) -> Result<LoanResponse> {
         self.client
             .get_signed_p(SAPI_V1_ASSET_ONGOING_ORDERS, Option::<String>::None, self.recv_window)
             .await
     }
 
+    #[deprecated(note = "use `vip_loans::VipLoans::ongoing_orders`, which returns typed amounts")]
     pub async fn get_vip_loans(&self) -> Result<VipLoanResponse> {
         self.client
             .get_signed_p(
@@ -178,7 +230,8 @@ impl Wallet {
             .await
     }
 
-    /// Withdraw History starting at start_from (defaults to now), ranging total_duration (defaults to 90 days), with intervals of 90 days.
+    /// Deposit History starting at start_from (defaults to now), ranging total_duration (defaults to 90 days), with intervals of 90 days.
+    /// Every page of each interval is fetched, the coin, status and tx id filters of the query are applied.
     ///
     /// # Examples
     /// ```rust,no_run
@@ -208,8 +261,7 @@ impl Wallet {
             query.start_time = Some(current_period_start.timestamp_millis() as u64);
             query.end_time = Some(current_period_end.timestamp_millis() as u64);
 
-            // eprintln!("query: {:?}", query);
-            let records = self.deposit_history(&query).await?;
+            let records = self.deposit_history_pages(&mut query).await?;
 
             if !records.is_empty() {
                 let item = RecordHistory::<DepositRecord> {
@@ -227,6 +279,24 @@ impl Wallet {
         Ok(result)
     }
 
+    /// Deposit history of every page matching the query, starting at its offset
+    async fn deposit_history_pages(&self, query: &mut DepositHistoryQuery) -> Result<Vec<DepositRecord>> {
+        let page_size = query.limit.unwrap_or(DEPOSIT_HISTORY_MAX_LIMIT) as usize;
+        let first_offset = query.offset;
+        let mut records = vec![];
+        loop {
+            let page = self.deposit_history(query).await?;
+            let is_last_page = page.len() < page_size || page.is_empty();
+            records.extend(page);
+            if is_last_page {
+                break;
+            }
+            query.offset = Some(query.offset.unwrap_or(0) + page_size as u64);
+        }
+        query.offset = first_offset;
+        Ok(records)
+    }
+
     /// Withdraw History
     ///
     /// # Examples
@@ -292,7 +362,7 @@ impl Wallet {
         Ok(result)
     }
 
-    /// Deposit address
+    /// Deposit address, or an invoice of the requested amount on the Lightning network
     ///
     /// # Examples
     /// ```rust,no_run
@@ -301,6 +371,13 @@ impl Wallet {
     /// let query: DepositAddressQuery = DepositAddressQuery::default();
     /// let records = tokio_test::block_on(wallet.deposit_address(query));
     /// assert!(records.is_ok(), "{:?}", records);
+    /// let query = DepositAddressQuery {
+    ///     coin: "BTC".into(),
+    ///     network: Some("LIGHTNING".into()),
+    ///     amount: Some(0.001),
+    /// };
+    /// let invoice = tokio_test::block_on(wallet.deposit_address(query));
+    /// assert!(invoice.is_ok(), "{:?}", invoice);
     /// ```
     pub async fn deposit_address(&self, query: DepositAddressQuery) -> Result<DepositAddress> {
         self.client
@@ -308,6 +385,23 @@ impl Wallet {
             .await
     }
 
+    /// Deposit addresses of a coin, for every network it supports
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, wallet::*, config::*, rest_model::*};
+    /// let wallet: Wallet = Binance::new_with_env(&Config::testnet());
+    /// let query = DepositAddressListQuery { coin: "USDT".to_string(), ..Default::default() };
+    /// let records = tokio_test::block_on(wallet.deposit_addresses(query));
+    /// assert!(records.is_ok(), "{:?}", records);
+    /// ```
+    pub async fn deposit_addresses(&self, query: DepositAddressListQuery) -> Result<Vec<NetworkDepositAddress>> {
+        self.client
+            .get_signed_p(SAPI_V1_CAPITAL_DEPOSIT_ADDRESS_LIST, Some(query), self.recv_window)
+            .await
+    }
+
+    #[deprecated(note = "use `loans::Loans::adjust_ltv`, which returns a typed response")]
     pub async fn flexible_loan_adjust_ltv(
         &self,
         loan_coin: String,
@@ -330,6 +424,7 @@ impl Wallet {
     ///
     /// from_symbol must be sent when transfer_type are IsolatedmarginMargin and IsolatedmarginIsolatedmargin
     /// to_symbol must be sent when transfer_type are MarginIsolatedmargin and IsolatedmarginIsolatedmargin
+    /// The transfer is validated locally first, see [`check_universal_transfer`]
     ///
     /// # Examples
     /// ```rust,no_run
@@ -352,15 +447,152 @@ impl Wallet {
         let transfer = UniversalTransfer {
             asset,
             amount,
-            from_symbol: from_symbol.map(Into::<String>::into),
-            to_symbol: to_symbol.map(Into::<String>::into),
+            from_symbol,
+            to_symbol,
             transfer_type,
         };
+        check_universal_transfer(&transfer)?;
         self.client
             .post_signed_p(SAPI_V1_ASSET_TRANSFER, transfer, self.recv_window)
             .await
     }
 
+    /// Transfer from the spot account to the funding account
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, wallet::*, config::*};
+    /// let wallet: Wallet = Binance::new_with_env(&Config::testnet());
+    /// let transfer = tokio_test::block_on(wallet.transfer_spot_to_funding("USDT", 10.0));
+    /// assert!(transfer.is_ok(), "{:?}", transfer);
+    /// ```
+    pub async fn transfer_spot_to_funding<S: Into<String>>(&self, asset: S, amount: f64) -> Result<TransactionId> {
+        self.universal_transfer(asset.into(), amount, None, None, UniversalTransferType::MainFunding)
+            .await
+    }
+
+    /// Transfer from the funding account to the spot account
+    pub async fn transfer_funding_to_spot<S: Into<String>>(&self, asset: S, amount: f64) -> Result<TransactionId> {
+        self.universal_transfer(asset.into(), amount, None, None, UniversalTransferType::FundingMain)
+            .await
+    }
+
+    /// Transfer from the spot account to the cross margin account
+    pub async fn transfer_spot_to_margin<S: Into<String>>(&self, asset: S, amount: f64) -> Result<TransactionId> {
+        self.universal_transfer(asset.into(), amount, None, None, UniversalTransferType::MainMargin)
+            .await
+    }
+
+    /// Transfer from the cross margin account to the spot account
+    pub async fn transfer_margin_to_spot<S: Into<String>>(&self, asset: S, amount: f64) -> Result<TransactionId> {
+        self.universal_transfer(asset.into(), amount, None, None, UniversalTransferType::MarginMain)
+            .await
+    }
+
+    /// Transfer from the spot account to the USDⓈ-M futures account
+    pub async fn transfer_spot_to_usd_m_futures<S: Into<String>>(
+        &self,
+        asset: S,
+        amount: f64,
+    ) -> Result<TransactionId> {
+        self.universal_transfer(asset.into(), amount, None, None, UniversalTransferType::MainUmfuture)
+            .await
+    }
+
+    /// Transfer from the USDⓈ-M futures account to the spot account
+    pub async fn transfer_usd_m_futures_to_spot<S: Into<String>>(
+        &self,
+        asset: S,
+        amount: f64,
+    ) -> Result<TransactionId> {
+        self.universal_transfer(asset.into(), amount, None, None, UniversalTransferType::UmfutureMain)
+            .await
+    }
+
+    /// Transfer from the spot account to the COIN-M futures account
+    pub async fn transfer_spot_to_coin_m_futures<S: Into<String>>(
+        &self,
+        asset: S,
+        amount: f64,
+    ) -> Result<TransactionId> {
+        self.universal_transfer(asset.into(), amount, None, None, UniversalTransferType::MainCmfuture)
+            .await
+    }
+
+    /// Transfer from the COIN-M futures account to the spot account
+    pub async fn transfer_coin_m_futures_to_spot<S: Into<String>>(
+        &self,
+        asset: S,
+        amount: f64,
+    ) -> Result<TransactionId> {
+        self.universal_transfer(asset.into(), amount, None, None, UniversalTransferType::CmfutureMain)
+            .await
+    }
+
+    /// Transfer from the cross margin account to the isolated margin account of `symbol`
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, wallet::*, config::*};
+    /// let wallet: Wallet = Binance::new_with_env(&Config::testnet());
+    /// let transfer = tokio_test::block_on(wallet.transfer_margin_to_isolated("USDT", "BTCUSDT", 10.0));
+    /// assert!(transfer.is_ok(), "{:?}", transfer);
+    /// ```
+    pub async fn transfer_margin_to_isolated<S1, S2>(&self, asset: S1, symbol: S2, amount: f64) -> Result<TransactionId>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+    {
+        self.universal_transfer(
+            asset.into(),
+            amount,
+            None,
+            Some(symbol.into()),
+            UniversalTransferType::MarginIsolatedmargin,
+        )
+        .await
+    }
+
+    /// Transfer from the isolated margin account of `symbol` to the cross margin account
+    pub async fn transfer_isolated_to_margin<S1, S2>(&self, asset: S1, symbol: S2, amount: f64) -> Result<TransactionId>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+    {
+        self.universal_transfer(
+            asset.into(),
+            amount,
+            Some(symbol.into()),
+            None,
+            UniversalTransferType::IsolatedmarginMargin,
+        )
+        .await
+    }
+
+    /// Transfer between the isolated margin accounts of `from_symbol` and `to_symbol`
+    pub async fn transfer_isolated_to_isolated<S1, S2, S3>(
+        &self,
+        asset: S1,
+        from_symbol: S2,
+        to_symbol: S3,
+        amount: f64,
+    ) -> Result<TransactionId>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<String>,
+    {
+        self.universal_transfer(
+            asset.into(),
+            amount,
+            Some(from_symbol.into()),
+            Some(to_symbol.into()),
+            UniversalTransferType::IsolatedmarginIsolatedmargin,
+        )
+        .await
+    }
+
+    #[deprecated(note = "use `SubAccount::universal_transfer` of the `sub_account_api` feature")]
     pub async fn universal_transfer_subaccount(
         &self,
         asset: String,
@@ -434,6 +666,21 @@ impl Wallet {
             .await
     }
 
+    /// Account info: VIP level and which products are enabled
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, wallet::*, config::*, rest_model::*};
+    /// let wallet: Wallet = Binance::new_with_env(&Config::testnet());
+    /// let records = tokio_test::block_on(wallet.account_info());
+    /// assert!(records.is_ok(), "{:?}", records);
+    /// ```
+    pub async fn account_info(&self) -> Result<AccountInfo> {
+        self.client
+            .get_signed_p(SAPI_V1_ACCOUNT_INFO, Option::<String>::None, self.recv_window)
+            .await
+    }
+
     /// Current api trading status
     ///
     /// # Examples
@@ -505,6 +752,38 @@ impl Wallet {
             .await
     }
 
+    /// Convert all the dust assets worth at least `min_btc_value` to BNB
+    ///
+    /// Assets are converted in batches, if a batch fails the error is returned and the previous batches stay converted.
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, wallet::*, config::*, rest_model::*};
+    /// let wallet: Wallet = Binance::new_with_env(&Config::testnet());
+    /// let report = tokio_test::block_on(wallet.dust_transfer_all(0.0));
+    /// assert!(report.is_ok(), "{:?}", report);
+    /// ```
+    pub async fn dust_transfer_all(&self, min_btc_value: f64) -> Result<DustTransferReport> {
+        let (eligible, skipped): (Vec<ConvertibleAssetDetails>, Vec<ConvertibleAssetDetails>) = self
+            .convertible_assets()
+            .await?
+            .details
+            .into_iter()
+            .partition(|details| details.to_btc >= min_btc_value);
+        let mut report = DustTransferReport {
+            skipped: skipped.into_iter().map(|details| details.asset).collect(),
+            ..Default::default()
+        };
+        let assets: Vec<String> = eligible.into_iter().map(|details| details.asset).collect();
+        for chunk in assets.chunks(DUST_TRANSFER_MAX_ASSETS) {
+            let transfer = self.dust_transfer(chunk.to_vec()).await?;
+            report.total_service_charge += transfer.total_service_charge;
+            report.total_transferred += transfer.total_transferred;
+            report.transfer_result.extend(transfer.transfer_result);
+        }
+        Ok(report)
+    }
+
     /// Asset Dividend Record
     ///
     /// # Examples
@@ -520,6 +799,109 @@ impl Wallet {
             .await
     }
 
+    /// Stream every asset dividend between start_time and end_time (inclusive, in milliseconds), most recent first.
+    /// Pages of 500 records spanning at most 180 days are requested, each ending at the oldest dividend of the
+    /// previous page. Fails rather than skipping records if more than a page of dividends share a timestamp.
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, wallet::*, config::*, rest_model::*};
+    /// use futures::TryStreamExt;
+    /// let wallet: Wallet = Binance::new_with_env(&Config::testnet());
+    /// let end = chrono::Utc::now().timestamp_millis() as u64;
+    /// let start = end - chrono::Duration::days(90).num_milliseconds() as u64;
+    /// let dividends = tokio_test::block_on(wallet.asset_dividends_range(None, start, end).try_collect::<Vec<_>>());
+    /// assert!(dividends.is_ok(), "{:?}", dividends);
+    /// ```
+    pub fn asset_dividends_range(
+        &self,
+        asset: Option<String>,
+        start_time: u64,
+        end_time: u64,
+    ) -> impl Stream<Item = Result<AssetDividend>> + '_ {
+        let cursor = Some((end_time, HashSet::new()));
+        futures::stream::try_unfold((asset, cursor), move |(asset, cursor)| async move {
+            match cursor {
+                None => Ok(None),
+                Some(cursor) => self
+                    .asset_dividends_page(asset.clone(), start_time, cursor)
+                    .await
+                    .map(|(dividends, next)| Some((dividends, (asset, next)))),
+            }
+        })
+        .map_ok(|dividends| futures::stream::iter(dividends.into_iter().map(Ok)))
+        .try_flatten()
+    }
+
+    /// Fetch the page of dividends ending at the cursor, along with the cursor of the next page.
+    /// The cursor holds the end time of the page and the ids already returned at that time.
+    async fn asset_dividends_page(
+        &self,
+        asset: Option<String>,
+        start_time: u64,
+        (end_time, seen): (u64, HashSet<u64>),
+    ) -> Result<(Vec<AssetDividend>, Option<(u64, HashSet<u64>)>)> {
+        let page_start = end_time
+            .saturating_sub(ASSET_DIVIDEND_MAX_WINDOW_MS - 1)
+            .max(start_time);
+        let query = AssetDividendQuery {
+            asset,
+            start_time: Some(page_start),
+            end_time: Some(end_time),
+            limit: Some(ASSET_DIVIDEND_MAX_LIMIT),
+        };
+        let dividends = self.asset_dividends(query).await?.rows.unwrap_or_default();
+        let next = match dividends.iter().map(|dividend| dividend.div_time).min() {
+            Some(oldest) if dividends.len() as u64 >= ASSET_DIVIDEND_MAX_LIMIT => {
+                // Dividends at the oldest time may continue on the next page, the page cannot end before them
+                if dividends.iter().all(|dividend| dividend.div_time == oldest) {
+                    return Err(Error::Msg(format!(
+                        "more than {ASSET_DIVIDEND_MAX_LIMIT} asset dividends at {oldest}, they cannot be paged"
+                    )));
+                }
+                let seen = dividends
+                    .iter()
+                    .filter(|dividend| dividend.div_time == oldest)
+                    .map(|dividend| dividend.id)
+                    .collect();
+                Some((oldest, seen))
+            }
+            // The window is exhausted, continue with the window before it
+            _ if page_start > start_time => Some((page_start - 1, HashSet::new())),
+            _ => None,
+        };
+        let dividends = dividends
+            .into_iter()
+            .filter(|dividend| !seen.contains(&dividend.id))
+            .collect();
+        Ok((dividends, next))
+    }
+
+    /// Total dividends per asset between start_time and end_time (inclusive, in milliseconds)
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, wallet::*, config::*, rest_model::*};
+    /// let wallet: Wallet = Binance::new_with_env(&Config::testnet());
+    /// let end = chrono::Utc::now().timestamp_millis() as u64;
+    /// let start = end - chrono::Duration::days(90).num_milliseconds() as u64;
+    /// let totals = tokio_test::block_on(wallet.asset_dividends_by_asset(None, start, end));
+    /// assert!(totals.is_ok(), "{:?}", totals);
+    /// ```
+    pub async fn asset_dividends_by_asset(
+        &self,
+        asset: Option<String>,
+        start_time: u64,
+        end_time: u64,
+    ) -> Result<HashMap<String, f64>> {
+        self.asset_dividends_range(asset, start_time, end_time)
+            .try_fold(HashMap::new(), |mut totals, dividend| async move {
+                *totals.entry(dividend.asset).or_insert(0.0) += dividend.amount;
+                Ok(totals)
+            })
+            .await
+    }
+
     /// Asset Details
     ///
     /// # Examples
@@ -548,15 +930,7 @@ impl Wallet {
         let mut query = HashMap::new();
         query.insert("symbol", symbol);
         self.client
-            .get_signed_p(
-                if self.binance_us_api {
-                    SAPI_V1_ASSET_TRADEFEE_US
-                } else {
-                    SAPI_V1_ASSET_TRADEFEE
-                },
-                Some(query),
-                self.recv_window,
-            )
+            .get_signed_p(SAPI_V1_ASSET_TRADEFEE, Some(query), self.recv_window)
             .await
     }
 
@@ -583,6 +957,244 @@ impl Wallet {
             .await
     }
 
+    /// User Assets
+    ///
+    /// Balances of the spot wallet, only assets with a positive balance are returned
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, wallet::*, config::*, rest_model::*};
+    /// let wallet: Wallet = Binance::new_with_env(&Config::testnet());
+    /// let records = tokio_test::block_on(wallet.user_assets(None, Some(true)));
+    /// assert!(records.is_ok(), "{:?}", records);
+    /// ```
+    pub async fn user_assets(
+        &self,
+        asset: Option<String>,
+        need_btc_valuation: Option<bool>,
+    ) -> Result<Vec<WalletUserAsset>> {
+        let mut query = HashMap::new();
+        query.insert("asset", asset);
+        query.insert("needBtcValuation", need_btc_valuation.map(|b| format!("{b}")));
+        self.client
+            .post_signed_p(SAPI_V3_ASSET_GETUSERASSET, Some(query), self.recv_window)
+            .await
+    }
+
+    /// Wallet Balance
+    ///
+    /// Valuation of every wallet of the user (spot, funding, margin, futures, earn...), in `quote_asset` or BTC
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, wallet::*, config::*, rest_model::*};
+    /// let wallet: Wallet = Binance::new_with_env(&Config::testnet());
+    /// let records = tokio_test::block_on(wallet.wallet_balance(None));
+    /// assert!(records.is_ok(), "{:?}", records);
+    /// ```
+    pub async fn wallet_balance(&self, quote_asset: Option<String>) -> Result<Vec<WalletBalance>> {
+        let mut query = HashMap::new();
+        query.insert("quoteAsset", quote_asset);
+        self.client
+            .get_signed_p(SAPI_V1_ASSET_WALLET_BALANCE, Some(query), self.recv_window)
+            .await
+    }
+
+    /// Auto-Converting Stable Coins
+    ///
+    /// Stable coins which are converted to BUSD on deposit and withdrawal, and whether the conversion is enabled
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, wallet::*, config::*, rest_model::*};
+    /// let wallet: Wallet = Binance::new_with_env(&Config::testnet());
+    /// let records = tokio_test::block_on(wallet.convertible_coins());
+    /// assert!(records.is_ok(), "{:?}", records);
+    /// ```
+    pub async fn convertible_coins(&self) -> Result<ConvertibleCoins> {
+        self.client
+            .get_signed_p(
+                SAPI_V1_CAPITAL_CONTRACT_CONVERTIBLECOINS,
+                Option::<String>::None,
+                self.recv_window,
+            )
+            .await
+    }
+
+    /// Switch on/off the automatic conversion of `coin`
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, wallet::*, config::*, rest_model::*};
+    /// let wallet: Wallet = Binance::new_with_env(&Config::testnet());
+    /// let records = tokio_test::block_on(wallet.toggle_coin_conversion("USDC", false));
+    /// assert!(records.is_ok(), "{:?}", records);
+    /// ```
+    pub async fn toggle_coin_conversion<S>(&self, coin: S, enable: bool) -> Result<()>
+    where
+        S: AsRef<str>,
+    {
+        let mut query = HashMap::new();
+        query.insert("coin", coin.as_ref().to_string());
+        query.insert("enable", enable.to_string());
+        let _: Value = self
+            .client
+            .post_signed_p(SAPI_V1_CAPITAL_CONTRACT_CONVERTIBLECOINS, Some(query), self.recv_window)
+            .await?;
+        Ok(())
+    }
+
+    /// BUSD Convert
+    ///
+    /// Convert between BUSD and the stable coins it supports
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, wallet::*, config::*, rest_model::*};
+    /// let wallet: Wallet = Binance::new_with_env(&Config::testnet());
+    /// let query = ConvertTransferQuery {
+    ///     client_tran_id: "118263407119".to_string(),
+    ///     asset: "BUSD".to_string(),
+    ///     amount: 20.0,
+    ///     target_asset: "USDC".to_string(),
+    ///     ..Default::default()
+    /// };
+    /// let records = tokio_test::block_on(wallet.convert_transfer(query));
+    /// assert!(records.is_ok(), "{:?}", records);
+    /// ```
+    pub async fn convert_transfer(&self, query: ConvertTransferQuery) -> Result<ConvertTransferResponse> {
+        self.client
+            .post_signed_p(SAPI_V1_ASSET_CONVERT_TRANSFER, Some(query), self.recv_window)
+            .await
+    }
+
+    /// BUSD Convert History
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, wallet::*, config::*, rest_model::*};
+    /// let wallet: Wallet = Binance::new_with_env(&Config::testnet());
+    /// let query = ConvertTransferHistoryQuery { start_time: 1_690_000_000_000, end_time: 1_690_086_400_000, ..Default::default() };
+    /// let records = tokio_test::block_on(wallet.convert_transfer_history(query));
+    /// assert!(records.is_ok(), "{:?}", records);
+    /// ```
+    pub async fn convert_transfer_history(
+        &self,
+        query: ConvertTransferHistoryQuery,
+    ) -> Result<RecordsQueryResult<ConvertTransferRecord>> {
+        self.client
+            .get_signed_p(
+                SAPI_V1_ASSET_CONVERT_TRANSFER_QUERYBYPAGE,
+                Some(query),
+                self.recv_window,
+            )
+            .await
+    }
+
+    /// Cloud-Mining payment and refund history
+    ///
+    /// Results are paginated with `current` and `size`, see [`RecordsQueryResult::total`] for the number of records
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, wallet::*, config::*, rest_model::*};
+    /// let wallet: Wallet = Binance::new_with_env(&Config::testnet());
+    /// let query = CloudMiningHistoryQuery { start_time: 1_690_000_000_000, end_time: 1_690_086_400_000, ..Default::default() };
+    /// let records = tokio_test::block_on(wallet.cloud_mining_history(query));
+    /// assert!(records.is_ok(), "{:?}", records);
+    /// ```
+    pub async fn cloud_mining_history(
+        &self,
+        query: CloudMiningHistoryQuery,
+    ) -> Result<RecordsQueryResult<CloudMiningRecord>> {
+        self.client
+            .get_signed_p(
+                SAPI_V1_ASSET_LEDGER_TRANSFER_CLOUD_MINING_QUERYBYPAGE,
+                Some(query),
+                self.recv_window,
+            )
+            .await
+    }
+
+    /// Delegation history of the assets of a custody account
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, wallet::*, config::*, rest_model::*};
+    /// let wallet: Wallet = Binance::new_with_env(&Config::testnet());
+    /// let query = DelegationHistoryQuery {
+    ///     email: "user@domain.com".to_string(),
+    ///     start_time: 1_690_000_000_000,
+    ///     end_time: 1_690_086_400_000,
+    ///     ..Default::default()
+    /// };
+    /// let records = tokio_test::block_on(wallet.delegation_history(query));
+    /// assert!(records.is_ok(), "{:?}", records);
+    /// ```
+    pub async fn delegation_history(
+        &self,
+        query: DelegationHistoryQuery,
+    ) -> Result<RecordsQueryResult<DelegationRecord>> {
+        self.client
+            .get_signed_p(SAPI_V1_ASSET_CUSTODY_TRANSFER_HISTORY, Some(query), self.recv_window)
+            .await
+    }
+
+    /// Symbols which are going to be delisted from spot, and when
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, wallet::*, config::*, rest_model::*};
+    /// let wallet: Wallet = Binance::new_with_env(&Config::testnet());
+    /// let records = tokio_test::block_on(wallet.delist_schedule());
+    /// assert!(records.is_ok(), "{:?}", records);
+    /// ```
+    pub async fn delist_schedule(&self) -> Result<Vec<DelistSchedule>> {
+        self.client
+            .get_signed_p(SAPI_V1_SPOT_DELIST_SCHEDULE, Option::<String>::None, self.recv_window)
+            .await
+    }
+
+    /// Symbols which are going to be listed on spot, and when they open for trading
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, wallet::*, config::*, rest_model::*};
+    /// let wallet: Wallet = Binance::new_with_env(&Config::testnet());
+    /// let records = tokio_test::block_on(wallet.open_symbol_list());
+    /// assert!(records.is_ok(), "{:?}", records);
+    /// ```
+    pub async fn open_symbol_list(&self) -> Result<Vec<SymbolOpenSchedule>> {
+        self.client.get_p(SAPI_V1_SPOT_OPEN_SYMBOL_LIST, None).await
+    }
+
+    /// Withdraw quota over the last 24h, and how much of it was used
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, wallet::*, config::*, rest_model::*};
+    /// let wallet: Wallet = Binance::new_with_env(&Config::testnet());
+    /// let records = tokio_test::block_on(wallet.withdraw_quota());
+    /// assert!(records.is_ok(), "{:?}", records);
+    /// ```
+    pub async fn withdraw_quota(&self) -> Result<WithdrawQuota> {
+        self.client
+            .get_signed_p(SAPI_V1_CAPITAL_WITHDRAW_QUOTA, Option::<String>::None, self.recv_window)
+            .await
+    }
+
+    /// One click arrival deposit apply
+    ///
+    /// Credit a deposit which was not credited, e.g. because it was sent to the wrong network or held for travel rule checks
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, wallet::*, config::*, rest_model::*};
+    /// let wallet: Wallet = Binance::new_with_env(&Config::testnet());
+    /// let query = DepositCreditApplyQuery { deposit_id: Some(4_615_328_107_052_018_945), ..Default::default() };
+    /// let records = tokio_test::block_on(wallet.deposit_credit_apply(query));
+    /// assert!(records.is_ok(), "{:?}", records);
+    /// ```
+    pub async fn deposit_credit_apply(&self, query: DepositCreditApplyQuery) -> Result<DepositCreditApplyResponse> {
+        self.client
+            .post_signed_p(SAPI_V1_CAPITAL_DEPOSIT_CREDIT_APPLY, Some(query), self.recv_window)
+            .await
+    }
+
     /// Api Key Permissions
     ///
     /// # Examples
@@ -597,4 +1209,163 @@ impl Wallet {
             .get_signed_p(SAPI_V1_ASSET_APIRESTRICTIONS, Option::<String>::None, self.recv_window)
             .await
     }
+
+    /// Check the permissions of a new key pair, then switch the client and the ones sharing its credentials to it
+    ///
+    /// The current keys are kept if the new ones are rejected, the requests in flight complete with the old keys.
+    ///
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, wallet::*, config::*, rest_model::*};
+    /// let wallet: Wallet = Binance::new_with_env(&Config::testnet());
+    /// let permissions = tokio_test::block_on(wallet.rotate_credentials("new api key", "new secret key"));
+    /// assert!(permissions.is_ok(), "{:?}", permissions);
+    /// ```
+    pub async fn rotate_credentials<S1, S2>(&self, api_key: S1, secret_key: S2) -> Result<ApiKeyPermissions>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+    {
+        let (api_key, secret_key) = (api_key.into(), secret_key.into());
+        let mut candidate = self.clone();
+        candidate.client.credentials = SharedCredentials::new(api_key.clone(), secret_key.clone());
+        let permissions = candidate.api_key_permissions().await?;
+        self.client.rotate_credentials(api_key, secret_key);
+        Ok(permissions)
+    }
+}
+
+/// Validate a withdrawal of `coin` before applying for it.
+///
+/// The network, the default network of the coin if none is given, must be enabled for withdrawals, and the amount
+/// must be within its limits and a multiple of its withdrawal precision. With `deduct_fee`, the fee of the network
+/// is deducted from the amount, which is then rounded down to the precision, so that `amount` is the total debited.
+/// Returns the query with its network and amount resolved.
+///
+/// # Examples
+/// ```rust
+/// use binance::{errors::*, rest_model::*, wallet::*};
+/// let coin: WalletCoinInfo = serde_json::from_value(serde_json::json!({
+///     "coin": "USDT", "name": "TetherUS", "depositAllEnable": true, "withdrawAllEnable": true, "trading": true,
+///     "isLegalMoney": false, "free": "500", "freeze": "0", "ipoable": "0", "ipoing": "0", "locked": "0",
+///     "storage": "0", "withdrawing": "0",
+///     "networkList": [{
+///         "network": "TRX", "coin": "USDT", "name": "Tron (TRC20)", "isDefault": true, "addressRegex": "",
+///         "memoRegex": "", "depositEnable": true, "withdrawEnable": true, "withdrawFee": "1",
+///         "withdrawIntegerMultiple": "0.000001", "withdrawMin": "10", "withdrawMax": "10000000", "minConfirm": 1,
+///         "unLockConfirm": 0, "resetAddressStatus": false, "specialTips": null
+///     }]
+/// }))
+/// .unwrap();
+/// let query = CoinWithdrawalQuery {
+///     coin: "USDT".into(),
+///     address: "TMuA6YqfCeX8EhbfYEg5y7S4DqzSJireY9".into(),
+///     amount: 100.0,
+///     ..Default::default()
+/// };
+/// let checked = check_withdrawal(&coin, query.clone(), true).unwrap();
+/// assert_eq!(checked.network.as_deref(), Some("TRX"));
+/// assert_eq!(checked.amount, 99.0);
+/// let too_small = CoinWithdrawalQuery { amount: 5.0, ..query };
+/// assert!(matches!(check_withdrawal(&coin, too_small, false), Err(Error::InvalidWithdrawal(_))));
+/// ```
+pub fn check_withdrawal(
+    coin: &WalletCoinInfo,
+    mut query: CoinWithdrawalQuery,
+    deduct_fee: bool,
+) -> Result<CoinWithdrawalQuery> {
+    let network = match &query.network {
+        Some(network) => coin
+            .network_list
+            .iter()
+            .find(|n| n.network.eq_ignore_ascii_case(network)),
+        None => coin.network_list.iter().find(|n| n.is_default),
+    }
+    .ok_or_else(|| {
+        Error::InvalidWithdrawal(format!(
+            "unknown network {} for {}",
+            query.network.as_deref().unwrap_or("(default)"),
+            coin.coin
+        ))
+    })?;
+    if !coin.withdraw_all_enable || !network.withdraw_enable {
+        return Err(Error::InvalidWithdrawal(format!(
+            "withdrawals of {} are disabled on {}",
+            coin.coin, network.network
+        )));
+    }
+    let mut amount = query.amount;
+    if deduct_fee {
+        amount = round_down(amount - network.withdraw_fee, network.withdraw_integer_multiple);
+    } else if round_down(amount, network.withdraw_integer_multiple) != amount {
+        return Err(Error::InvalidWithdrawal(format!(
+            "{amount} {} is not a multiple of {}",
+            coin.coin, network.withdraw_integer_multiple
+        )));
+    }
+    if amount < network.withdraw_min || (network.withdraw_max > 0.0 && amount > network.withdraw_max) {
+        return Err(Error::InvalidWithdrawal(format!(
+            "{amount} {} is out of the withdrawal limits of {}, from {} to {}",
+            coin.coin, network.network, network.withdraw_min, network.withdraw_max
+        )));
+    }
+    query.network = Some(network.network.clone());
+    query.amount = amount;
+    Ok(query)
+}
+
+/// Validate a universal transfer before sending it.
+///
+/// The amount must be positive, and the isolated margin symbols must be sent exactly when the transfer type
+/// requires them, see [`UniversalTransferType::requires_from_symbol`] and
+/// [`UniversalTransferType::requires_to_symbol`].
+///
+/// # Examples
+/// ```rust
+/// use binance::{errors::*, rest_model::*, wallet::*};
+/// let transfer = UniversalTransfer {
+///     asset: "USDT".into(),
+///     amount: 10.0,
+///     from_symbol: None,
+///     to_symbol: None,
+///     transfer_type: UniversalTransferType::MarginIsolatedmargin,
+/// };
+/// let checked = check_universal_transfer(&transfer);
+/// assert!(matches!(checked, Err(Error::InvalidTransfer(_))), "{:?}", checked);
+/// let transfer = UniversalTransfer { to_symbol: Some("BTCUSDT".into()), ..transfer };
+/// assert!(check_universal_transfer(&transfer).is_ok());
+/// ```
+pub fn check_universal_transfer(transfer: &UniversalTransfer) -> Result<()> {
+    let transfer_type = &transfer.transfer_type;
+    if transfer.amount.is_nan() || transfer.amount <= 0.0 {
+        return Err(Error::InvalidTransfer(format!(
+            "the amount of {} must be positive, got {}",
+            transfer.asset, transfer.amount
+        )));
+    }
+    for (required, symbol, side) in [
+        (transfer_type.requires_from_symbol(), &transfer.from_symbol, "from"),
+        (transfer_type.requires_to_symbol(), &transfer.to_symbol, "to"),
+    ] {
+        match (required, symbol) {
+            (true, None) => {
+                return Err(Error::InvalidTransfer(format!(
+                    "{side}_symbol is required by {transfer_type:?} transfers"
+                )))
+            }
+            (false, Some(symbol)) => {
+                return Err(Error::InvalidTransfer(format!(
+                    "{side}_symbol {symbol} is only sent by isolated margin transfers, not by {transfer_type:?}"
+                )))
+            }
+            _ => {}
+        }
+    }
+    if transfer.from_symbol.is_some() && transfer.from_symbol == transfer.to_symbol {
+        return Err(Error::InvalidTransfer(format!(
+            "cannot transfer from and to the same isolated margin account {}",
+            transfer.from_symbol.as_deref().unwrap_or_default()
+        )));
+    }
+    Ok(())
 }
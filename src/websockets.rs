@@ -1,16 +1,35 @@
+use std::fmt;
+#[cfg(feature = "websockets")]
 use std::sync::atomic::{AtomicBool, Ordering};
 
-use futures::StreamExt;
+#[cfg(feature = "websockets")]
+use futures::stream::BoxStream;
+#[cfg(feature = "websockets")]
+use futures::{SinkExt, StreamExt};
+#[cfg(feature = "websockets")]
 use serde_json::from_str;
+#[cfg(feature = "websockets")]
 use tokio::net::TcpStream;
+#[cfg(feature = "websockets")]
+use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+#[cfg(feature = "websockets")]
 use tokio_tungstenite::tungstenite::handshake::client::Response;
+#[cfg(feature = "websockets")]
 use tokio_tungstenite::tungstenite::Message;
+#[cfg(feature = "websockets")]
 use tokio_tungstenite::WebSocketStream;
+#[cfg(feature = "websockets")]
 use tokio_tungstenite::{connect_async, MaybeTlsStream};
+#[cfg(feature = "websockets")]
 use url::Url;
 
-use crate::config::Config;
+#[cfg(feature = "websockets")]
+use crate::config::{Config, WebSocketProduct};
+#[cfg(feature = "websockets")]
 use crate::errors::*;
+#[cfg(feature = "websockets")]
+use crate::ws_model::{AccountPositionUpdate, BalanceUpdate, CombinedStreamEvent, OrderListUpdate, OrderUpdate,
+                      QueryResult, WebsocketEvent, WebsocketEventUntag};
 
 pub static STREAM_ENDPOINT: &str = "stream";
 pub static WS_ENDPOINT: &str = "ws";
@@ -23,6 +42,16 @@ pub static DEPTH_ORDERBOOK: &str = "depthUpdate";
 pub static PARTIAL_ORDERBOOK: &str = "lastUpdateId";
 pub static DAYTICKER: &str = "24hrTicker";
 
+/// Streams allowed by Binance on a single connection
+pub const MAX_STREAMS_PER_CONNECTION: usize = 1024;
+
+/// Messages, e.g. subscriptions, allowed by Binance per second and per connection
+pub const MAX_MESSAGES_PER_SECOND: u32 = 5;
+
+/// Streams subscribed by a single message of a [`FanOut`]
+#[cfg(feature = "websockets")]
+const SUBSCRIBE_BATCH: usize = 200;
+
 pub fn all_ticker_stream() -> &'static str { "!ticker@arr" }
 
 pub fn ticker_stream(symbol: &str) -> String { format!("{symbol}@ticker") }
@@ -31,10 +60,36 @@ pub fn agg_trade_stream(symbol: &str) -> String { format!("{symbol}@aggTrade") }
 
 pub fn trade_stream(symbol: &str) -> String { format!("{symbol}@trade") }
 
-pub fn kline_stream(symbol: &str, interval: &str) -> String { format!("{symbol}@kline_{interval}") }
+/// # Arguments
+///
+/// * `symbol`: the market symbol
+/// * `interval`: an [`Interval`](crate::rest_model::Interval) or its string value, e.g. "1m"
+///
+/// # Examples
+/// ```rust
+/// use binance::{rest_model::Interval, websockets::kline_stream};
+/// assert_eq!(kline_stream("btcusdt", Interval::FifteenMinutes), "btcusdt@kline_15m");
+/// ```
+pub fn kline_stream(symbol: &str, interval: impl fmt::Display) -> String { format!("{symbol}@kline_{interval}") }
 
 pub fn book_ticker_stream(symbol: &str) -> String { format!("{symbol}@bookTicker") }
 
+/// The `aggTrade` streams of `symbols`, lowercased
+pub fn agg_trade_streams<S: AsRef<str>>(symbols: &[S]) -> Vec<String> {
+    symbols
+        .iter()
+        .map(|symbol| agg_trade_stream(&symbol.as_ref().to_lowercase()))
+        .collect()
+}
+
+/// The `bookTicker` streams of `symbols`, lowercased
+pub fn book_ticker_streams<S: AsRef<str>>(symbols: &[S]) -> Vec<String> {
+    symbols
+        .iter()
+        .map(|symbol| book_ticker_stream(&symbol.as_ref().to_lowercase()))
+        .collect()
+}
+
 pub fn all_book_ticker_stream() -> &'static str { "!bookTicker" }
 
 pub fn all_mini_ticker_stream() -> &'static str { "!miniTicker@arr" }
@@ -56,14 +111,138 @@ pub fn partial_book_depth_stream(symbol: &str, levels: u16, update_speed: u16) -
 /// * `update_speed`: 1000 or 100
 pub fn diff_book_depth_stream(symbol: &str, update_speed: u16) -> String { format!("{symbol}@depth@{update_speed}ms") }
 
-fn combined_stream(streams: Vec<String>) -> String { streams.join("/") }
+#[cfg(any(feature = "websockets", all(feature = "wasm", target_arch = "wasm32")))]
+pub(crate) fn combined_stream(streams: Vec<String>) -> String { streams.join("/") }
+
+/// Events which may be the update of a kline
+#[cfg(feature = "websockets")]
+pub trait KlineEventFilter {
+    /// Whether the event is the update of a candle which is not closed yet
+    fn is_open_kline(&self) -> bool;
+}
+
+#[cfg(feature = "websockets")]
+impl KlineEventFilter for WebsocketEvent {
+    fn is_open_kline(&self) -> bool { matches!(self, WebsocketEvent::Kline(event) if !event.kline.is_final_bar) }
+}
+
+#[cfg(feature = "websockets")]
+impl KlineEventFilter for WebsocketEventUntag {
+    fn is_open_kline(&self) -> bool {
+        matches!(self, WebsocketEventUntag::WebsocketEvent(event) if event.is_open_kline())
+    }
+}
+
+#[cfg(feature = "websockets")]
+impl<T: KlineEventFilter> KlineEventFilter for CombinedStreamEvent<T> {
+    fn is_open_kline(&self) -> bool { self.data.is_open_kline() }
+}
+
+/// Wrap a handler so that it only receives the klines of closed candles (`k.x == true`),
+/// the other events are passed through
+///
+/// # Examples
+/// ```rust,no_run
+/// use binance::{rest_model::Interval, websockets::*, ws_model::*};
+/// use std::sync::atomic::AtomicBool;
+/// let keep_running = AtomicBool::new(true);
+/// let mut web_socket: WebSockets<'_, WebsocketEvent> = WebSockets::new(closed_klines(|event| {
+///     if let WebsocketEvent::Kline(kline_event) = event {
+///         println!("{} closed at {}", kline_event.symbol, kline_event.kline.close);
+///     }
+///     Ok(())
+/// }));
+/// let connected = tokio_test::block_on(web_socket.connect(&kline_stream("btcusdt", Interval::OneMinute)));
+/// assert!(connected.is_ok(), "{:?}", connected);
+/// let _ = tokio_test::block_on(web_socket.event_loop(&keep_running));
+/// ```
+#[cfg(feature = "websockets")]
+pub fn closed_klines<'a, WE, Callback>(mut handler: Callback) -> impl FnMut(WE) -> Result<()> + 'a + Send
+where
+    WE: KlineEventFilter,
+    Callback: FnMut(WE) -> Result<()> + 'a + Send,
+{
+    move |event: WE| {
+        if event.is_open_kline() {
+            Ok(())
+        } else {
+            handler(event)
+        }
+    }
+}
 
+/// Typed callbacks of the user data stream, every method defaults to ignoring its event
+///
+/// # Examples
+/// ```rust,no_run
+/// use binance::{api::*, config::*, errors::*, userstream::*, websockets::*, ws_model::*};
+/// use std::sync::atomic::AtomicBool;
+///
+/// struct Fills;
+///
+/// impl UserDataHandler for Fills {
+///     fn on_execution_report(&mut self, report: OrderUpdate) -> Result<()> {
+///         println!("{} {:?}: {}", report.symbol, report.execution_type, report.qty_last_executed);
+///         Ok(())
+///     }
+/// }
+///
+/// let user_stream: UserStream = Binance::new_with_env(&Config::testnet());
+/// let keep_running = AtomicBool::new(true);
+/// tokio_test::block_on(async {
+///     let answer = user_stream.start().await.unwrap();
+///     let mut web_socket: WebSockets<'_, WebsocketEvent> = WebSockets::new(user_data_handler(Fills));
+///     web_socket.connect(&answer.listen_key).await.unwrap();
+///     web_socket.event_loop(&keep_running).await.unwrap();
+/// });
+/// ```
+#[cfg(feature = "websockets")]
+pub trait UserDataHandler {
+    /// `executionReport`, an order was created, updated or filled
+    fn on_execution_report(&mut self, _report: OrderUpdate) -> Result<()> { Ok(()) }
+
+    /// `outboundAccountPosition`, the balances changed by an event of the account
+    fn on_account_position(&mut self, _update: AccountPositionUpdate) -> Result<()> { Ok(()) }
+
+    /// `balanceUpdate`, a deposit, a withdrawal or a transfer
+    fn on_balance_update(&mut self, _update: BalanceUpdate) -> Result<()> { Ok(()) }
+
+    /// `listStatus`, the status of an OCO or another order list changed
+    fn on_oco_status(&mut self, _update: OrderListUpdate) -> Result<()> { Ok(()) }
+
+    /// Any other event, e.g. when market streams are combined with the user data stream
+    fn on_other(&mut self, _event: WebsocketEvent) -> Result<()> { Ok(()) }
+
+    /// Call the method of `event`
+    fn dispatch(&mut self, event: WebsocketEvent) -> Result<()> {
+        match event {
+            WebsocketEvent::OrderUpdate(report) => self.on_execution_report(*report),
+            WebsocketEvent::AccountPositionUpdate(update) => self.on_account_position(*update),
+            WebsocketEvent::BalanceUpdate(update) => self.on_balance_update(*update),
+            WebsocketEvent::ListOrderUpdate(update) => self.on_oco_status(*update),
+            event => self.on_other(event),
+        }
+    }
+}
+
+/// Websocket handler dispatching the events of the user data stream to `handler`
+#[cfg(feature = "websockets")]
+pub fn user_data_handler<'a, H>(mut handler: H) -> impl FnMut(WebsocketEvent) -> Result<()> + 'a + Send
+where
+    H: UserDataHandler + 'a + Send,
+{
+    move |event: WebsocketEvent| handler.dispatch(event)
+}
+
+#[cfg(feature = "websockets")]
 pub struct WebSockets<'a, WE> {
     pub socket: Option<(WebSocketStream<MaybeTlsStream<TcpStream>>, Response)>,
     handler: Box<dyn FnMut(WE) -> Result<()> + 'a + Send>,
     conf: Config,
+    product: WebSocketProduct,
 }
 
+#[cfg(feature = "websockets")]
 impl<'a, WE: serde::de::DeserializeOwned> WebSockets<'a, WE> {
     /// New websocket holder with default configuration
     /// # Examples
@@ -86,13 +265,20 @@ impl<'a, WE: serde::de::DeserializeOwned> WebSockets<'a, WE> {
             socket: None,
             handler: Box::new(handler),
             conf,
+            product: WebSocketProduct::Spot,
         }
     }
 
+    /// Stream from the endpoint of `product` instead of spot
+    pub fn with_product(mut self, product: WebSocketProduct) -> Self {
+        self.product = product;
+        self
+    }
+
     /// Connect to multiple websocket endpoints
     /// N.B: WE has to be CombinedStreamEvent
     pub async fn connect_multiple(&mut self, endpoints: Vec<String>) -> Result<()> {
-        let mut url = Url::parse(&self.conf.ws_endpoint)?;
+        let mut url = Url::parse(self.conf.ws_endpoint_for(self.product))?;
         url.path_segments_mut()
             .map_err(|_| Error::UrlParserError(url::ParseError::RelativeUrlWithoutBase))?
             .push(STREAM_ENDPOINT);
@@ -103,14 +289,17 @@ impl<'a, WE: serde::de::DeserializeOwned> WebSockets<'a, WE> {
 
     /// Connect to a websocket endpoint
     pub async fn connect(&mut self, endpoint: &str) -> Result<()> {
-        let wss: String = format!("{}/{}/{}", self.conf.ws_endpoint, WS_ENDPOINT, endpoint);
+        let ws_endpoint = self.conf.ws_endpoint_for(self.product);
+        let wss: String = format!("{ws_endpoint}/{WS_ENDPOINT}/{endpoint}");
         let url = Url::parse(&wss)?;
 
         self.handle_connect(url).await
     }
 
     async fn handle_connect(&mut self, url: Url) -> Result<()> {
-        match connect_async(url).await {
+        let mut request = url.into_client_request()?;
+        request.headers_mut().extend(self.conf.default_headers()?);
+        match connect_async(request).await {
             Ok(answer) => {
                 self.socket = Some(answer);
                 Ok(())
@@ -155,3 +344,139 @@ impl<'a, WE: serde::de::DeserializeOwned> WebSockets<'a, WE> {
         Ok(())
     }
 }
+
+/// Subscribes to more streams than a single connection allows.
+///
+/// The streams are sharded over as many connections as needed, each of them subscribed to at most
+/// `streams_per_connection` streams with no more than `messages_per_second` subscription messages,
+/// and the events of all the connections are merged into a single stream.
+///
+/// # Examples
+/// ```rust,no_run
+/// use binance::{config::*, websockets::*, ws_model::*};
+/// use futures::StreamExt;
+/// let symbols = vec!["BTCUSDT", "ETHUSDT", "BNBUSDT"];
+/// let fan_out = FanOut::new(Config::default());
+/// tokio_test::block_on(async {
+///     let mut events = fan_out
+///         .connect::<WebsocketEventUntag>(book_ticker_streams(&symbols))
+///         .await
+///         .unwrap();
+///     while let Some(event) = events.next().await {
+///         if let Ok(CombinedStreamEvent {
+///             data: WebsocketEventUntag::BookTicker(ticker),
+///             ..
+///         }) = event
+///         {
+///             println!("{}: {} / {}", ticker.symbol, ticker.best_bid, ticker.best_ask);
+///         }
+///     }
+/// });
+/// ```
+#[cfg(feature = "websockets")]
+#[derive(Clone, Debug)]
+pub struct FanOut {
+    conf: Config,
+    product: WebSocketProduct,
+    streams_per_connection: usize,
+    messages_per_second: u32,
+}
+
+#[cfg(feature = "websockets")]
+impl FanOut {
+    /// Fan-out with the limits of Binance
+    pub fn new(conf: Config) -> Self {
+        Self {
+            conf,
+            product: WebSocketProduct::Spot,
+            streams_per_connection: MAX_STREAMS_PER_CONNECTION,
+            messages_per_second: MAX_MESSAGES_PER_SECOND,
+        }
+    }
+
+    /// Stream from the endpoint of `product` instead of spot
+    pub fn with_product(mut self, product: WebSocketProduct) -> Self {
+        self.product = product;
+        self
+    }
+
+    /// Streams per connection, at most [`MAX_STREAMS_PER_CONNECTION`]
+    pub fn with_streams_per_connection(mut self, streams_per_connection: usize) -> Self {
+        self.streams_per_connection = streams_per_connection.clamp(1, MAX_STREAMS_PER_CONNECTION);
+        self
+    }
+
+    /// Subscription messages per second, at most [`MAX_MESSAGES_PER_SECOND`]
+    pub fn with_messages_per_second(mut self, messages_per_second: u32) -> Self {
+        self.messages_per_second = messages_per_second.clamp(1, MAX_MESSAGES_PER_SECOND);
+        self
+    }
+
+    /// Connections opened to subscribe to `streams` streams
+    pub fn connections(&self, streams: usize) -> usize { streams.div_ceil(self.streams_per_connection) }
+
+    /// Connect and subscribe to all the `streams`, returning their merged events
+    /// N.B: a connection closed by Binance yields an error, then its streams end while the others go on,
+    /// a subscription rejected by Binance yields a [`Error::BinanceError`]
+    pub async fn connect<WE>(&self, streams: Vec<String>) -> Result<BoxStream<'static, Result<CombinedStreamEvent<WE>>>>
+    where
+        WE: serde::de::DeserializeOwned + Send + 'static,
+    {
+        let pause = std::time::Duration::from_millis(1000 / u64::from(self.messages_per_second));
+        let mut shards = Vec::with_capacity(self.connections(streams.len()));
+        let mut id = 0;
+        for shard in streams.chunks(self.streams_per_connection) {
+            let mut url = Url::parse(self.conf.ws_endpoint_for(self.product))?;
+            url.path_segments_mut()
+                .map_err(|_| Error::UrlParserError(url::ParseError::RelativeUrlWithoutBase))?
+                .push(STREAM_ENDPOINT);
+            let mut request = url.into_client_request()?;
+            request.headers_mut().extend(self.conf.default_headers()?);
+            let (mut socket, _) = connect_async(request)
+                .await
+                .map_err(|e| Error::Msg(format!("Error during handshake {e}")))?;
+            for params in shard.chunks(SUBSCRIBE_BATCH) {
+                id += 1;
+                let subscribe = serde_json::json!({ "method": "SUBSCRIBE", "params": params, "id": id });
+                socket.send(Message::Text(subscribe.to_string())).await?;
+                tokio::time::sleep(pause).await;
+            }
+            shards.push(
+                socket
+                    .filter_map(|message| futures::future::ready(fan_out_event(message)))
+                    .boxed(),
+            );
+        }
+        Ok(futures::stream::select_all(shards).boxed())
+    }
+}
+
+/// Answer to a rejected subscription, e.g. `{"error":{"code":2,"msg":"Invalid request"},"id":1}`
+#[cfg(feature = "websockets")]
+#[derive(Deserialize)]
+struct QueryError {
+    error: BinanceContentError,
+}
+
+/// The event of a fan-out message, skipping the control frames and the answers to the accepted subscriptions
+#[cfg(feature = "websockets")]
+fn fan_out_event<WE: serde::de::DeserializeOwned>(
+    message: std::result::Result<Message, tokio_tungstenite::tungstenite::Error>,
+) -> Option<Result<CombinedStreamEvent<WE>>> {
+    match message {
+        Ok(Message::Text(msg)) => {
+            if let Ok(rejected) = from_str::<QueryError>(&msg) {
+                return Some(Err(Error::BinanceError {
+                    response: rejected.error,
+                }));
+            }
+            if from_str::<QueryResult>(&msg).is_ok() {
+                return None;
+            }
+            Some(from_str(&msg).map_err(Error::from))
+        }
+        Ok(Message::Close(e)) => Some(Err(Error::Msg(format!("Disconnected {e:?}")))),
+        Ok(_) => None,
+        Err(e) => Some(Err(e.into())),
+    }
+}
@@ -1,4 +1,7 @@
-use crate::rest_model::{string_or_float, Asks, Bids, OrderBook, OrderSide, OrderStatus, OrderType, TimeInForce};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use crate::rest_model::{string_or_float, Asks, Bids, ContingencyType, ListOrderStatus, ListStatusType, OrderBook,
+                        OrderSide, OrderStatus, OrderType, TimeInForce};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "e")]
@@ -43,11 +46,11 @@ pub struct TradesEvent {
     #[serde(rename = "a")]
     pub aggregated_trade_id: u64,
 
-    #[serde(rename = "p")]
-    pub price: String,
+    #[serde(rename = "p", with = "string_or_float")]
+    pub price: f64,
 
-    #[serde(rename = "q")]
-    pub qty: String,
+    #[serde(rename = "q", with = "string_or_float")]
+    pub qty: f64,
 
     #[serde(rename = "f")]
     pub first_break_trade_id: u64,
@@ -77,11 +80,11 @@ pub struct TradeEvent {
     #[serde(rename = "t")]
     pub trade_id: u64,
 
-    #[serde(rename = "p")]
-    pub price: String,
+    #[serde(rename = "p", with = "string_or_float")]
+    pub price: f64,
 
-    #[serde(rename = "q")]
-    pub qty: String,
+    #[serde(rename = "q", with = "string_or_float")]
+    pub qty: f64,
 
     #[serde(rename = "b")]
     pub buyer_order_id: u64,
@@ -106,36 +109,36 @@ pub struct DayTickerEvent {
     pub event_time: u64,
     #[serde(rename = "s")]
     pub symbol: String,
-    #[serde(rename = "p")]
-    pub price_change: String,
-    #[serde(rename = "P")]
-    pub price_change_percent: String,
-    #[serde(rename = "w")]
-    pub average_price: String,
-    #[serde(rename = "x")]
-    pub prev_close: String,
-    #[serde(rename = "c")]
-    pub current_close: String,
-    #[serde(rename = "Q")]
-    pub current_close_qty: String,
-    #[serde(rename = "b")]
-    pub best_bid: String,
-    #[serde(rename = "B")]
-    pub best_bid_qty: String,
-    #[serde(rename = "a")]
-    pub best_ask: String,
-    #[serde(rename = "A")]
-    pub best_ask_qty: String,
-    #[serde(rename = "o")]
-    pub open: String,
-    #[serde(rename = "h")]
-    pub high: String,
-    #[serde(rename = "l")]
-    pub low: String,
-    #[serde(rename = "v")]
-    pub volume: String,
-    #[serde(rename = "q")]
-    pub quote_volume: String,
+    #[serde(rename = "p", with = "string_or_float")]
+    pub price_change: f64,
+    #[serde(rename = "P", with = "string_or_float")]
+    pub price_change_percent: f64,
+    #[serde(rename = "w", with = "string_or_float")]
+    pub average_price: f64,
+    #[serde(rename = "x", with = "string_or_float")]
+    pub prev_close: f64,
+    #[serde(rename = "c", with = "string_or_float")]
+    pub current_close: f64,
+    #[serde(rename = "Q", with = "string_or_float")]
+    pub current_close_qty: f64,
+    #[serde(rename = "b", with = "string_or_float")]
+    pub best_bid: f64,
+    #[serde(rename = "B", with = "string_or_float")]
+    pub best_bid_qty: f64,
+    #[serde(rename = "a", with = "string_or_float")]
+    pub best_ask: f64,
+    #[serde(rename = "A", with = "string_or_float")]
+    pub best_ask_qty: f64,
+    #[serde(rename = "o", with = "string_or_float")]
+    pub open: f64,
+    #[serde(rename = "h", with = "string_or_float")]
+    pub high: f64,
+    #[serde(rename = "l", with = "string_or_float")]
+    pub low: f64,
+    #[serde(rename = "v", with = "string_or_float")]
+    pub volume: f64,
+    #[serde(rename = "q", with = "string_or_float")]
+    pub quote_volume: f64,
     #[serde(rename = "O")]
     pub open_time: u64,
     #[serde(rename = "C")]
@@ -155,18 +158,18 @@ pub struct MiniDayTickerEvent {
     pub event_time: u64,
     #[serde(rename = "s")]
     pub symbol: String,
-    #[serde(rename = "c")]
-    pub current_close: String,
-    #[serde(rename = "o")]
-    pub open: String,
-    #[serde(rename = "h")]
-    pub high: String,
-    #[serde(rename = "l")]
-    pub low: String,
-    #[serde(rename = "v")]
-    pub volume: String,
-    #[serde(rename = "q")]
-    pub quote_volume: String,
+    #[serde(rename = "c", with = "string_or_float")]
+    pub current_close: f64,
+    #[serde(rename = "o", with = "string_or_float")]
+    pub open: f64,
+    #[serde(rename = "h", with = "string_or_float")]
+    pub high: f64,
+    #[serde(rename = "l", with = "string_or_float")]
+    pub low: f64,
+    #[serde(rename = "v", with = "string_or_float")]
+    pub volume: f64,
+    #[serde(rename = "q", with = "string_or_float")]
+    pub quote_volume: f64,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -272,7 +275,6 @@ pub struct CombinedStreamEvent<T> {
     pub data: T,
 }
 
-///
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum WebsocketEventUntag {
@@ -293,7 +295,7 @@ impl<T> CombinedStreamEvent<T> {
     }
 }
 
-/// User Stream related events
+// User Stream related events
 
 /// Account position update
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -448,6 +450,145 @@ pub struct OrderUpdate {
     pub quote_order_qty: f64,
 }
 
+impl OrderUpdate {
+    /// Average price of the fills so far, None if nothing was filled
+    pub fn average_fill_price(&self) -> Option<f64> {
+        (self.cumulative_filled_qty > 0.0)
+            .then(|| self.cumulative_quote_asset_transacted_qty / self.cumulative_filled_qty)
+    }
+
+    pub fn remaining_qty(&self) -> f64 { (self.qty - self.cumulative_filled_qty).max(0.0) }
+
+    /// Whether the order can no longer be filled
+    pub fn is_closed(&self) -> bool {
+        matches!(
+            self.current_order_status,
+            OrderStatus::Filled | OrderStatus::Canceled | OrderStatus::Rejected | OrderStatus::Expired
+        )
+    }
+}
+
+/// Fills of an order aggregated across its execution reports, see [`OrderTracker`]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct OrderFills {
+    pub symbol: String,
+    pub order_id: u64,
+    pub client_order_id: Option<String>,
+    pub side: OrderSide,
+    pub status: OrderStatus,
+    pub qty: f64,
+    pub filled_qty: f64,
+    /// Sum of the price times the quantity of each fill
+    pub filled_quote_qty: f64,
+    /// Commission paid on the fills, per asset
+    pub commissions: BTreeMap<String, f64>,
+    pub trade_count: u64,
+    /// Ids of the trades counted, so that replayed reports are not counted twice
+    pub trade_ids: BTreeSet<i64>,
+    pub update_time: u64,
+}
+
+impl OrderFills {
+    fn new(update: &OrderUpdate) -> Self {
+        Self {
+            symbol: update.symbol.clone(),
+            order_id: update.order_id,
+            client_order_id: update.client_order_id.clone(),
+            side: update.side.clone(),
+            status: update.current_order_status.clone(),
+            qty: update.qty,
+            filled_qty: 0.0,
+            filled_quote_qty: 0.0,
+            commissions: BTreeMap::new(),
+            trade_count: 0,
+            trade_ids: BTreeSet::new(),
+            update_time: update.event_time,
+        }
+    }
+
+    fn apply(&mut self, update: &OrderUpdate) {
+        if update.event_time >= self.update_time {
+            self.status = update.current_order_status.clone();
+            self.update_time = update.event_time;
+        }
+        // Reports may arrive out of order, a late trade is still counted unless it was seen already
+        if update.execution_type != OrderStatus::Trade || !self.trade_ids.insert(update.trade_id) {
+            return;
+        }
+        self.trade_count += 1;
+        // The cumulative quantities of the report are authoritative, they also account for missed reports
+        self.filled_qty = self.filled_qty.max(update.cumulative_filled_qty);
+        self.filled_quote_qty = self.filled_quote_qty.max(update.cumulative_quote_asset_transacted_qty);
+        if let Some(asset) = &update.commission_asset {
+            *self.commissions.entry(asset.clone()).or_default() += update.commission;
+        }
+    }
+
+    /// Average price of the fills, None if nothing was filled
+    pub fn average_price(&self) -> Option<f64> {
+        (self.filled_qty > 0.0).then(|| self.filled_quote_qty / self.filled_qty)
+    }
+
+    pub fn remaining_qty(&self) -> f64 { (self.qty - self.filled_qty).max(0.0) }
+
+    /// Commission paid in `asset`
+    pub fn commission(&self, asset: &str) -> f64 { self.commissions.get(asset).copied().unwrap_or_default() }
+
+    /// Whether the order can no longer be filled
+    pub fn is_closed(&self) -> bool {
+        matches!(
+            self.status,
+            OrderStatus::Filled | OrderStatus::Canceled | OrderStatus::Rejected | OrderStatus::Expired
+        )
+    }
+}
+
+/// Aggregates the execution reports of the user data stream per order
+///
+/// # Examples
+/// ```rust,no_run
+/// use binance::ws_model::{OrderTracker, OrderUpdate};
+/// let mut tracker = OrderTracker::default();
+/// let mut on_execution_report = |update: OrderUpdate| {
+///     let fills = tracker.update(&update);
+///     println!("{} filled at {:?}, commissions {:?}", fills.filled_qty, fills.average_price(), fills.commissions);
+/// };
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct OrderTracker {
+    orders: HashMap<(String, u64), OrderFills>,
+}
+
+impl OrderTracker {
+    /// Account for an execution report, returns the fills of its order
+    pub fn update(&mut self, update: &OrderUpdate) -> &OrderFills {
+        let fills = self
+            .orders
+            .entry((update.symbol.clone(), update.order_id))
+            .or_insert_with(|| OrderFills::new(update));
+        fills.apply(update);
+        fills
+    }
+
+    pub fn get(&self, symbol: &str, order_id: u64) -> Option<&OrderFills> {
+        self.orders.get(&(symbol.to_string(), order_id))
+    }
+
+    /// Orders which can still be filled
+    pub fn open_orders(&self) -> impl Iterator<Item = &OrderFills> { self.orders.values().filter(|o| !o.is_closed()) }
+
+    /// Stop tracking the orders which can no longer be filled, and return them
+    pub fn remove_closed(&mut self) -> Vec<OrderFills> {
+        let closed: Vec<(String, u64)> = self
+            .orders
+            .iter()
+            .filter(|(_, fills)| fills.is_closed())
+            .map(|(key, _)| key.clone())
+            .collect();
+        closed.into_iter().filter_map(|key| self.orders.remove(&key)).collect()
+    }
+}
+
 /// For OCO Events
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
@@ -457,23 +598,42 @@ pub struct OrderListUpdate {
     #[serde(rename = "s")]
     pub symbol: String,
     #[serde(rename = "g")]
-    order_list_id: i64,
+    pub order_list_id: i64,
     #[serde(rename = "c")]
-    contingency_type: String,
+    pub contingency_type: ContingencyType,
     #[serde(rename = "l")]
-    list_status_type: String,
+    pub list_status_type: ListStatusType,
     #[serde(rename = "L")]
-    list_order_status: String,
+    pub list_order_status: ListOrderStatus,
+    /// "NONE" unless the list was rejected
     #[serde(rename = "r")]
-    list_reject_reason: String,
+    pub list_reject_reason: String,
     #[serde(rename = "C")]
-    list_client_order_id: String,
+    pub list_client_order_id: String,
     #[serde(rename = "T")]
     pub transaction_time: u64,
+    /// Orders of the list
     #[serde(rename = "O")]
     pub objects: Vec<OrderListTransaction>,
 }
 
+impl OrderListUpdate {
+    /// Whether the list finished executing, one of its orders was filled or all of them were canceled
+    pub fn is_done(&self) -> bool { self.list_order_status == ListOrderStatus::AllDone }
+
+    pub fn is_rejected(&self) -> bool { self.list_order_status == ListOrderStatus::Reject }
+
+    /// Reason of the rejection, None if the list was not rejected
+    pub fn reject_reason(&self) -> Option<&str> {
+        Some(self.list_reject_reason.as_str()).filter(|reason| *reason != "NONE")
+    }
+
+    /// Order of the list with the id `order_id`
+    pub fn order(&self, order_id: i64) -> Option<&OrderListTransaction> {
+        self.objects.iter().find(|o| o.order_id == order_id)
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct OrderListTransaction {
@@ -484,3 +644,40 @@ pub struct OrderListTransaction {
     #[serde(rename = "c")]
     pub client_order_id: String,
 }
+
+#[cfg(test)]
+mod test {
+    use crate::rest_model::OrderStatus;
+    use crate::ws_model::{OrderTracker, OrderUpdate};
+
+    #[test]
+    fn order_tracker_fills() {
+        let report = |time: u64, status: &str, trade_id: i64, last: (f64, f64), cumulative: (f64, f64), fee: f64| {
+            let report = format!(
+                r#"{{"e": "executionReport", "E": {time}, "s": "ETHBTC", "c": "mUvoqJxFIILMdfAW5iGSOW", "S": "BUY",
+                "o": "LIMIT", "f": "GTC", "q": "2.0", "p": "0.1", "P": "0", "F": "0", "g": -1, "C": "",
+                "x": "TRADE", "X": "{status}", "r": "NONE", "i": 4293153, "l": "{}", "z": "{}", "L": "{}",
+                "n": "{fee}", "N": "BNB", "T": {time}, "t": {trade_id}, "I": 8641984, "w": true, "m": false,
+                "M": false, "O": 1499405658657, "Z": "{}", "Y": "0", "Q": "0"}}"#,
+                last.0, cumulative.0, last.1, cumulative.1
+            );
+            serde_json::from_str::<OrderUpdate>(&report).unwrap()
+        };
+        let first = report(1, "PARTIALLY_FILLED", 10, (0.5, 0.1), (0.5, 0.05), 0.001);
+        let second = report(2, "FILLED", 11, (1.5, 0.09), (2.0, 0.185), 0.002);
+        assert_eq!(second.average_fill_price(), Some(0.0925));
+        let mut tracker = OrderTracker::default();
+        tracker.update(&second);
+        // The report of the first trade arrives late, and is then replayed
+        tracker.update(&first);
+        let fills = tracker.update(&first);
+        assert_eq!(fills.trade_count, 2);
+        assert_eq!(fills.status, OrderStatus::Filled);
+        assert_eq!(fills.filled_qty, 2.0);
+        assert_eq!(fills.average_price(), Some(0.0925));
+        assert_eq!(fills.commission("BNB"), 0.003);
+        assert!(fills.is_closed());
+        assert_eq!(tracker.remove_closed().len(), 1);
+        assert!(tracker.get("ETHBTC", 4293153).is_none());
+    }
+}